@@ -59,6 +59,13 @@ impl OutboxRelay {
                 self.cmd_tx.send(command).await?;
                 res_fut.await
             }
+            OutboxEvent::BlockchainReorganize(block) => {
+                let (command, res_fut) = self
+                    .cmd_tx_res_factory
+                    .build_blk_cmd_handle_block_reorganize(block);
+                self.cmd_tx.send(command).await?;
+                res_fut.await
+            }
         }
     }
 }
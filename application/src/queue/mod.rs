@@ -1,5 +1,7 @@
+mod orphan;
 mod proc;
 mod sync;
 
+pub use orphan::DefaultBlockOrphanPool;
 pub use proc::DefaultBlockProcessingQueue;
 pub use sync::DefaultBlockSyncQueue;
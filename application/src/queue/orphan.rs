@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests;
+
+use async_trait::async_trait;
+use common::log_app_debug;
+use domain::entities::block::NonValidatedBlock;
+use domain::system::queue::BlockOrphanPool;
+use domain::types::hash::Hash;
+use domain::types::network::NetworkPeerId;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+#[derive(Debug)]
+struct OrphanBlock {
+    block: NonValidatedBlock,
+    from_peer: NetworkPeerId,
+    received_at: Instant,
+}
+
+#[derive(Debug)]
+pub struct DefaultBlockOrphanPool {
+    orphans: Mutex<HashMap<Hash, Vec<OrphanBlock>>>,
+}
+
+impl DefaultBlockOrphanPool {
+    /// Maximum number of pooled orphans.
+    const MAX_ORPHANS: usize = 100;
+
+    /// Orphans whose parent doesn't arrive in time are dropped.
+    const MAX_ORPHAN_AGE: Duration = Duration::from_secs(10 * 60);
+
+    pub fn new() -> Self {
+        Self {
+            orphans: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn prune_expired(orphans: &mut HashMap<Hash, Vec<OrphanBlock>>) {
+        orphans.retain(|_, children| {
+            children.retain(|orphan| orphan.received_at.elapsed() < Self::MAX_ORPHAN_AGE);
+            !children.is_empty()
+        });
+    }
+
+    fn evict_oldest(orphans: &mut HashMap<Hash, Vec<OrphanBlock>>) {
+        let oldest = orphans
+            .iter()
+            .flat_map(|(parent_hash, children)| {
+                children
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, orphan)| (parent_hash, index, orphan.received_at))
+            })
+            .min_by_key(|(_, _, received_at)| *received_at)
+            .map(|(parent_hash, index, _)| (parent_hash.clone(), index));
+        let Some((parent_hash, index)) = oldest else {
+            return;
+        };
+        if let Some(children) = orphans.get_mut(&parent_hash) {
+            children.remove(index);
+            if children.is_empty() {
+                orphans.remove(&parent_hash);
+            }
+        }
+    }
+}
+
+impl Default for DefaultBlockOrphanPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BlockOrphanPool for DefaultBlockOrphanPool {
+    async fn add_orphan(&self, block: NonValidatedBlock, from_peer: NetworkPeerId) -> bool {
+        let Some(parent_hash) = block.get_prev_block_hash() else {
+            return false;
+        };
+        let mut orphans = self.orphans.lock().await;
+        Self::prune_expired(&mut orphans);
+
+        let block_hash = block.get_hash();
+        let pooled = orphans
+            .get(&parent_hash)
+            .is_some_and(|children| children.iter().any(|o| o.block.get_hash() == block_hash));
+        if pooled {
+            return false;
+        }
+
+        let orphan_count: usize = orphans.values().map(Vec::len).sum();
+        if orphan_count >= Self::MAX_ORPHANS {
+            Self::evict_oldest(&mut orphans);
+        }
+
+        log_app_debug!(
+            "BlockOrphanPool.add_orphan() | Pooling block (hash: {block_hash:?}) awaiting parent (hash: {parent_hash:?})"
+        );
+        orphans.entry(parent_hash).or_default().push(OrphanBlock {
+            block,
+            from_peer,
+            received_at: Instant::now(),
+        });
+        true
+    }
+
+    async fn take_orphans_by_parent(
+        &self,
+        parent_hash: &Hash,
+    ) -> Vec<(NonValidatedBlock, NetworkPeerId)> {
+        let mut orphans = self.orphans.lock().await;
+        Self::prune_expired(&mut orphans);
+
+        orphans
+            .remove(parent_hash)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|orphan| (orphan.block, orphan.from_peer))
+            .collect()
+    }
+}
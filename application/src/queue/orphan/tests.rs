@@ -0,0 +1,170 @@
+use super::*;
+use domain::entities::block::{BlockDifficultyTarget, BlockHeight, BlockNonce};
+use domain::entities::transaction::{
+    NonValidatedTransaction, TransactionAmount, TransactionOutput,
+};
+use domain::types::time::DateTime;
+use domain::types::wallet::WalletAddress;
+use std::collections::HashSet;
+
+fn create_test_hash(seed: usize) -> Hash {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&(seed as u64).to_le_bytes());
+    Hash::new(bytes)
+}
+
+fn create_test_peer_id() -> NetworkPeerId {
+    NetworkPeerId::_new_validated(vec![1], "test-peer".to_string())
+}
+
+fn create_test_block(prev_block_hash: Hash, nonce: u64) -> NonValidatedBlock {
+    let recipient = format!("{:0>64}", 1)
+        .parse::<WalletAddress>()
+        .expect("Valid wallet address");
+    let timestamp = DateTime::from_ms(1725799696000);
+    let coinbase = NonValidatedTransaction::new_coinbase(
+        BlockHeight::from(1),
+        vec![TransactionOutput::new(
+            recipient,
+            TransactionAmount::new(50),
+        )],
+        timestamp.clone(),
+    )
+    .unwrap();
+    NonValidatedBlock::new(
+        BlockHeight::from(1),
+        Some(prev_block_hash),
+        BlockNonce::new(nonce),
+        BlockDifficultyTarget::new(u128::MAX),
+        vec![coinbase],
+        timestamp,
+    )
+    .unwrap()
+}
+
+async fn backdate_orphan(pool: &DefaultBlockOrphanPool, block_hash: &Hash, age: Duration) {
+    let mut orphans = pool.orphans.lock().await;
+    let orphan = orphans
+        .values_mut()
+        .flatten()
+        .find(|orphan| orphan.block.get_hash() == *block_hash)
+        .expect("Pooled orphan");
+    orphan.received_at = Instant::now().checked_sub(age).unwrap();
+}
+
+async fn count_orphans(pool: &DefaultBlockOrphanPool) -> usize {
+    pool.orphans.lock().await.values().map(Vec::len).sum()
+}
+
+// ============================================================================
+// add_orphan()
+// ============================================================================
+
+#[tokio::test]
+async fn test_add_orphan_ignores_duplicates() {
+    let pool = DefaultBlockOrphanPool::new();
+    let block = create_test_block(create_test_hash(1), 0);
+
+    assert!(pool.add_orphan(block.clone(), create_test_peer_id()).await);
+    assert!(!pool.add_orphan(block, create_test_peer_id()).await);
+    assert_eq!(count_orphans(&pool).await, 1);
+}
+
+#[tokio::test]
+async fn test_add_orphan_evicts_oldest_over_capacity() {
+    let pool = DefaultBlockOrphanPool::new();
+    let blocks = (0..DefaultBlockOrphanPool::MAX_ORPHANS)
+        .map(|seed| create_test_block(create_test_hash(seed), seed as u64))
+        .collect::<Vec<_>>();
+    for block in &blocks {
+        assert!(pool.add_orphan(block.clone(), create_test_peer_id()).await);
+    }
+    let oldest_hash = blocks[42].get_hash();
+    backdate_orphan(&pool, &oldest_hash, Duration::from_secs(60)).await;
+
+    let block = create_test_block(create_test_hash(DefaultBlockOrphanPool::MAX_ORPHANS), 0);
+    assert!(pool.add_orphan(block, create_test_peer_id()).await);
+
+    assert_eq!(
+        count_orphans(&pool).await,
+        DefaultBlockOrphanPool::MAX_ORPHANS
+    );
+    assert!(
+        pool.take_orphans_by_parent(&create_test_hash(42))
+            .await
+            .is_empty(),
+        "Oldest orphan should be evicted"
+    );
+}
+
+#[tokio::test]
+async fn test_add_orphan_prunes_expired() {
+    let pool = DefaultBlockOrphanPool::new();
+    let expired_block = create_test_block(create_test_hash(1), 0);
+    let block = create_test_block(create_test_hash(2), 0);
+    pool.add_orphan(expired_block.clone(), create_test_peer_id())
+        .await;
+    backdate_orphan(
+        &pool,
+        &expired_block.get_hash(),
+        DefaultBlockOrphanPool::MAX_ORPHAN_AGE,
+    )
+    .await;
+
+    pool.add_orphan(block, create_test_peer_id()).await;
+
+    assert_eq!(count_orphans(&pool).await, 1);
+    assert!(
+        pool.take_orphans_by_parent(&create_test_hash(1))
+            .await
+            .is_empty(),
+        "Orphans should expire after 10 minutes"
+    );
+}
+
+// ============================================================================
+// take_orphans_by_parent()
+// ============================================================================
+
+#[tokio::test]
+async fn test_take_orphans_by_parent_returns_children() {
+    let pool = DefaultBlockOrphanPool::new();
+    let parent_hash = create_test_hash(1);
+    let child1 = create_test_block(parent_hash.clone(), 1);
+    let child2 = create_test_block(parent_hash.clone(), 2);
+    let unrelated = create_test_block(create_test_hash(2), 0);
+    for block in [&child1, &child2, &unrelated] {
+        pool.add_orphan(block.clone(), create_test_peer_id()).await;
+    }
+
+    let children = pool
+        .take_orphans_by_parent(&parent_hash)
+        .await
+        .into_iter()
+        .map(|(block, _)| block.get_hash())
+        .collect::<HashSet<_>>();
+
+    assert_eq!(
+        children,
+        HashSet::from([child1.get_hash(), child2.get_hash()]),
+        "Every child of the arrived parent should be handed out"
+    );
+    assert!(pool.take_orphans_by_parent(&parent_hash).await.is_empty());
+    assert_eq!(count_orphans(&pool).await, 1);
+}
+
+#[tokio::test]
+async fn test_take_orphans_by_parent_skips_expired() {
+    let pool = DefaultBlockOrphanPool::new();
+    let parent_hash = create_test_hash(1);
+    let block = create_test_block(parent_hash.clone(), 0);
+    pool.add_orphan(block.clone(), create_test_peer_id()).await;
+    backdate_orphan(
+        &pool,
+        &block.get_hash(),
+        DefaultBlockOrphanPool::MAX_ORPHAN_AGE,
+    )
+    .await;
+
+    assert!(pool.take_orphans_by_parent(&parent_hash).await.is_empty());
+}
@@ -1,30 +1,95 @@
+#[cfg(test)]
+mod tests;
+
 use async_trait::async_trait;
 use common::log_app_debug;
 use domain::entities::block::{BlockHeight, NonValidatedBlock};
 use domain::system::queue::BlockProcessingQueue;
-use std::collections::{BTreeMap, HashSet};
+use domain::types::hash::Hash;
+use domain::types::network::NetworkPeerId;
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use tokio::sync::Mutex;
 
 #[derive(Debug)]
 pub struct DefaultBlockProcessingQueue {
     next_expected: Mutex<BlockHeight>,
-    buffered_blocks: Mutex<BTreeMap<BlockHeight, NonValidatedBlock>>,
+    buffered_blocks: Mutex<BTreeMap<BlockHeight, (NonValidatedBlock, NetworkPeerId)>>,
     in_flight: Mutex<HashSet<BlockHeight>>,
+    side_blocks: Mutex<VecDeque<(NonValidatedBlock, NetworkPeerId)>>,
+    rejected: Mutex<RejectedBlockHashes>,
+}
+
+/// Bounded set of permanently rejected block hashes, evicting the oldest entries first.
+#[derive(Debug, Default)]
+struct RejectedBlockHashes {
+    hashes: HashSet<Hash>,
+    order: VecDeque<Hash>,
+}
+
+impl RejectedBlockHashes {
+    /// Maximum number of remembered rejected block hashes.
+    const MAX_REJECTED_BLOCKS: usize = 1000;
+
+    fn insert(&mut self, hash: Hash) {
+        if !self.hashes.insert(hash.clone()) {
+            return;
+        }
+        self.order.push_back(hash);
+        while self.order.len() > Self::MAX_REJECTED_BLOCKS {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.hashes.remove(&oldest);
+        }
+    }
+
+    fn contains(&self, hash: &Hash) -> bool {
+        self.hashes.contains(hash)
+    }
 }
 
 impl DefaultBlockProcessingQueue {
+    /// Maximum number of buffered blocks below the next expected height.
+    const MAX_SIDE_BLOCKS: usize = 100;
+
     pub fn new(next_expected_height: BlockHeight) -> Self {
         Self {
             next_expected: Mutex::new(next_expected_height),
             buffered_blocks: Mutex::new(BTreeMap::new()),
             in_flight: Mutex::new(HashSet::new()),
+            side_blocks: Mutex::new(VecDeque::new()),
+            rejected: Mutex::new(RejectedBlockHashes::default()),
         }
     }
 }
 
 #[async_trait]
 impl BlockProcessingQueue for DefaultBlockProcessingQueue {
-    async fn push_block(&self, block: NonValidatedBlock) {
+    async fn push_block(&self, block: NonValidatedBlock, from_peer: NetworkPeerId) {
+        {
+            let mut rejected = self.rejected.lock().await;
+            if rejected.contains(&block.get_hash()) {
+                log_app_debug!(
+                    "BlockProcessingQueue.push_block() | Ignoring rejected block {:?}",
+                    block.get_hash()
+                );
+                return;
+            }
+            // Descendants of rejected blocks are just as invalid.
+            if let Some(prev_hash) = block
+                .get_prev_block_hash()
+                .filter(|prev_hash| rejected.contains(prev_hash))
+            {
+                log_app_debug!(
+                    "BlockProcessingQueue.push_block() | Rejecting descendant block {:?} of rejected block {:?}",
+                    block.get_hash(),
+                    prev_hash
+                );
+                rejected.insert(block.get_hash());
+                return;
+            }
+        }
+
         let next_expected = self.next_expected.lock().await;
         let mut buffered = self.buffered_blocks.lock().await;
         let height = block.get_height();
@@ -32,24 +97,41 @@ impl BlockProcessingQueue for DefaultBlockProcessingQueue {
         if height == *next_expected {
             // We could process this one immediately, but that would introduce coupling.
             // Let's buffer and rely on polling instead...
-            buffered.insert(height, block);
+            buffered.insert(height, (block, from_peer));
         } else if height > *next_expected {
-            buffered.insert(height, block);
+            buffered.insert(height, (block, from_peer));
         } else {
-            log_app_debug!(
-                "BlockProcessingQueue.push_block() | Ignoring old or duplicate block {:?}",
-                height
-            );
+            // Blocks below the next expected height may extend a side branch.
+            let mut side_blocks = self.side_blocks.lock().await;
+            if side_blocks
+                .iter()
+                .any(|(side_block, _)| side_block.get_hash() == block.get_hash())
+            {
+                log_app_debug!(
+                    "BlockProcessingQueue.push_block() | Ignoring duplicate block {:?}",
+                    height
+                );
+                return;
+            }
+            if side_blocks.len() >= Self::MAX_SIDE_BLOCKS {
+                side_blocks.pop_front();
+            }
+            side_blocks.push_back((block, from_peer));
         }
     }
 
-    async fn next_ready_block(&self) -> Option<NonValidatedBlock> {
+    async fn next_ready_block(&self) -> Option<(NonValidatedBlock, NetworkPeerId)> {
+        // Side blocks are handed out once, regardless of their processing outcome.
+        if let Some(entry) = self.side_blocks.lock().await.pop_front() {
+            return Some(entry);
+        }
+
         let next_expected = self.next_expected.lock().await;
         let buffered = self.buffered_blocks.lock().await;
         let mut in_flight = self.in_flight.lock().await;
 
         let next_block = buffered.get(&next_expected);
-        if let Some(block) = next_block {
+        if let Some((block, from_peer)) = next_block {
             let height = block.get_height();
 
             if in_flight.contains(&height) {
@@ -57,7 +139,7 @@ impl BlockProcessingQueue for DefaultBlockProcessingQueue {
             }
 
             in_flight.insert(height.clone());
-            return Some(block.clone());
+            return Some((block.clone(), from_peer.clone()));
         }
         None
     }
@@ -79,4 +161,33 @@ impl BlockProcessingQueue for DefaultBlockProcessingQueue {
         let mut in_flight = self.in_flight.lock().await;
         in_flight.remove(height);
     }
+
+    async fn discard_block(&self, height: &BlockHeight) {
+        let mut buffered = self.buffered_blocks.lock().await;
+        let mut in_flight = self.in_flight.lock().await;
+
+        buffered.remove(height);
+        in_flight.remove(height);
+    }
+
+    async fn reject_block(&self, block_hash: &Hash, height: &BlockHeight) {
+        let mut buffered = self.buffered_blocks.lock().await;
+        let mut in_flight = self.in_flight.lock().await;
+        let mut rejected = self.rejected.lock().await;
+
+        // Another block may have since been buffered for the same height.
+        if buffered
+            .get(height)
+            .is_some_and(|(buffered_block, _)| buffered_block.get_hash() == *block_hash)
+        {
+            buffered.remove(height);
+            in_flight.remove(height);
+        }
+        rejected.insert(block_hash.clone());
+    }
+
+    async fn is_rejected(&self, block_hash: &Hash) -> bool {
+        let rejected = self.rejected.lock().await;
+        rejected.contains(block_hash)
+    }
 }
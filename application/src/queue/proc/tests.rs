@@ -0,0 +1,133 @@
+use super::*;
+use domain::entities::block::{BlockDifficultyTarget, BlockNonce};
+use domain::entities::transaction::{
+    NonValidatedTransaction, TransactionAmount, TransactionOutput,
+};
+use domain::types::time::DateTime;
+use domain::types::wallet::WalletAddress;
+
+fn create_test_hash(seed: usize) -> Hash {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&(seed as u64).to_le_bytes());
+    Hash::new(bytes)
+}
+
+fn create_test_peer_id() -> NetworkPeerId {
+    NetworkPeerId::_new_validated(vec![1], "test-peer".to_string())
+}
+
+fn create_test_block(height: u64, prev_block_hash: Option<Hash>) -> NonValidatedBlock {
+    let recipient = format!("{:0>64}", 1)
+        .parse::<WalletAddress>()
+        .expect("Valid wallet address");
+    let timestamp = DateTime::from_ms(1725799696000);
+    let coinbase = NonValidatedTransaction::new_coinbase(
+        BlockHeight::from(height),
+        vec![TransactionOutput::new(
+            recipient,
+            TransactionAmount::new(50),
+        )],
+        timestamp.clone(),
+    )
+    .unwrap();
+    NonValidatedBlock::new(
+        BlockHeight::from(height),
+        prev_block_hash,
+        BlockNonce::new(0),
+        BlockDifficultyTarget::new(u128::MAX),
+        vec![coinbase],
+        timestamp,
+    )
+    .unwrap()
+}
+
+// ============================================================================
+// RejectedBlockHashes
+// ============================================================================
+
+#[test]
+fn test_rejected_block_hashes_bounded() {
+    let mut rejected = RejectedBlockHashes::default();
+    for seed in 0..RejectedBlockHashes::MAX_REJECTED_BLOCKS * 2 {
+        rejected.insert(create_test_hash(seed));
+    }
+
+    assert_eq!(
+        rejected.hashes.len(),
+        RejectedBlockHashes::MAX_REJECTED_BLOCKS
+    );
+    assert_eq!(
+        rejected.order.len(),
+        RejectedBlockHashes::MAX_REJECTED_BLOCKS
+    );
+}
+
+#[test]
+fn test_rejected_block_hashes_evicts_oldest_first() {
+    let mut rejected = RejectedBlockHashes::default();
+    for seed in 0..RejectedBlockHashes::MAX_REJECTED_BLOCKS {
+        rejected.insert(create_test_hash(seed));
+    }
+    // Re-inserting a remembered hash doesn't refresh it
+    rejected.insert(create_test_hash(0));
+    assert!(rejected.contains(&create_test_hash(0)));
+
+    rejected.insert(create_test_hash(RejectedBlockHashes::MAX_REJECTED_BLOCKS));
+
+    assert!(
+        !rejected.contains(&create_test_hash(0)),
+        "Oldest hash should be evicted"
+    );
+    assert!(rejected.contains(&create_test_hash(1)));
+    assert!(rejected.contains(&create_test_hash(RejectedBlockHashes::MAX_REJECTED_BLOCKS)));
+}
+
+// ============================================================================
+// reject_block()
+// ============================================================================
+
+#[tokio::test]
+async fn test_reject_block_discards_buffered_block() {
+    let queue = DefaultBlockProcessingQueue::new(BlockHeight::from(1));
+    let block = create_test_block(1, Some(create_test_hash(0)));
+    queue.push_block(block.clone(), create_test_peer_id()).await;
+    let (next_block, _) = queue.next_ready_block().await.unwrap();
+    assert_eq!(next_block.get_hash(), block.get_hash());
+
+    queue
+        .reject_block(&block.get_hash(), &block.get_height())
+        .await;
+
+    assert!(queue.is_rejected(&block.get_hash()).await);
+    assert!(queue.next_ready_block().await.is_none());
+
+    // Rejected blocks received again are ignored
+    queue.push_block(block, create_test_peer_id()).await;
+    assert!(queue.next_ready_block().await.is_none());
+}
+
+#[tokio::test]
+async fn test_reject_block_rejects_descendants() {
+    let queue = DefaultBlockProcessingQueue::new(BlockHeight::from(1));
+    let block = create_test_block(1, Some(create_test_hash(0)));
+    let child = create_test_block(2, Some(block.get_hash()));
+    let grandchild = create_test_block(3, Some(child.get_hash()));
+
+    queue
+        .reject_block(&block.get_hash(), &block.get_height())
+        .await;
+    queue.push_block(child.clone(), create_test_peer_id()).await;
+    queue
+        .push_block(grandchild.clone(), create_test_peer_id())
+        .await;
+
+    assert!(
+        queue.is_rejected(&child.get_hash()).await,
+        "Children of rejected blocks should be rejected"
+    );
+    assert!(
+        queue.is_rejected(&grandchild.get_hash()).await,
+        "Rejection should propagate down the branch"
+    );
+    assert!(queue.buffered_blocks.lock().await.is_empty());
+}
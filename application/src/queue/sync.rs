@@ -13,7 +13,6 @@ use tokio::sync::Mutex;
 #[derive(Debug)]
 pub struct DefaultBlockSyncQueue {
     in_progress: Mutex<HashSet<BlockHeight>>,
-    completed: Mutex<HashSet<BlockHeight>>,
     block_proc_queue: Arc<dyn BlockProcessingQueue>,
     cmd_tx: Arc<dyn CommandSender>,
     cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
@@ -27,7 +26,6 @@ impl DefaultBlockSyncQueue {
     ) -> Self {
         Self {
             in_progress: Mutex::new(HashSet::new()),
-            completed: Mutex::new(HashSet::new()),
             block_proc_queue,
             cmd_tx,
             cmd_tx_res_factory,
@@ -57,9 +55,9 @@ impl BlockSyncQueue for DefaultBlockSyncQueue {
         from_peer: NetworkPeerId,
     ) -> Result<(), AppError> {
         let mut in_progress = self.in_progress.lock().await;
-        let completed = self.completed.lock().await;
 
-        if completed.contains(&height) || in_progress.contains(&height) {
+        // Heights may be requested again, their blocks having since been replaced by a fork.
+        if in_progress.contains(&height) {
             return Ok(());
         }
 
@@ -74,16 +72,10 @@ impl BlockSyncQueue for DefaultBlockSyncQueue {
         Ok(())
     }
 
-    async fn on_block_received(&self, block: NonValidatedBlock, _from_peer: NetworkPeerId) {
+    async fn on_block_received(&self, block: NonValidatedBlock, from_peer: NetworkPeerId) {
         let mut in_progress = self.in_progress.lock().await;
-        let mut completed = self.completed.lock().await;
         let height = block.get_height();
 
-        if completed.contains(&height) {
-            return;
-        }
-
-        completed.insert(height.clone());
         in_progress.remove(&height);
 
         let block_hash = block.get_hash();
@@ -91,7 +83,7 @@ impl BlockSyncQueue for DefaultBlockSyncQueue {
         log_app_debug!(
             "BlockSyncQueue.on_block_received() | Pushing block (hash: {block_hash:?}, height: {block_height:?}) to BlockProcessingQueue."
         );
-        self.block_proc_queue.push_block(block).await;
+        self.block_proc_queue.push_block(block, from_peer).await;
     }
 
     async fn is_in_progress(&self, height: &BlockHeight) -> bool {
@@ -18,6 +18,10 @@ pub struct AppState {
     pub get_blockchain_blocks_by_height_range_use_case:
         dev::blockchain::blocks::GetBlockchainBlocksByHeightRangeUseCase,
     pub adhoc_mine_block_use_case: dev::blockchain::blocks::AdHocMineBlockUseCase,
+    pub submit_block_use_case: dev::blockchain::blocks::SubmitBlockUseCase,
+    pub get_block_template_use_case: dev::blockchain::mining::GetBlockTemplateUseCase,
+    pub start_mining_use_case: dev::blockchain::mining::StartMiningUseCase,
+    pub stop_mining_use_case: dev::blockchain::mining::StopMiningUseCase,
     pub get_network_self_info_use_case: dev::network::GetNetworkSelfInfoUseCase,
     pub get_network_peers_use_case: dev::network::GetNetworkPeersUseCase,
     pub add_network_peer_use_case: dev::network::AddNetworkPeerUseCase,
@@ -26,6 +30,8 @@ pub struct AppState {
     pub place_mempool_transaction_use_case:
         dev::transactions::mempool::PlaceMempoolTransactionUseCase,
     pub get_utxos_use_case: dev::transactions::utxo::GetUtxosUseCase,
+    pub get_utxos_by_address_use_case: dev::transactions::utxo::GetUtxosByAddressUseCase,
+    pub get_balance_by_address_use_case: dev::transactions::utxo::GetBalanceByAddressUseCase,
 }
 
 impl AppState {
@@ -55,6 +61,22 @@ impl AppState {
             cmd_tx.clone(),
             cmd_tx_res_factory.clone(),
         );
+        let submit_block_use_case = dev::blockchain::blocks::SubmitBlockUseCase::new(
+            cmd_tx.clone(),
+            cmd_tx_res_factory.clone(),
+        );
+        let get_block_template_use_case = dev::blockchain::mining::GetBlockTemplateUseCase::new(
+            cmd_tx.clone(),
+            cmd_tx_res_factory.clone(),
+        );
+        let start_mining_use_case = dev::blockchain::mining::StartMiningUseCase::new(
+            cmd_tx.clone(),
+            cmd_tx_res_factory.clone(),
+        );
+        let stop_mining_use_case = dev::blockchain::mining::StopMiningUseCase::new(
+            cmd_tx.clone(),
+            cmd_tx_res_factory.clone(),
+        );
         let get_network_self_info_use_case = dev::network::GetNetworkSelfInfoUseCase::new(
             cmd_tx.clone(),
             cmd_tx_res_factory.clone(),
@@ -77,6 +99,15 @@ impl AppState {
             cmd_tx.clone(),
             cmd_tx_res_factory.clone(),
         );
+        let get_utxos_by_address_use_case = dev::transactions::utxo::GetUtxosByAddressUseCase::new(
+            cmd_tx.clone(),
+            cmd_tx_res_factory.clone(),
+        );
+        let get_balance_by_address_use_case =
+            dev::transactions::utxo::GetBalanceByAddressUseCase::new(
+                cmd_tx.clone(),
+                cmd_tx_res_factory.clone(),
+            );
 
         Self {
             // Validation
@@ -90,12 +121,18 @@ impl AppState {
             get_blockchain_block_use_case,
             get_blockchain_blocks_by_height_range_use_case,
             adhoc_mine_block_use_case,
+            submit_block_use_case,
+            get_block_template_use_case,
+            start_mining_use_case,
+            stop_mining_use_case,
             get_network_self_info_use_case,
             get_network_peers_use_case,
             add_network_peer_use_case,
             get_mempool_transactions_use_case,
             place_mempool_transaction_use_case,
             get_utxos_use_case,
+            get_utxos_by_address_use_case,
+            get_balance_by_address_use_case,
         }
     }
 }
@@ -1,4 +1,5 @@
 use domain::repos::blockchain::BlockchainRepository;
+use domain::repos::mempool::MempoolRepository;
 use domain::repos::network::NetworkRepository;
 use domain::repos::outbox::OutboxRepository;
 use domain::repos::utxo::UtxoRepository;
@@ -9,6 +10,8 @@ pub trait Storage {
 
     fn get_utxo_repo(&self) -> Arc<dyn UtxoRepository>;
 
+    fn get_mempool_repo(&self) -> Arc<dyn MempoolRepository>;
+
     fn get_network_repo(&self) -> Arc<dyn NetworkRepository>;
 
     fn get_outbox_repo(&self) -> Arc<dyn OutboxRepository>;
@@ -1,5 +1,7 @@
+use async_trait::async_trait;
 use common::error::AppError;
-use domain::entities::block::{Block, BlockDifficultyTarget, BlockTemplate};
+use domain::entities::block::{Block, BlockTemplate};
+use domain::system::mining::{BlockMiner, DefaultBlockMiner, MiningCancellation};
 use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
 use domain::types::hash::Hash;
 use std::sync::Arc;
@@ -8,6 +10,7 @@ use std::sync::Arc;
 pub struct AdHocMineBlockUseCase {
     cmd_tx: Arc<dyn CommandSender>,
     cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    block_miner: Arc<dyn BlockMiner>,
 }
 
 impl AdHocMineBlockUseCase {
@@ -18,6 +21,7 @@ impl AdHocMineBlockUseCase {
         Self {
             cmd_tx,
             cmd_tx_res_factory,
+            block_miner: Arc::new(DefaultBlockMiner::new()),
         }
     }
 
@@ -47,8 +51,28 @@ impl AdHocMineBlockUseCase {
             .await?
             .ok_or(AppError::internal("Tip block not found!"))?;
 
-        let block_tpl =
-            BlockTemplate::new(&tip_block, transactions, BlockDifficultyTarget::_new_stub());
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_blk_cmd_get_difficulty_target(tip_info.1.next());
+        self.cmd_tx.send(command).await?;
+        let difficulty_target = res_fut.await?;
+
+        let block_tpl = BlockTemplate::new(&tip_block, transactions, difficulty_target);
+
+        let cancellation = TipChangeCancellation {
+            cmd_tx: self.cmd_tx.clone(),
+            cmd_tx_res_factory: self.cmd_tx_res_factory.clone(),
+            tip_hash: tip_block.get_hash(),
+        };
+        let Some(block_tpl) = self
+            .block_miner
+            .mine_block(block_tpl, &cancellation)
+            .await?
+        else {
+            return Err(AppError::conflict(
+                "Blockchain tip changed while mining! Please retry.",
+            ));
+        };
 
         let (command, res_fut) = self
             .cmd_tx_res_factory
@@ -61,6 +85,23 @@ impl AdHocMineBlockUseCase {
     }
 }
 
+/// Cancels mining as soon as the blockchain tip moves past the template's parent block.
+struct TipChangeCancellation {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    tip_hash: Hash,
+}
+
+#[async_trait]
+impl MiningCancellation for TipChangeCancellation {
+    async fn is_cancelled(&self) -> Result<bool, AppError> {
+        let (command, res_fut) = self.cmd_tx_res_factory.build_blk_cmd_get_tip_info();
+        self.cmd_tx.send(command).await?;
+        let tip_info = res_fut.await?;
+        Ok(tip_info.is_none_or(|(tip_hash, _)| tip_hash != self.tip_hash))
+    }
+}
+
 #[derive(Debug)]
 pub struct AdHocMineBlockUseCaseRequest {
     pub transaction_hashes: Vec<Hash>,
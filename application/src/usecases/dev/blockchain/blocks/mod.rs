@@ -1,7 +1,9 @@
 mod adhoc_mine_block;
 mod get_block;
 mod get_blocks;
+mod submit_block;
 
 pub use adhoc_mine_block::*;
 pub use get_block::*;
 pub use get_blocks::*;
+pub use submit_block::*;
@@ -0,0 +1,46 @@
+use common::error::AppError;
+use domain::entities::block::{Block, NonValidatedBlock};
+use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct SubmitBlockUseCase {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl SubmitBlockUseCase {
+    pub fn new(
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: SubmitBlockUseCaseRequest,
+    ) -> Result<SubmitBlockUseCaseResponse, AppError> {
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_blk_cmd_submit_block(request.block);
+        self.cmd_tx.send(command).await?;
+        let block = res_fut.await?;
+
+        let res = SubmitBlockUseCaseResponse { block };
+        Ok(res)
+    }
+}
+
+#[derive(Debug)]
+pub struct SubmitBlockUseCaseRequest {
+    pub block: NonValidatedBlock,
+}
+
+#[derive(Debug)]
+pub struct SubmitBlockUseCaseResponse {
+    pub block: Block,
+}
@@ -0,0 +1,47 @@
+use common::error::AppError;
+use domain::entities::block::BlockTemplate;
+use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
+use domain::types::wallet::WalletAddress;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct GetBlockTemplateUseCase {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl GetBlockTemplateUseCase {
+    pub fn new(
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: GetBlockTemplateUseCaseRequest,
+    ) -> Result<GetBlockTemplateUseCaseResponse, AppError> {
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_blk_cmd_get_block_template(request.reward_address);
+        self.cmd_tx.send(command).await?;
+        let block_template = res_fut.await?;
+
+        let res = GetBlockTemplateUseCaseResponse { block_template };
+        Ok(res)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetBlockTemplateUseCaseRequest {
+    pub reward_address: WalletAddress,
+}
+
+#[derive(Debug)]
+pub struct GetBlockTemplateUseCaseResponse {
+    pub block_template: BlockTemplate,
+}
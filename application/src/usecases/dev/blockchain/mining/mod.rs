@@ -0,0 +1,7 @@
+mod get_block_template;
+mod start_mining;
+mod stop_mining;
+
+pub use get_block_template::*;
+pub use start_mining::*;
+pub use stop_mining::*;
@@ -0,0 +1,46 @@
+use common::error::AppError;
+use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
+use domain::types::wallet::WalletAddress;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StartMiningUseCase {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl StartMiningUseCase {
+    pub fn new(
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: StartMiningUseCaseRequest,
+    ) -> Result<StartMiningUseCaseResponse, AppError> {
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_blk_cmd_start_mining(request.reward_address);
+        self.cmd_tx.send(command).await?;
+        let reward_address = res_fut.await?;
+
+        let res = StartMiningUseCaseResponse { reward_address };
+        Ok(res)
+    }
+}
+
+#[derive(Debug)]
+pub struct StartMiningUseCaseRequest {
+    pub reward_address: Option<WalletAddress>,
+}
+
+#[derive(Debug)]
+pub struct StartMiningUseCaseResponse {
+    pub reward_address: WalletAddress,
+}
@@ -0,0 +1,27 @@
+use common::error::AppError;
+use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StopMiningUseCase {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl StopMiningUseCase {
+    pub fn new(
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<(), AppError> {
+        let (command, res_fut) = self.cmd_tx_res_factory.build_blk_cmd_stop_mining();
+        self.cmd_tx.send(command).await?;
+        res_fut.await
+    }
+}
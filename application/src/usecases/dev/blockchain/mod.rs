@@ -1,4 +1,5 @@
 pub mod blocks;
 mod get_tip;
+pub mod mining;
 
 pub use get_tip::*;
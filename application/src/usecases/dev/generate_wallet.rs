@@ -7,6 +7,12 @@ use domain::types::wallet::WalletAddress;
 #[derive(Clone)]
 pub struct GenerateWalletUseCase;
 
+impl Default for GenerateWalletUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GenerateWalletUseCase {
     pub fn new() -> Self {
         Self {}
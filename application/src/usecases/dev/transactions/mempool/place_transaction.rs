@@ -4,7 +4,7 @@ use domain::entities::transaction::{
     TransactionOutput, Utxo,
 };
 use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
-use domain::types::sign::PrivateKey;
+use domain::types::sign::{PrivateKey, PublicKey};
 use domain::types::time::DateTime;
 use domain::types::wallet::WalletAddress;
 use std::sync::Arc;
@@ -31,24 +31,26 @@ impl PlaceMempoolTransactionUseCase {
         request: PlaceMempoolTransactionUseCaseRequest,
     ) -> Result<PlaceMempoolTransactionUseCaseResponse, AppError> {
         // Build transaction inputs
+        let sender_public_key = request.sender_private_key.get_public_key();
         let utxos = self.get_utxos(request.consumed_outpoints).await?;
         let input_amount = self.get_input_amount(&utxos)?;
-        let inputs = self.build_inputs(utxos);
+        let inputs = self.build_inputs(utxos, &sender_public_key);
 
         // Build transaction outputs
-        // Assuming no tx fee, private key passed in for dev convenience...
+        // Private key passed in for dev convenience...
         let output_amount = request.amount;
-        let sender_public_key = request.sender_private_key.get_public_key();
         let sender_address = (&sender_public_key).into();
         let outputs = self.build_outputs(
             sender_address,
             request.recipient_wallet_address,
             input_amount,
             output_amount,
+            request.fee,
         )?;
 
-        // Build transaction (pre-validation)
-        let tx = NonValidatedTransaction::new(inputs, outputs, DateTime::now())?;
+        // Build and sign transaction (pre-validation)
+        let tx = NonValidatedTransaction::new(inputs, outputs, DateTime::now())?
+            .sign(&request.sender_private_key)?;
 
         let (command, res_fut) = self.cmd_tx_res_factory.build_mp_cmd_place_transaction(tx);
         self.cmd_tx.send(command).await?;
@@ -86,12 +88,16 @@ impl PlaceMempoolTransactionUseCase {
             })
     }
 
-    fn build_inputs(&self, utxos: Vec<Utxo>) -> Vec<TransactionInput> {
+    fn build_inputs(
+        &self,
+        utxos: Vec<Utxo>,
+        sender_public_key: &PublicKey,
+    ) -> Vec<TransactionInput> {
         utxos
             .into_iter()
             .map(|outpoint| {
                 let prev_outpoint = outpoint.get_outpoint().clone();
-                TransactionInput::new(prev_outpoint)
+                TransactionInput::new(prev_outpoint, sender_public_key.clone())
             })
             .collect()
     }
@@ -102,10 +108,15 @@ impl PlaceMempoolTransactionUseCase {
         recipient_address: WalletAddress,
         input_amount: TransactionAmount,
         output_amount: TransactionAmount,
+        fee: TransactionAmount,
     ) -> Result<Vec<TransactionOutput>, AppError> {
+        // The fee is left unclaimed by the outputs, for the coinbase to collect.
         let change_amount = input_amount
             .checked_sub(output_amount)
-            .ok_or_else(|| AppError::bad_request("Input amount is less than output amount!"))?;
+            .and_then(|amount| amount.checked_sub(fee))
+            .ok_or_else(|| {
+                AppError::bad_request("Input amount is less than output amount plus fee!")
+            })?;
 
         let primary_output = TransactionOutput::new(recipient_address, output_amount);
 
@@ -123,6 +134,7 @@ pub struct PlaceMempoolTransactionUseCaseRequest {
     pub sender_private_key: PrivateKey, // purely for dev convenience...
     pub recipient_wallet_address: WalletAddress,
     pub amount: TransactionAmount,
+    pub fee: TransactionAmount,
     pub consumed_outpoints: Vec<TransactionOutPoint>,
 }
 
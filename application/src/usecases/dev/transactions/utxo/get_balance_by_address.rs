@@ -0,0 +1,46 @@
+use common::error::AppError;
+use domain::entities::transaction::TransactionAmount;
+use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
+use domain::types::wallet::WalletAddress;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct GetBalanceByAddressUseCase {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl GetBalanceByAddressUseCase {
+    pub fn new(
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: GetBalanceByAddressUseCaseRequest,
+    ) -> Result<GetBalanceByAddressUseCaseResponse, AppError> {
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_utxo_cmd_get_balance_by_address(request.address);
+        self.cmd_tx.send(command).await?;
+        let balance = res_fut.await?;
+        let res = GetBalanceByAddressUseCaseResponse { balance };
+        Ok(res)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetBalanceByAddressUseCaseRequest {
+    pub address: WalletAddress,
+}
+
+#[derive(Debug)]
+pub struct GetBalanceByAddressUseCaseResponse {
+    pub balance: TransactionAmount,
+}
@@ -0,0 +1,46 @@
+use common::error::AppError;
+use domain::entities::transaction::Utxo;
+use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
+use domain::types::wallet::WalletAddress;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct GetUtxosByAddressUseCase {
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl GetUtxosByAddressUseCase {
+    pub fn new(
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: GetUtxosByAddressUseCaseRequest,
+    ) -> Result<GetUtxosByAddressUseCaseResponse, AppError> {
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_utxo_cmd_get_utxos_by_address(request.address);
+        self.cmd_tx.send(command).await?;
+        let utxos = res_fut.await?;
+        let res = GetUtxosByAddressUseCaseResponse { utxos };
+        Ok(res)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetUtxosByAddressUseCaseRequest {
+    pub address: WalletAddress,
+}
+
+#[derive(Debug)]
+pub struct GetUtxosByAddressUseCaseResponse {
+    pub utxos: Vec<Utxo>,
+}
@@ -1,3 +1,7 @@
+mod get_balance_by_address;
 mod get_utxos;
+mod get_utxos_by_address;
 
+pub use get_balance_by_address::*;
 pub use get_utxos::*;
+pub use get_utxos_by_address::*;
@@ -2,18 +2,109 @@ use crate::error::AppError;
 use serde::Deserialize;
 
 #[derive(Clone, Debug, Deserialize, Default)]
-pub struct PartialNodeConfig {}
+pub struct PartialNodeConfig {
+    pub target_block_interval_secs: Option<u64>,
+    pub coinbase_maturity: Option<u64>,
+    pub max_future_block_drift_secs: Option<u64>,
+    pub min_relay_fee_rate: Option<u64>,
+    pub max_mempool_size_bytes: Option<u64>,
+    pub replace_by_fee_enabled: Option<bool>,
+    pub max_replacement_evictions: Option<u64>,
+    pub mempool_expiry_secs: Option<u64>,
+    pub mining_enabled: Option<bool>,
+    pub mining_reward_address: Option<String>,
+}
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct NodeConfig {}
+pub struct NodeConfig {
+    pub target_block_interval_secs: u64,
+    pub coinbase_maturity: u64,
+    pub max_future_block_drift_secs: u64,
+    pub min_relay_fee_rate: u64, // per encoded byte
+    pub max_mempool_size_bytes: u64,
+    pub replace_by_fee_enabled: bool,
+    pub max_replacement_evictions: u64,
+    pub mempool_expiry_secs: u64,
+    pub mining_enabled: bool,
+    pub mining_reward_address: Option<String>, // WalletAddress
+}
 
 impl NodeConfig {
-    #[allow(unused)]
+    const DEFAULT_TARGET_BLOCK_INTERVAL_SECS: u64 = 30;
+    const DEFAULT_COINBASE_MATURITY: u64 = 100;
+    const DEFAULT_MAX_FUTURE_BLOCK_DRIFT_SECS: u64 = 600;
+    const DEFAULT_MIN_RELAY_FEE_RATE: u64 = 1;
+    const DEFAULT_MAX_MEMPOOL_SIZE_BYTES: u64 = 16 * 1024 * 1024;
+    const DEFAULT_REPLACE_BY_FEE_ENABLED: bool = false;
+    const DEFAULT_MAX_REPLACEMENT_EVICTIONS: u64 = 100;
+    const DEFAULT_MEMPOOL_EXPIRY_SECS: u64 = 14 * 24 * 60 * 60;
+    const DEFAULT_MINING_ENABLED: bool = false;
+
     pub(super) fn from_parts(
         base: PartialNodeConfig,
         overrides: PartialNodeConfig,
     ) -> Result<Self, AppError> {
-        let config = NodeConfig {};
+        let target_block_interval_secs = overrides
+            .target_block_interval_secs
+            .or(base.target_block_interval_secs)
+            .unwrap_or(Self::DEFAULT_TARGET_BLOCK_INTERVAL_SECS);
+
+        let coinbase_maturity = overrides
+            .coinbase_maturity
+            .or(base.coinbase_maturity)
+            .unwrap_or(Self::DEFAULT_COINBASE_MATURITY);
+
+        let max_future_block_drift_secs = overrides
+            .max_future_block_drift_secs
+            .or(base.max_future_block_drift_secs)
+            .unwrap_or(Self::DEFAULT_MAX_FUTURE_BLOCK_DRIFT_SECS);
+
+        let min_relay_fee_rate = overrides
+            .min_relay_fee_rate
+            .or(base.min_relay_fee_rate)
+            .unwrap_or(Self::DEFAULT_MIN_RELAY_FEE_RATE);
+
+        let max_mempool_size_bytes = overrides
+            .max_mempool_size_bytes
+            .or(base.max_mempool_size_bytes)
+            .unwrap_or(Self::DEFAULT_MAX_MEMPOOL_SIZE_BYTES);
+
+        let replace_by_fee_enabled = overrides
+            .replace_by_fee_enabled
+            .or(base.replace_by_fee_enabled)
+            .unwrap_or(Self::DEFAULT_REPLACE_BY_FEE_ENABLED);
+
+        let max_replacement_evictions = overrides
+            .max_replacement_evictions
+            .or(base.max_replacement_evictions)
+            .unwrap_or(Self::DEFAULT_MAX_REPLACEMENT_EVICTIONS);
+
+        let mempool_expiry_secs = overrides
+            .mempool_expiry_secs
+            .or(base.mempool_expiry_secs)
+            .unwrap_or(Self::DEFAULT_MEMPOOL_EXPIRY_SECS);
+
+        let mining_enabled = overrides
+            .mining_enabled
+            .or(base.mining_enabled)
+            .unwrap_or(Self::DEFAULT_MINING_ENABLED);
+
+        let mining_reward_address = overrides
+            .mining_reward_address
+            .or(base.mining_reward_address);
+
+        let config = NodeConfig {
+            target_block_interval_secs,
+            coinbase_maturity,
+            max_future_block_drift_secs,
+            min_relay_fee_rate,
+            max_mempool_size_bytes,
+            replace_by_fee_enabled,
+            max_replacement_evictions,
+            mempool_expiry_secs,
+            mining_enabled,
+            mining_reward_address,
+        };
         Ok(config)
     }
 }
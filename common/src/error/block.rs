@@ -5,6 +5,12 @@ pub enum BlockValidationError {
     #[error("Invalid merkle root: expected {expected}, got {actual}")]
     InvalidMerkleRoot { expected: String, actual: String },
 
+    #[error("Invalid witness merkle root: expected {expected}, got {actual}")]
+    InvalidWitnessMerkleRoot { expected: String, actual: String },
+
+    #[error("Invalid block hash: expected {expected}, got {actual}")]
+    InvalidBlockHash { expected: String, actual: String },
+
     #[error("Block contains no transactions")]
     NoTransactions,
 
@@ -22,15 +28,62 @@ pub enum BlockValidationError {
         blockchain_tip_hash: Option<String>,
     },
 
+    #[error("Invalid block height: expected {expected}, got {actual}")]
+    InvalidBlockHeight { expected: u64, actual: u64 },
+
     #[error("Block already known: {hash}")]
     BlockAlreadyKnown { hash: String },
 
     #[error("Multiple coinbase transactions found")]
     MultipleCoinbaseTransactions,
 
+    #[error("Coinbase transaction must be the first transaction in the block")]
+    MisplacedCoinbaseTransaction,
+
+    #[error(
+        "Coinbase transaction commits to height {actual:?} instead of the block's ({expected})"
+    )]
+    InvalidCoinbaseHeight { expected: u64, actual: Option<u64> },
+
+    #[error("Coinbase outputs ({outputs}) exceed block subsidy plus fees ({limit})")]
+    ExcessiveCoinbaseValue { outputs: u128, limit: u128 },
+
     #[error("Invalid timestamp: {reason}")]
     InvalidTimestamp { reason: String },
 
+    #[error("Block timestamp ({timestamp}) exceeds the allowed future drift ({max_timestamp})")]
+    FutureTimestamp { timestamp: u64, max_timestamp: u64 },
+
     #[error("Block size exceeds limit: {size} > {limit}")]
     BlockSizeExceeded { size: usize, limit: usize },
+
+    #[error("Block transaction count exceeds limit: {count} > {limit}")]
+    TooManyTransactions { count: usize, limit: usize },
+}
+
+impl BlockValidationError {
+    /// Whether the block may pass validation later on, as opposed to being invalid for good.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            // The tip may move on, or the parent may yet arrive.
+            BlockValidationError::ContinuityMismatch { .. } => true,
+            // The local clock will eventually catch up.
+            BlockValidationError::FutureTimestamp { .. } => true,
+            BlockValidationError::BlockAlreadyKnown { .. } => true,
+            BlockValidationError::InvalidMerkleRoot { .. }
+            | BlockValidationError::InvalidWitnessMerkleRoot { .. }
+            | BlockValidationError::InvalidBlockHash { .. }
+            | BlockValidationError::NoTransactions
+            | BlockValidationError::DuplicateTransactions
+            | BlockValidationError::GenesisAlreadyExists
+            | BlockValidationError::InvalidBlockHeight { .. }
+            | BlockValidationError::MultipleCoinbaseTransactions
+            | BlockValidationError::MisplacedCoinbaseTransaction
+            | BlockValidationError::InvalidCoinbaseHeight { .. }
+            | BlockValidationError::ExcessiveCoinbaseValue { .. }
+            | BlockValidationError::InvalidTimestamp { .. }
+            | BlockValidationError::BlockSizeExceeded { .. }
+            | BlockValidationError::TooManyTransactions { .. } => false,
+        }
+    }
 }
@@ -80,6 +80,18 @@ impl AppError {
         }
     }
 
+    /// Whether the failure may resolve itself on retry.<br />
+    /// Validation failures are only transient if the entity may become valid later on.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            AppError::BlockValidation(block_err) => block_err.is_transient(),
+            AppError::TransactionValidation(_)
+            | AppError::ConsensusValidation(_)
+            | AppError::Cryptographic(_) => false,
+            _ => true,
+        }
+    }
+
     pub fn get_public_info(&self) -> String {
         match self {
             AppError::BlockValidation(block_err) => block_err.to_string(),
@@ -20,6 +20,54 @@ pub enum TransactionValidationError {
     #[error("Invalid signature for input {index}")]
     InvalidSignature { tx_id: String, index: usize },
 
+    #[error("Public key for input {index} does not match the spent output's recipient")]
+    PublicKeyMismatch { tx_id: String, index: usize },
+
+    #[error("Transaction fee ({fee}) is below the minimum required fee ({min_fee})")]
+    InsufficientFee {
+        tx_id: String,
+        fee: u128,
+        min_fee: u128,
+    },
+
+    #[error("Outpoint {outpoint} is already spent by mempool transaction {conflicting_tx_id}")]
+    MempoolConflict {
+        tx_id: String,
+        outpoint: String,
+        conflicting_tx_id: String,
+    },
+
+    #[error("Mempool is full, transaction fee rate too low to evict pending transactions")]
+    MempoolFull { tx_id: String },
+
+    #[error(
+        "Replacement fee ({fee}) must exceed the fees of the replaced transactions ({replaced_fee})"
+    )]
+    ReplacementInsufficientFee {
+        tx_id: String,
+        fee: u128,
+        replaced_fee: u128,
+    },
+
+    #[error("Replacement fee rate must exceed that of mempool transaction {conflicting_tx_id}")]
+    ReplacementInsufficientFeeRate {
+        tx_id: String,
+        conflicting_tx_id: String,
+    },
+
+    #[error("Replacement would evict {evictions} mempool transactions (maximum: {max_evictions})")]
+    ReplacementTooManyEvictions {
+        tx_id: String,
+        evictions: usize,
+        max_evictions: usize,
+    },
+
+    #[error("Replacement spends outputs of mempool transaction {conflicting_tx_id} it replaces")]
+    ReplacementSpendsConflict {
+        tx_id: String,
+        conflicting_tx_id: String,
+    },
+
     #[error("Zero or negative amount in output {index}")]
     InvalidOutputAmount { tx_id: String, index: usize },
 
@@ -28,4 +76,20 @@ pub enum TransactionValidationError {
 
     #[error("Transaction must have at least one output")]
     EmptyOutputs { tx_id: String },
+
+    #[error("Outpoint {outpoint} is spent by more than one input")]
+    DuplicateInput { tx_id: String, outpoint: String },
+
+    #[error("Transaction id {tx_id} does not match its contents ({computed})")]
+    InvalidTransactionId { tx_id: String, computed: String },
+
+    #[error("Only coinbase transactions may commit to a block height")]
+    UnexpectedCoinbaseHeight { tx_id: String },
+
+    #[error("Immature coinbase output spent: {outpoint} (spendable at height {spendable_height})")]
+    ImmatureCoinbaseSpend {
+        tx_id: String,
+        outpoint: String,
+        spendable_height: u64,
+    },
 }
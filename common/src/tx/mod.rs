@@ -24,6 +24,7 @@ impl AtomicTransactionOutput {
 }
 
 pub trait UnitOfWork: Send + Sync {
+    #[allow(clippy::type_complexity)]
     fn run_in_transaction(
         &self,
         f: Box<
@@ -1,13 +1,45 @@
+use crate::types::hash::Hash;
 use bincode::{Decode, Encode};
 
-#[derive(Clone, Debug, Encode, Decode)]
+/// Proof-of-work target a block hash must not exceed.<br />
+/// Compared against the 128 most significant bits of the hash (big-endian).<br />
+/// Lower targets mean higher difficulty.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
 pub struct BlockDifficultyTarget(pub(super) u128);
 
 impl BlockDifficultyTarget {
+    /// Target expecting roughly 2^16 hashing attempts per block.
+    const INITIAL: u128 = u128::MAX >> 16;
+
+    pub fn new(target: u128) -> Self {
+        Self(target)
+    }
+
+    /// The default target used when no retargeting information is available.
+    pub fn initial() -> Self {
+        Self(Self::INITIAL)
+    }
+
     pub fn as_u128(&self) -> u128 {
         self.0
     }
 
+    /// Expected number of hashing attempts required to meet the target.<br />
+    /// Accumulated along a chain, it determines the heaviest one.
+    pub fn get_work(&self) -> u128 {
+        match self.0.checked_add(1) {
+            Some(divisor) => ((u128::MAX - self.0) / divisor).saturating_add(1),
+            None => 1,
+        }
+    }
+
+    /// Checks whether the provided block hash satisfies the target.
+    pub fn is_met_by(&self, hash: &Hash) -> bool {
+        let mut prefix = [0u8; 16];
+        prefix.copy_from_slice(&hash.as_bytes()[..16]);
+        u128::from_be_bytes(prefix) <= self.0
+    }
+
     /// **Unimplemented Stub**
     pub fn _new_stub() -> Self {
         Self(0)
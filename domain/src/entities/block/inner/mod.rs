@@ -1,5 +1,7 @@
 use crate::entities::block::{BlockDifficultyTarget, BlockHeight, BlockNonce};
-use crate::entities::transaction::{NonValidatedTransaction, Transaction, TransactionsMerkleRoot};
+use crate::entities::transaction::{
+    NonValidatedTransaction, Transaction, TransactionAmount, TransactionsMerkleRoot,
+};
 use crate::ext::AppErrorConvertibleDomain;
 use crate::types::hash::{Hash, TryHashable};
 use crate::types::time::DateTime;
@@ -14,6 +16,7 @@ pub(super) struct InnerBlock {
     pub(super) nonce: BlockNonce,
     pub(super) difficulty_target: BlockDifficultyTarget,
     pub(super) transactions_merkle_root: TransactionsMerkleRoot,
+    pub(super) witness_merkle_root: TransactionsMerkleRoot,
     pub(super) transactions: Vec<Transaction>,
     pub(super) timestamp: DateTime,
 }
@@ -25,6 +28,7 @@ pub(super) struct NonValidatedInnerBlock {
     pub(super) nonce: BlockNonce,
     pub(super) difficulty_target: BlockDifficultyTarget,
     pub(super) transactions_merkle_root: TransactionsMerkleRoot,
+    pub(super) witness_merkle_root: TransactionsMerkleRoot,
     pub(super) transactions: Vec<NonValidatedTransaction>,
     pub(super) timestamp: DateTime,
 }
@@ -37,6 +41,7 @@ impl InnerBlock {
             nonce: self.nonce,
             difficulty_target: self.difficulty_target,
             transactions_merkle_root: self.transactions_merkle_root,
+            witness_merkle_root: self.witness_merkle_root,
             transactions: self
                 .transactions
                 .into_iter()
@@ -48,11 +53,18 @@ impl InnerBlock {
 
     /// Internal method to construct a validated [`InnerBlock`] from a [`NonValidatedInnerBlock`].<br />
     /// Called exclusively post-validation.
-    pub(super) fn _new_validated(inner: NonValidatedInnerBlock) -> InnerBlock {
+    pub(super) fn _new_validated(
+        inner: NonValidatedInnerBlock,
+        fees: Vec<TransactionAmount>,
+    ) -> InnerBlock {
+        let fees = fees
+            .into_iter()
+            .chain(std::iter::repeat(TransactionAmount::new(0)));
         let transactions = inner
             .transactions
             .into_iter()
-            .map(Transaction::_new_validated)
+            .zip(fees)
+            .map(|(tx, fee)| Transaction::_new_validated(tx, fee))
             .collect();
         Self {
             height: inner.height,
@@ -60,6 +72,7 @@ impl InnerBlock {
             nonce: inner.nonce,
             difficulty_target: inner.difficulty_target,
             transactions_merkle_root: inner.transactions_merkle_root,
+            witness_merkle_root: inner.witness_merkle_root,
             transactions,
             timestamp: inner.timestamp,
         }
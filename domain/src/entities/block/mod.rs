@@ -3,16 +3,19 @@ mod height;
 mod inner;
 mod nonce;
 mod template;
+mod undo;
 
 pub use difficulty::BlockDifficultyTarget;
 pub use height::BlockHeight;
 pub use nonce::BlockNonce;
 pub use template::BlockTemplate;
+pub use undo::BlockUndo;
 
 use crate::encode::{TryDecode, TryEncode};
 use crate::entities::block::inner::{InnerBlock, NonValidatedInnerBlock};
 use crate::entities::transaction::{
-    NonValidatedTransaction, Transaction, TransactionOutput, TransactionsMerkleRoot,
+    NonValidatedTransaction, Transaction, TransactionAmount, TransactionOutput,
+    TransactionsMerkleRoot,
 };
 use crate::ext::AppErrorConvertibleDomain;
 use crate::types::hash::{Hash, TryHashable};
@@ -33,6 +36,13 @@ pub struct NonValidatedBlock {
 }
 
 impl Block {
+    /// Consensus limit on the encoded size of a block (in bytes).<br />
+    /// Kept well below the network codec's message size cap.
+    pub const MAX_ENCODED_SIZE: usize = 512 * 1024;
+
+    /// Consensus limit on the number of transactions in a block, coinbase included.
+    pub const MAX_TRANSACTIONS: usize = 2_500;
+
     /// Flips a validated [`Block`] into a [`NonValidatedBlock`].<br />
     pub fn invalidate(self) -> NonValidatedBlock {
         NonValidatedBlock {
@@ -42,11 +52,22 @@ impl Block {
     }
 
     /// Internal method to construct a validated [`Block`] from a [`NonValidatedBlock`].<br />
-    /// Called exclusively post-validation.
+    /// Called exclusively post-validation.<br />
+    /// Transactions are assigned no fees, see [`Block::_new_validated_with_fees`].
     pub(crate) fn _new_validated(block: NonValidatedBlock) -> Block {
+        Self::_new_validated_with_fees(block, Vec::new())
+    }
+
+    /// Internal method to construct a validated [`Block`] from a [`NonValidatedBlock`].<br />
+    /// Called exclusively post-validation, with the fees of the block's transactions (in order).<br />
+    /// Transactions lacking a fee entry are assigned none.
+    pub(crate) fn _new_validated_with_fees(
+        block: NonValidatedBlock,
+        fees: Vec<TransactionAmount>,
+    ) -> Block {
         Self {
             hash: block.hash,
-            data: InnerBlock::_new_validated(block.data),
+            data: InnerBlock::_new_validated(block.data, fees),
         }
     }
 }
@@ -60,16 +81,22 @@ impl NonValidatedBlock {
             .iter()
             .map(|utxo| {
                 let tx_output = TransactionOutput::new((&utxo.wallet_pub_key).into(), utxo.amount);
-                NonValidatedTransaction::new(vec![], vec![tx_output], cfg.timestamp.clone())
+                NonValidatedTransaction::new_coinbase(
+                    BlockHeight::genesis(),
+                    vec![tx_output],
+                    cfg.timestamp.clone(),
+                )
             })
             .collect::<Result<Vec<NonValidatedTransaction>, AppError>>()?;
         let transactions_merkle_root = TransactionsMerkleRoot::new_non_validated(&transactions)?;
+        let witness_merkle_root = TransactionsMerkleRoot::new_witness_non_validated(&transactions)?;
         let data = NonValidatedInnerBlock {
             height: BlockHeight::genesis(),
             prev_block_hash: None,
             nonce: BlockNonce::default(),
             difficulty_target: BlockDifficultyTarget(0),
             transactions_merkle_root,
+            witness_merkle_root,
             transactions,
             timestamp: cfg.timestamp,
         };
@@ -78,22 +105,49 @@ impl NonValidatedBlock {
         Ok(block)
     }
 
-    pub(crate) fn from_template(block_tpl: BlockTemplate) -> Result<Self, AppError> {
-        let transactions_merkle_root =
-            TransactionsMerkleRoot::new_non_validated(&block_tpl.transactions)?;
+    /// Constructs a block from its header fields and transactions.<br />
+    /// Merkle roots and the block hash are derived from the provided contents.
+    pub fn new(
+        height: BlockHeight,
+        prev_block_hash: Option<Hash>,
+        nonce: BlockNonce,
+        difficulty_target: BlockDifficultyTarget,
+        transactions: Vec<NonValidatedTransaction>,
+        timestamp: DateTime,
+    ) -> Result<Self, AppError> {
+        let transactions_merkle_root = TransactionsMerkleRoot::new_non_validated(&transactions)?;
+        let witness_merkle_root = TransactionsMerkleRoot::new_witness_non_validated(&transactions)?;
         let data = NonValidatedInnerBlock {
-            height: block_tpl.block_height,
-            prev_block_hash: block_tpl.prev_block_hash,
-            nonce: block_tpl.nonce,
-            difficulty_target: block_tpl.difficulty_target,
+            height,
+            prev_block_hash,
+            nonce,
+            difficulty_target,
             transactions_merkle_root,
-            transactions: block_tpl.transactions,
-            timestamp: DateTime::now(),
+            witness_merkle_root,
+            transactions,
+            timestamp,
         };
         let hash = data.try_hash()?;
         let block = Self { hash, data };
         Ok(block)
     }
+
+    pub fn from_template(block_tpl: BlockTemplate) -> Result<Self, AppError> {
+        Self::new(
+            block_tpl.block_height,
+            block_tpl.prev_block_hash,
+            block_tpl.nonce,
+            block_tpl.difficulty_target,
+            block_tpl.transactions,
+            block_tpl.timestamp,
+        )
+    }
+
+    /// Recomputes the block hash from its contents.<br />
+    /// Used to confirm the declared hash hasn't been tampered with.
+    pub(crate) fn compute_hash(&self) -> Result<Hash, AppError> {
+        self.data.try_hash()
+    }
 }
 
 impl Block {
@@ -121,6 +175,10 @@ impl Block {
         &self.data.transactions_merkle_root
     }
 
+    pub fn get_witness_merkle_root(&self) -> &TransactionsMerkleRoot {
+        &self.data.witness_merkle_root
+    }
+
     pub fn get_transactions(&self) -> &Vec<Transaction> {
         &self.data.transactions
     }
@@ -159,6 +217,10 @@ impl NonValidatedBlock {
         &self.data.transactions_merkle_root
     }
 
+    pub fn get_witness_merkle_root(&self) -> &TransactionsMerkleRoot {
+        &self.data.witness_merkle_root
+    }
+
     pub fn get_transactions(&self) -> &Vec<NonValidatedTransaction> {
         &self.data.transactions
     }
@@ -180,6 +242,14 @@ impl TryEncode for Block {
     }
 }
 
+impl TryEncode for NonValidatedBlock {
+    fn try_encode(&self) -> Result<Vec<u8>, AppError> {
+        let config = bincode::config::standard();
+        let data = bincode::encode_to_vec(self, config).to_app_error()?;
+        Ok(data)
+    }
+}
+
 impl TryDecode for Block {
     fn try_decode(data: &[u8]) -> Result<Self, AppError> {
         let config = bincode::config::standard();
@@ -187,3 +257,11 @@ impl TryDecode for Block {
         Ok(data)
     }
 }
+
+impl TryDecode for NonValidatedBlock {
+    fn try_decode(data: &[u8]) -> Result<Self, AppError> {
+        let config = bincode::config::standard();
+        let (data, _): (Self, usize) = bincode::decode_from_slice(data, config).to_app_error()?;
+        Ok(data)
+    }
+}
@@ -4,6 +4,10 @@ use bincode::{Decode, Encode};
 pub struct BlockNonce(pub(super) u64);
 
 impl BlockNonce {
+    pub fn new(nonce: u64) -> Self {
+        Self(nonce)
+    }
+
     pub fn as_u64(&self) -> u64 {
         self.0
     }
@@ -1,10 +1,12 @@
 use crate::entities::block::Block;
 use crate::entities::block::difficulty::BlockDifficultyTarget;
 use crate::entities::block::height::BlockHeight;
+use crate::entities::block::inner::NonValidatedInnerBlock;
 use crate::entities::block::nonce::BlockNonce;
-use crate::entities::transaction::NonValidatedTransaction;
-use crate::types::hash::Hash;
+use crate::entities::transaction::{NonValidatedTransaction, TransactionsMerkleRoot};
+use crate::types::hash::{Hash, TryHashable};
 use crate::types::time::DateTime;
+use common::error::AppError;
 
 #[derive(Debug)]
 pub struct BlockTemplate {
@@ -13,7 +15,6 @@ pub struct BlockTemplate {
     pub(super) nonce: BlockNonce,
     pub(super) difficulty_target: BlockDifficultyTarget,
     pub(super) transactions: Vec<NonValidatedTransaction>,
-    #[allow(unused)]
     pub(super) timestamp: DateTime,
 }
 
@@ -33,9 +34,68 @@ impl BlockTemplate {
         }
     }
 
-    #[allow(unused)]
+    pub fn get_height(&self) -> BlockHeight {
+        self.block_height.clone()
+    }
+
+    pub fn get_prev_block_hash(&self) -> Option<Hash> {
+        self.prev_block_hash.clone()
+    }
+
+    pub fn get_nonce(&self) -> BlockNonce {
+        self.nonce.clone()
+    }
+
+    pub fn get_difficulty_target(&self) -> BlockDifficultyTarget {
+        self.difficulty_target.clone()
+    }
+
+    pub fn get_transactions(&self) -> &Vec<NonValidatedTransaction> {
+        &self.transactions
+    }
+
+    pub fn get_timestamp(&self) -> DateTime {
+        self.timestamp.clone()
+    }
+
+    /// Searches up to `max_attempts` nonces for a block hash meeting the difficulty target.<br />
+    /// On success, the template retains the nonce and timestamp that produced the winning hash.
+    pub(crate) fn try_solve(&mut self, max_attempts: u64) -> Result<bool, AppError> {
+        let transactions_merkle_root =
+            TransactionsMerkleRoot::new_non_validated(&self.transactions)?;
+        let witness_merkle_root =
+            TransactionsMerkleRoot::new_witness_non_validated(&self.transactions)?;
+        let mut data = NonValidatedInnerBlock {
+            height: self.block_height.clone(),
+            prev_block_hash: self.prev_block_hash.clone(),
+            nonce: self.nonce.clone(),
+            difficulty_target: self.difficulty_target.clone(),
+            transactions_merkle_root,
+            witness_merkle_root,
+            transactions: std::mem::take(&mut self.transactions),
+            timestamp: self.timestamp.clone(),
+        };
+
+        let mut search = || {
+            for _ in 0..max_attempts {
+                let hash = data.try_hash()?;
+                if self.difficulty_target.is_met_by(&hash) {
+                    return Ok(true);
+                }
+                self.increment_nonce();
+                data.nonce = self.nonce.clone();
+                data.timestamp = self.timestamp.clone();
+            }
+            Ok(false)
+        };
+        let res = search();
+
+        self.transactions = data.transactions;
+        res
+    }
+
     pub(crate) fn increment_nonce(&mut self) {
-        self.nonce.0 += 1;
+        self.nonce.0 = self.nonce.0.wrapping_add(1);
         self.update_timestamp();
     }
 
@@ -43,3 +103,11 @@ impl BlockTemplate {
         self.timestamp = DateTime::now();
     }
 }
+
+/// Expose internal methods for unit testing.
+#[cfg(test)]
+impl BlockTemplate {
+    pub(crate) fn set_timestamp(&mut self, timestamp: DateTime) {
+        self.timestamp = timestamp;
+    }
+}
@@ -0,0 +1,39 @@
+use crate::encode::{TryDecode, TryEncode};
+use crate::entities::transaction::Utxo;
+use crate::ext::AppErrorConvertibleDomain;
+use bincode::{Decode, Encode};
+use common::error::AppError;
+
+/// Records the UTXO set state consumed by a block.<br />
+/// Allows the block to be disconnected from the UTXO set.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct BlockUndo {
+    /// Outputs spent by the block's transactions, in order of appearance.
+    spent_utxos: Vec<Utxo>,
+}
+
+impl BlockUndo {
+    pub fn new(spent_utxos: Vec<Utxo>) -> Self {
+        Self { spent_utxos }
+    }
+
+    pub fn get_spent_utxos(&self) -> &[Utxo] {
+        &self.spent_utxos
+    }
+}
+
+impl TryEncode for BlockUndo {
+    fn try_encode(&self) -> Result<Vec<u8>, AppError> {
+        let config = bincode::config::standard();
+        let data = bincode::encode_to_vec(self, config).to_app_error()?;
+        Ok(data)
+    }
+}
+
+impl TryDecode for BlockUndo {
+    fn try_decode(data: &[u8]) -> Result<Self, AppError> {
+        let config = bincode::config::standard();
+        let (data, _): (Self, usize) = bincode::decode_from_slice(data, config).to_app_error()?;
+        Ok(data)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::entities::transaction::{TransactionInput, TransactionOutput};
+use crate::entities::block::BlockHeight;
+use crate::entities::transaction::{SighashOutputs, TransactionInput, TransactionOutput};
 use crate::ext::AppErrorConvertibleDomain;
 use crate::types::hash::{Hash, TryHashable};
 use crate::types::time::DateTime;
@@ -11,21 +12,96 @@ pub(crate) struct InnerTransaction {
     pub(super) inputs: Vec<TransactionInput>,
     pub(super) outputs: Vec<TransactionOutput>,
     pub(super) timestamp: DateTime,
+    /// Height of the block a coinbase transaction belongs to, keeping coinbase ids unique.<br />
+    /// Always `None` for regular transactions.
+    pub(super) coinbase_height: Option<BlockHeight>,
 }
 
-impl TryHashable for InnerTransaction {
-    fn try_hash(&self) -> Result<Hash, AppError> {
+/// The transaction data committed to by an input signature.
+#[derive(Encode)]
+struct SighashPreimage {
+    /// Every input but the signed one, unless signing with `ANYONECANPAY`.
+    inputs: Vec<TransactionInput>,
+    signed_input: TransactionInput,
+    outputs: Vec<TransactionOutput>,
+    timestamp: DateTime,
+}
+
+impl InnerTransaction {
+    /// Computes the hash committed to by the signature of the given input.<br />
+    /// The covered parts depend on the input's sighash type.<br />
+    /// Returns `None` if the sighash type can't be satisfied (`SINGLE` lacking a matching output).
+    pub(super) fn compute_sighash(&self, input_index: usize) -> Result<Option<Hash>, AppError> {
+        let Some(signed_input) = self.inputs.get(input_index) else {
+            return Err(AppError::internal(format!(
+                "Transaction input index out of bounds! | Index: {input_index}"
+            )));
+        };
+        let sighash_type = signed_input.get_sighash_type();
+
+        let inputs = if sighash_type.is_anyone_can_pay() {
+            vec![]
+        } else {
+            self.inputs
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != input_index)
+                .map(|(_, input)| input.to_unsigned())
+                .collect()
+        };
+        let outputs = match sighash_type.get_outputs() {
+            SighashOutputs::All => self.outputs.clone(),
+            SighashOutputs::None => vec![],
+            SighashOutputs::Single => match self.outputs.get(input_index) {
+                Some(output) => vec![output.clone()],
+                None => return Ok(None),
+            },
+        };
+
+        let preimage = SighashPreimage {
+            inputs,
+            signed_input: signed_input.to_unsigned(),
+            outputs,
+            timestamp: self.timestamp.clone(),
+        };
         let config = bincode::config::standard();
-        let serialized_bytes = bincode::encode_to_vec(self, config).to_app_error()?;
+        let serialized_bytes = bincode::encode_to_vec(&preimage, config).to_app_error()?;
+        Ok(Some(hash_bytes(&serialized_bytes)))
+    }
 
-        let mut hasher = Blake2b512::new();
-        hasher.update(&serialized_bytes);
-        let result = hasher.finalize();
-        let bytes = result.as_slice();
+    /// Computes the hash of the whole transaction, signatures included.
+    pub(super) fn compute_witness_hash(&self) -> Result<Hash, AppError> {
+        let config = bincode::config::standard();
+        let serialized_bytes = bincode::encode_to_vec(self, config).to_app_error()?;
+        Ok(hash_bytes(&serialized_bytes))
+    }
+}
 
-        let mut hash_bytes = [0u8; 32];
-        hash_bytes.copy_from_slice(&bytes[..32]);
-        let hash = Hash::new(hash_bytes);
-        Ok(hash)
+/// Computes the transaction id.<br />
+/// Signatures are excluded, preventing third parties from altering the id of a signed transaction.
+impl TryHashable for InnerTransaction {
+    fn try_hash(&self) -> Result<Hash, AppError> {
+        let unsigned_tx = Self {
+            inputs: self
+                .inputs
+                .iter()
+                .map(|input| input.to_unsigned())
+                .collect(),
+            outputs: self.outputs.clone(),
+            timestamp: self.timestamp.clone(),
+            coinbase_height: self.coinbase_height.clone(),
+        };
+        unsigned_tx.compute_witness_hash()
     }
 }
+
+fn hash_bytes(bytes: &[u8]) -> Hash {
+    let mut hasher = Blake2b512::new();
+    hasher.update(bytes);
+    let result = hasher.finalize();
+    let bytes = result.as_slice();
+
+    let mut hash_bytes = [0u8; 32];
+    hash_bytes.copy_from_slice(&bytes[..32]);
+    Hash::new(hash_bytes)
+}
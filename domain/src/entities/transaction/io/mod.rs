@@ -1,6 +1,7 @@
 use crate::encode::{TryDecode, TryEncode};
-use crate::entities::transaction::{TransactionAmount, TransactionOutPoint};
+use crate::entities::transaction::{SighashType, TransactionAmount, TransactionOutPoint};
 use crate::ext::AppErrorConvertibleDomain;
+use crate::types::sign::{PublicKey, Signature};
 use crate::types::wallet::WalletAddress;
 use bincode::{Decode, Encode};
 use common::error::AppError;
@@ -8,7 +9,9 @@ use common::error::AppError;
 #[derive(Clone, Debug, Encode, Decode)]
 pub struct TransactionInput {
     previous_output: TransactionOutPoint,
-    // pub(super) signature: Signature, // TODO
+    public_key: PublicKey,
+    sighash_type: SighashType,
+    signature: Option<Signature>,
 }
 
 #[derive(Clone, Debug, Encode, Decode)]
@@ -18,13 +21,70 @@ pub struct TransactionOutput {
 }
 
 impl TransactionInput {
-    pub fn new(previous_output: TransactionOutPoint) -> Self {
-        Self { previous_output }
+    /// Constructs an unsigned input, spending an output owned by the given public key.<br />
+    /// The signature will commit to the whole transaction ([`SighashType::ALL`]).<br />
+    /// See [`NonValidatedTransaction::sign()`](crate::entities::transaction::NonValidatedTransaction::sign).
+    pub fn new(previous_output: TransactionOutPoint, public_key: PublicKey) -> Self {
+        Self::new_with_sighash_type(previous_output, public_key, SighashType::ALL)
+    }
+
+    /// Constructs an unsigned input, its signature committing to the parts selected by `sighash_type`.
+    pub fn new_with_sighash_type(
+        previous_output: TransactionOutPoint,
+        public_key: PublicKey,
+        sighash_type: SighashType,
+    ) -> Self {
+        Self {
+            previous_output,
+            public_key,
+            sighash_type,
+            signature: None,
+        }
+    }
+
+    pub fn new_signed(
+        previous_output: TransactionOutPoint,
+        public_key: PublicKey,
+        sighash_type: SighashType,
+        signature: Signature,
+    ) -> Self {
+        Self {
+            previous_output,
+            public_key,
+            sighash_type,
+            signature: Some(signature),
+        }
     }
 
     pub fn get_previous_output(&self) -> &TransactionOutPoint {
         &self.previous_output
     }
+
+    pub fn get_public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn get_sighash_type(&self) -> SighashType {
+        self.sighash_type
+    }
+
+    pub(super) fn set_signature(&mut self, signature: Signature) {
+        self.signature = Some(signature);
+    }
+
+    pub fn get_signature(&self) -> Option<&Signature> {
+        self.signature.as_ref()
+    }
+
+    /// Clones the input, stripping its signature.
+    pub(super) fn to_unsigned(&self) -> Self {
+        Self {
+            previous_output: self.previous_output.clone(),
+            public_key: self.public_key.clone(),
+            sighash_type: self.sighash_type,
+            signature: None,
+        }
+    }
 }
 
 impl TransactionOutput {
@@ -37,7 +97,7 @@ impl TransactionOutput {
     }
 
     pub fn get_amount(&self) -> TransactionAmount {
-        self.amount.clone()
+        self.amount
     }
 }
 
@@ -38,6 +38,34 @@ impl TransactionsMerkleRoot {
         Ok(Self(hash))
     }
 
+    /// Constructs a new witness [`TransactionsMerkleRoot`] from a slice of transactions.<br />
+    /// Leaves are witness hashes, committing to signature data excluded from transaction ids.<br />
+    /// Returns an error if the input slice is empty.
+    pub fn new_witness(transactions: &[Transaction]) -> Result<Self, AppError> {
+        let tx_leaves = transactions
+            .iter()
+            .map(|tx| tx.compute_witness_hash())
+            .collect::<Result<Vec<_>, AppError>>()?;
+        let merkle_tree = MerkleTree::new(&tx_leaves)?;
+        let hash = merkle_tree.get_root().clone();
+        Ok(Self(hash))
+    }
+
+    /// Constructs a new witness [`TransactionsMerkleRoot`] from a slice of non-validated transactions.<br />
+    /// Leaves are witness hashes, committing to signature data excluded from transaction ids.<br />
+    /// Returns an error if the input slice is empty.
+    pub fn new_witness_non_validated(
+        transactions: &[NonValidatedTransaction],
+    ) -> Result<Self, AppError> {
+        let tx_leaves = transactions
+            .iter()
+            .map(|tx| tx.compute_witness_hash())
+            .collect::<Result<Vec<_>, AppError>>()?;
+        let merkle_tree = MerkleTree::new(&tx_leaves)?;
+        let hash = merkle_tree.get_root().clone();
+        Ok(Self(hash))
+    }
+
     pub fn inner(&self) -> &Hash {
         &self.0
     }
@@ -58,7 +86,7 @@ impl MerkleTree {
 
         // Pad the layer if it has an odd number of nodes.
         // Unless it's the final root node.
-        if current_layer.len() % 2 != 0 {
+        if !current_layer.len().is_multiple_of(2) {
             current_layer.push(current_layer.last().unwrap().clone());
         }
 
@@ -3,26 +3,32 @@ pub(in super::super::entities) mod inner;
 mod io;
 mod merkle;
 mod outpoint;
+mod sighash;
 mod utxo;
 
 pub use amount::TransactionAmount;
 pub use io::{TransactionInput, TransactionOutput};
 pub use merkle::TransactionsMerkleRoot;
 pub use outpoint::TransactionOutPoint;
+pub use sighash::{SighashOutputs, SighashType};
 pub use utxo::Utxo;
 
 use crate::encode::{TryDecode, TryEncode};
+use crate::entities::block::BlockHeight;
 use crate::entities::transaction::inner::InnerTransaction;
 use crate::ext::AppErrorConvertibleDomain;
 use crate::types::hash::{Hash, TryHashable};
+use crate::types::sign::PrivateKey;
 use crate::types::time::DateTime;
 use bincode::{Decode, Encode};
-use common::error::AppError;
+use common::error::{AppError, TransactionValidationError};
 
 #[derive(Clone, Debug, Encode, Decode)]
 pub struct Transaction {
     hash: Hash,
     data: InnerTransaction,
+    /// Not part of the transaction data, computed against the UTXO set during validation.
+    fee: TransactionAmount,
 }
 
 #[derive(Clone, Debug, Encode, Decode)]
@@ -33,11 +39,15 @@ pub struct NonValidatedTransaction {
 
 impl Transaction {
     /// Internal method to construct a validated [`Transaction`] from a [`NonValidatedTransaction`].<br />
-    /// Called exclusively post-validation.
-    pub(crate) fn _new_validated(tx: NonValidatedTransaction) -> Transaction {
+    /// Called exclusively post-validation, once the fee has been computed.
+    pub(crate) fn _new_validated(
+        tx: NonValidatedTransaction,
+        fee: TransactionAmount,
+    ) -> Transaction {
         Self {
             hash: tx.hash,
             data: tx.data,
+            fee,
         }
     }
 }
@@ -48,11 +58,30 @@ impl NonValidatedTransaction {
         outputs: Vec<TransactionOutput>,
         timestamp: DateTime,
     ) -> Result<Self, AppError> {
-        let inner_tx = InnerTransaction {
+        Self::from_inner(InnerTransaction {
             inputs,
             outputs,
             timestamp,
-        };
+            coinbase_height: None,
+        })
+    }
+
+    /// Constructs a coinbase transaction for the block at the given height.<br />
+    /// Committing to the height keeps coinbase ids unique across blocks (see BIP34).
+    pub fn new_coinbase(
+        block_height: BlockHeight,
+        outputs: Vec<TransactionOutput>,
+        timestamp: DateTime,
+    ) -> Result<Self, AppError> {
+        Self::from_inner(InnerTransaction {
+            inputs: Vec::new(),
+            outputs,
+            timestamp,
+            coinbase_height: Some(block_height),
+        })
+    }
+
+    fn from_inner(inner_tx: InnerTransaction) -> Result<Self, AppError> {
         let hash = inner_tx.try_hash()?;
         let tx = Self {
             hash,
@@ -60,6 +89,26 @@ impl NonValidatedTransaction {
         };
         Ok(tx)
     }
+
+    /// Signs every input spending an output owned by the given private key.<br />
+    /// Inputs owned by other keys are left as is, for their owners to sign.<br />
+    /// Signatures don't affect the transaction id.
+    pub fn sign(mut self, private_key: &PrivateKey) -> Result<Self, AppError> {
+        let public_key = private_key.get_public_key();
+        for index in 0..self.data.inputs.len() {
+            if *self.data.inputs[index].get_public_key() != public_key {
+                continue;
+            }
+            let Some(sighash) = self.data.compute_sighash(index)? else {
+                return Err(AppError::bad_request(format!(
+                    "Input {index} signs a SINGLE output, but no output matches its index!"
+                )));
+            };
+            let signature = private_key.sign(sighash.as_bytes());
+            self.data.inputs[index].set_signature(signature);
+        }
+        Ok(self)
+    }
 }
 
 impl Transaction {
@@ -79,11 +128,35 @@ impl Transaction {
         self.data.timestamp.clone()
     }
 
-    #[allow(unused)]
+    /// The height of the block a coinbase transaction belongs to, `None` for regular transactions.
+    pub fn get_coinbase_height(&self) -> Option<&BlockHeight> {
+        self.data.coinbase_height.as_ref()
+    }
+
+    /// The amount by which the transaction's inputs exceed its outputs, claimable by the coinbase.<br />
+    /// Coinbase transactions pay no fee.<br />
+    /// Transactions of side branch blocks report no fee until their branch gets connected.
+    pub fn get_fee(&self) -> TransactionAmount {
+        self.fee
+    }
+
     pub(crate) fn is_coinbase_tx(&self) -> bool {
         self.data.inputs.is_empty()
     }
 
+    /// Computes the hash of the whole transaction, signatures included.<br />
+    /// Unlike the transaction id, it commits to signature data.
+    pub fn compute_witness_hash(&self) -> Result<Hash, AppError> {
+        self.data.compute_witness_hash()
+    }
+
+    /// Computes the size of the transaction's network encoding (in bytes).<br />
+    /// Fee rates are expressed per encoded byte.
+    pub fn compute_encoded_size(&self) -> Result<usize, AppError> {
+        let size = self.clone().invalidate().try_encode()?.len();
+        Ok(size)
+    }
+
     pub fn invalidate(self) -> NonValidatedTransaction {
         NonValidatedTransaction {
             hash: self.hash,
@@ -112,6 +185,61 @@ impl NonValidatedTransaction {
     pub(crate) fn is_coinbase_tx(&self) -> bool {
         self.data.inputs.is_empty()
     }
+
+    /// The height of the block a coinbase transaction belongs to, `None` for regular transactions.
+    pub fn get_coinbase_height(&self) -> Option<&BlockHeight> {
+        self.data.coinbase_height.as_ref()
+    }
+
+    /// Recomputes the transaction id from its contents, ensuring it matches the declared one.<br />
+    /// Transactions received from peers may declare any id.
+    pub(crate) fn validate_hash(&self) -> Result<(), AppError> {
+        let computed_hash = self.data.try_hash()?;
+        if self.hash != computed_hash {
+            return Err(AppError::TransactionValidation(
+                TransactionValidationError::InvalidTransactionId {
+                    tx_id: self.hash.to_string(),
+                    computed: computed_hash.to_string(),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Computes the hash committed to by the signature of the given input.<br />
+    /// Returns `None` if the input's sighash type can't be satisfied.
+    pub(crate) fn compute_sighash(&self, input_index: usize) -> Result<Option<Hash>, AppError> {
+        self.data.compute_sighash(input_index)
+    }
+
+    /// Computes the hash of the whole transaction, signatures included.<br />
+    /// Unlike the transaction id, it commits to signature data.
+    pub fn compute_witness_hash(&self) -> Result<Hash, AppError> {
+        self.data.compute_witness_hash()
+    }
+
+    /// Computes the fee paid by the transaction, given the UTXOs spent by its inputs.
+    pub(crate) fn compute_fee(&self, input_utxos: &[Utxo]) -> Result<TransactionAmount, AppError> {
+        let mut input_sum = TransactionAmount::new(0);
+        for utxo in input_utxos {
+            input_sum.checked_add_assign(utxo.get_output().get_amount())?;
+        }
+
+        let mut output_sum = TransactionAmount::new(0);
+        for txout in self.get_outputs() {
+            output_sum.checked_add_assign(txout.get_amount())?;
+        }
+
+        input_sum
+            .checked_sub(output_sum)
+            .ok_or(AppError::TransactionValidation(
+                TransactionValidationError::OutputsExceedInputs {
+                    tx_id: self.hash.to_string(),
+                    inputs: input_sum.as_u128(),
+                    outputs: output_sum.as_u128(),
+                },
+            ))
+    }
 }
 
 impl TryEncode for Transaction {
@@ -122,6 +250,14 @@ impl TryEncode for Transaction {
     }
 }
 
+impl TryEncode for NonValidatedTransaction {
+    fn try_encode(&self) -> Result<Vec<u8>, AppError> {
+        let config = bincode::config::standard();
+        let data = bincode::encode_to_vec(self, config).to_app_error()?;
+        Ok(data)
+    }
+}
+
 impl TryDecode for Transaction {
     fn try_decode(data: &[u8]) -> Result<Self, AppError> {
         let config = bincode::config::standard();
@@ -0,0 +1,95 @@
+use bincode::{Decode, Encode};
+use common::error::AppError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Determines which parts of a transaction an input signature commits to.<br />
+/// Allows for partially constructed and multi-party transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct SighashType {
+    outputs: SighashOutputs,
+    anyone_can_pay: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum SighashOutputs {
+    /// Commits to all outputs.
+    All,
+    /// Commits to no outputs, leaving them up to other parties.
+    None,
+    /// Commits to the output sharing the input's index.
+    Single,
+}
+
+impl SighashType {
+    pub const ALL: Self = Self::new(SighashOutputs::All, false);
+    pub const NONE: Self = Self::new(SighashOutputs::None, false);
+    pub const SINGLE: Self = Self::new(SighashOutputs::Single, false);
+
+    pub const fn new(outputs: SighashOutputs, anyone_can_pay: bool) -> Self {
+        Self {
+            outputs,
+            anyone_can_pay,
+        }
+    }
+
+    /// Only commits to the signed input, allowing others to add their own inputs.
+    pub const fn with_anyone_can_pay(self) -> Self {
+        Self::new(self.outputs, true)
+    }
+
+    pub fn get_outputs(&self) -> SighashOutputs {
+        self.outputs
+    }
+
+    pub fn is_anyone_can_pay(&self) -> bool {
+        self.anyone_can_pay
+    }
+}
+
+impl Default for SighashType {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl fmt::Display for SighashType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outputs = match self.outputs {
+            SighashOutputs::All => "ALL",
+            SighashOutputs::None => "NONE",
+            SighashOutputs::Single => "SINGLE",
+        };
+        if self.anyone_can_pay {
+            write!(f, "{outputs}|ANYONECANPAY")
+        } else {
+            write!(f, "{outputs}")
+        }
+    }
+}
+
+impl FromStr for SighashType {
+    type Err = AppError;
+
+    /// Parses the [`Display`](fmt::Display) representation, e.g. `SINGLE|ANYONECANPAY`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (outputs, anyone_can_pay) = match s.split_once('|') {
+            Some((outputs, "ANYONECANPAY")) => (outputs, true),
+            Some(_) => {
+                return Err(AppError::bad_request(format!(
+                    "Invalid sighash type modifier: {s}"
+                )));
+            }
+            None => (s, false),
+        };
+        let outputs = match outputs {
+            "ALL" => SighashOutputs::All,
+            "NONE" => SighashOutputs::None,
+            "SINGLE" => SighashOutputs::Single,
+            _ => {
+                return Err(AppError::bad_request(format!("Invalid sighash type: {s}")));
+            }
+        };
+        Ok(Self::new(outputs, anyone_can_pay))
+    }
+}
@@ -1,14 +1,33 @@
+use crate::encode::{TryDecode, TryEncode};
+use crate::entities::block::BlockHeight;
 use crate::entities::transaction::{TransactionOutPoint, TransactionOutput};
+use crate::ext::AppErrorConvertibleDomain;
+use bincode::{Decode, Encode};
+use common::error::AppError;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encode, Decode)]
 pub struct Utxo {
     outpoint: TransactionOutPoint,
     output: TransactionOutput,
+    /// Height of the block featuring the creating transaction.
+    block_height: BlockHeight,
+    /// Whether the output was minted by a coinbase transaction.
+    coinbase: bool,
 }
 
 impl Utxo {
-    pub fn new(outpoint: TransactionOutPoint, output: TransactionOutput) -> Utxo {
-        Utxo { outpoint, output }
+    pub fn new(
+        outpoint: TransactionOutPoint,
+        output: TransactionOutput,
+        block_height: BlockHeight,
+        coinbase: bool,
+    ) -> Utxo {
+        Utxo {
+            outpoint,
+            output,
+            block_height,
+            coinbase,
+        }
     }
 
     pub fn get_outpoint(&self) -> &TransactionOutPoint {
@@ -18,4 +37,28 @@ impl Utxo {
     pub fn get_output(&self) -> &TransactionOutput {
         &self.output
     }
+
+    pub fn get_block_height(&self) -> &BlockHeight {
+        &self.block_height
+    }
+
+    pub fn is_coinbase(&self) -> bool {
+        self.coinbase
+    }
+}
+
+impl TryEncode for Utxo {
+    fn try_encode(&self) -> Result<Vec<u8>, AppError> {
+        let config = bincode::config::standard();
+        let data = bincode::encode_to_vec(self, config).to_app_error()?;
+        Ok(data)
+    }
+}
+
+impl TryDecode for Utxo {
+    fn try_decode(data: &[u8]) -> Result<Self, AppError> {
+        let config = bincode::config::standard();
+        let (data, _): (Self, usize) = bincode::decode_from_slice(data, config).to_app_error()?;
+        Ok(data)
+    }
 }
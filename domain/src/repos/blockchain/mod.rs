@@ -7,6 +7,8 @@ use std::fmt::Debug;
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
+// Explicit lifetimes are required by mockall.
+#[allow(clippy::needless_lifetimes)]
 #[cfg_attr(test, mockall::automock)]
 pub trait BlockchainRepository: Send + Sync + Debug {
     fn get_blockchain_append_block_unit_of_work(&self) -> Arc<dyn UnitOfWork>;
@@ -53,6 +55,33 @@ pub trait BlockchainRepository: Send + Sync + Debug {
         block_hash: &Hash,
     ) -> Result<(), AppError>;
 
+    fn delete_height<'a>(
+        &self,
+        tx_ctx: Option<&'a dyn AtomicTransactionContext>,
+        height: &BlockHeight,
+    ) -> Result<(), AppError>;
+
+    /// Retrieves the cumulative proof-of-work of the chain ending at the given block.
+    fn get_chain_work<'a>(
+        &self,
+        tx_ctx: Option<&'a dyn AtomicTransactionContext>,
+        hash: &Hash,
+    ) -> Result<Option<u128>, AppError>;
+
+    fn insert_chain_work<'a>(
+        &self,
+        tx_ctx: Option<&'a dyn AtomicTransactionContext>,
+        hash: &Hash,
+        chain_work: u128,
+    ) -> Result<(), AppError>;
+
+    /// Checks whether a block was marked as invalid.
+    fn is_invalid_block(&self, hash: &Hash) -> Result<bool, AppError>;
+
+    /// Marks a block as invalid.<br />
+    /// Invalid blocks, alongside their descendants, may never become canon.
+    fn insert_invalid_block(&self, hash: &Hash) -> Result<(), AppError>;
+
     fn get_tip<'a>(
         &self,
         tx_ctx: Option<&'a dyn AtomicTransactionContext>,
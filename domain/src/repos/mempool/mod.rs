@@ -0,0 +1,16 @@
+use crate::entities::transaction::Transaction;
+use crate::types::time::DateTime;
+use common::error::AppError;
+use std::fmt::Debug;
+
+pub trait MempoolRepository: Send + Sync + Debug {
+    /// Replaces any previously persisted transactions.<br />
+    /// Each transaction is persisted alongside the time it was added to the mempool at.<br />
+    /// Transactions are persisted in the given order, which is preserved upon retrieval.
+    fn replace_transactions(
+        &self,
+        transactions: Vec<(Transaction, DateTime)>,
+    ) -> Result<(), AppError>;
+
+    fn get_transactions(&self) -> Result<Vec<(Transaction, DateTime)>, AppError>;
+}
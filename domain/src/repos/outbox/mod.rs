@@ -3,6 +3,8 @@ use common::error::AppError;
 use common::tx::ctx::AtomicTransactionContext;
 use std::fmt::Debug;
 
+// Explicit lifetimes are required by mockall.
+#[allow(clippy::needless_lifetimes)]
 #[cfg_attr(test, mockall::automock)]
 pub trait OutboxRepository: Send + Sync + Debug {
     fn insert_entry<'a>(
@@ -1,23 +1,31 @@
-use crate::entities::transaction::{TransactionOutPoint, TransactionOutput, Utxo};
+use crate::entities::block::BlockUndo;
+use crate::entities::transaction::{TransactionOutPoint, Utxo};
+use crate::types::hash::Hash;
+use crate::types::wallet::WalletAddress;
 use common::error::AppError;
 use common::tx::UnitOfWork;
 use common::tx::ctx::AtomicTransactionContext;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+// Explicit lifetimes are required by mockall.
+#[allow(clippy::needless_lifetimes)]
 #[cfg_attr(test, mockall::automock)]
 pub trait UtxoRepository: Send + Sync + Debug {
     fn get_utxo_set_append_block_unit_of_work(&self) -> Arc<dyn UnitOfWork>;
 
-    fn get_output<'a>(
+    fn get_utxo<'a>(
         &self,
         tx_ctx: Option<&'a dyn AtomicTransactionContext>,
         outpoint: &TransactionOutPoint,
-    ) -> Result<Option<TransactionOutput>, AppError>;
+    ) -> Result<Option<Utxo>, AppError>;
 
     // TODO: pagination
     fn get_multiple_utxos(&self) -> Result<Vec<Utxo>, AppError>;
 
+    /// Retrieves the UTXOs paying the given address, backed by a secondary address index.
+    fn get_utxos_by_address(&self, address: &WalletAddress) -> Result<Vec<Utxo>, AppError>;
+
     fn insert_utxo<'a>(
         &self,
         tx_ctx: Option<&'a dyn AtomicTransactionContext>,
@@ -31,4 +39,23 @@ pub trait UtxoRepository: Send + Sync + Debug {
     ) -> Result<(), AppError>;
 
     fn get_utxo_count(&self) -> usize;
+
+    fn get_block_undo<'a>(
+        &self,
+        tx_ctx: Option<&'a dyn AtomicTransactionContext>,
+        block_hash: &Hash,
+    ) -> Result<Option<BlockUndo>, AppError>;
+
+    fn insert_block_undo<'a>(
+        &self,
+        tx_ctx: Option<&'a dyn AtomicTransactionContext>,
+        block_hash: &Hash,
+        block_undo: BlockUndo,
+    ) -> Result<(), AppError>;
+
+    fn delete_block_undo<'a>(
+        &self,
+        tx_ctx: Option<&'a dyn AtomicTransactionContext>,
+        block_hash: &Hash,
+    ) -> Result<(), AppError>;
 }
@@ -17,13 +17,31 @@ use tokio::sync::Mutex;
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub(crate) trait Blockchain: Send + Sync + std::fmt::Debug {
+    /// Stores a block extending either the canon chain or one of its side branches.<br />
+    /// Side branch blocks outweighing the canon chain trigger a reorganization.
     async fn add_block(&self, block: Block) -> Result<(), AppError>;
+    /// Overwrites an already stored block.<br />
+    /// Persists side branch transaction fees, only known once their branch gets connected.
+    async fn replace_block(&self, block: &Block) -> Result<(), AppError>;
+    /// Marks a block extending the canon chain as its new tip.
     async fn set_tip(&self, block: &Block) -> Result<(), AppError>;
+    /// Rewinds the canon chain down to one of its blocks, dropping any blocks above it.
+    async fn rewind_tip(&self, block: &Block) -> Result<(), AppError>;
+    /// Retrieves the cumulative proof-of-work of the chain ending at the given block.
+    async fn get_chain_work(&self, hash: &Hash) -> Result<Option<u128>, AppError>;
+    /// Retrieves the side branch ending at the given block.<br />
+    /// Returns the canon block it forks off of, alongside the branch blocks (ascending height).
+    async fn get_branch(&self, hash: &Hash) -> Result<(Block, Vec<Block>), AppError>;
+    /// Marks a side branch block as invalid.<br />
+    /// Neither it nor its descendants will be reorganized onto.
+    async fn invalidate_block(&self, hash: &Hash) -> Result<(), AppError>;
+    async fn is_invalid_block(&self, hash: &Hash) -> Result<bool, AppError>;
     async fn get_tip_info(&self) -> Result<Option<(Hash, BlockHeight)>, AppError>;
-    async fn get_unknown_block_heights(
-        &self,
-        remote_tip_info: (Hash, BlockHeight),
-    ) -> Result<Option<RangeInclusive<BlockHeight>>, AppError>;
+    /// Builds a block locator for the canon chain: block hashes from the tip back to genesis.<br />
+    /// Hashes are dense near the tip, growing exponentially sparse further back.
+    async fn get_block_locator(&self) -> Result<Vec<Hash>, AppError>;
+    /// Retrieves the height of the first block locator hash found on the canon chain.
+    async fn find_fork_point(&self, locator: &[Hash]) -> Result<Option<BlockHeight>, AppError>;
     async fn has_canon_block(&self, hash: &Hash) -> Result<bool, AppError>;
     async fn has_known_block(&self, hash: &Hash) -> Result<bool, AppError>;
     async fn get_canon_block(&self, hash: &Hash) -> Result<Option<Block>, AppError>;
@@ -62,29 +80,66 @@ impl Blockchain for DefaultBlockchain {
         // Ensure previously validated blocks remain aligned with the latest blockchain state.
         let tip_info = self.get_tip_info().await?;
         let block_prev_hash = block.get_prev_block_hash();
-        if block_prev_hash.as_ref() != tip_info.as_ref().map(|tip_info| &tip_info.0) {
+        let parent_chain_work = match block_prev_hash.as_ref() {
+            Some(prev_hash) => self.blockchain_repo.get_chain_work(None, prev_hash)?,
+            // Only the genesis block may start a chain.
+            None if tip_info.is_none() => Some(0),
+            None => None,
+        };
+        let Some(parent_chain_work) = parent_chain_work else {
             return Err(AppError::BlockValidation(
                 BlockValidationError::ContinuityMismatch {
                     block_prev_hash: block_prev_hash.map(|h| h.to_string()),
                     blockchain_tip_hash: tip_info.as_ref().map(|tip_info| tip_info.0.to_string()),
                 },
             ));
+        };
+        let chain_work = parent_chain_work.saturating_add(block.get_difficulty_target().get_work());
+
+        let extends_tip = block_prev_hash.as_ref() == tip_info.as_ref().map(|tip_info| &tip_info.0);
+        if extends_tip {
+            self.append_block(block, chain_work).await?;
+            log_blk_info!(
+                "Blockchain.add_block(): Blockchain successfully appended block ({}) ",
+                block_hash
+            );
+            return Ok(());
         }
 
-        self.append_block(block).await?;
+        let tip_chain_work = match tip_info.as_ref() {
+            Some((tip_hash, _)) => self.get_chain_work(tip_hash).await?.unwrap_or_default(),
+            None => 0,
+        };
+        let reorganize = chain_work > tip_chain_work;
+        self.insert_side_branch_block(block, chain_work, reorganize)
+            .await?;
 
         log_blk_info!(
-            "Blockchain.add_block(): Blockchain successfully appended block ({}) ",
-            block_hash
+            "Blockchain.add_block(): Blockchain successfully stored side branch block ({}) | Reorganizing: {}",
+            block_hash,
+            reorganize
         );
         Ok(())
     }
 
+    async fn replace_block(&self, block: &Block) -> Result<(), AppError> {
+        let block_hash = block.get_hash();
+        if !self.has_known_block(&block_hash).await? {
+            return Err(AppError::internal(format!(
+                "Cannot replace unknown block ({})!",
+                block_hash
+            )));
+        }
+        self.blockchain_repo.insert_block(None, block)
+    }
+
     async fn set_tip(&self, block: &Block) -> Result<(), AppError> {
         log_blk_info!("Blockchain.set_tip() | block: {:?}", &block);
 
         let block_hash = block.get_hash();
         let block_height = block.get_height();
+        self.blockchain_repo
+            .insert_height(None, block_height.clone(), &block_hash)?;
         self.blockchain_repo.set_tip(None, &block_hash)?;
         *self.tip_cache.lock().await = Some((block_hash, block_height));
 
@@ -95,6 +150,74 @@ impl Blockchain for DefaultBlockchain {
         Ok(())
     }
 
+    async fn rewind_tip(&self, block: &Block) -> Result<(), AppError> {
+        log_blk_info!("Blockchain.rewind_tip() | block: {:?}", &block);
+
+        let Some((_, tip_height)) = self.get_tip_info().await? else {
+            return Err(AppError::internal("Cannot rewind an empty blockchain!"));
+        };
+        let block_hash = block.get_hash();
+        let block_height = block.get_height();
+        if !self.has_canon_block(&block_hash).await? {
+            return Err(AppError::internal(format!(
+                "Cannot rewind blockchain to non-canon block ({})!",
+                block_hash
+            )));
+        }
+
+        self.blockchain_repo.set_tip(None, &block_hash)?;
+        *self.tip_cache.lock().await = Some((block_hash.clone(), block_height.clone()));
+        let mut height = block_height;
+        while height < tip_height {
+            height = height.next();
+            self.blockchain_repo.delete_height(None, &height)?;
+        }
+
+        log_blk_info!(
+            "Blockchain.rewind_tip(): Blockchain tip successfully rewound to block ({}) ",
+            block_hash
+        );
+        Ok(())
+    }
+
+    async fn get_chain_work(&self, hash: &Hash) -> Result<Option<u128>, AppError> {
+        self.blockchain_repo.get_chain_work(None, hash)
+    }
+
+    async fn get_branch(&self, hash: &Hash) -> Result<(Block, Vec<Block>), AppError> {
+        let mut branch = Vec::new();
+        let mut hash = hash.clone();
+        loop {
+            let Some(block) = self.get_known_block(&hash).await? else {
+                return Err(AppError::internal(format!(
+                    "Missing block ({}) while walking back branch!",
+                    hash
+                )));
+            };
+            if let Some(block) = self.block_if_canon(block.clone()).await? {
+                branch.reverse();
+                return Ok((block, branch));
+            }
+            let Some(prev_hash) = block.get_prev_block_hash() else {
+                return Err(AppError::internal(format!(
+                    "Branch block ({}) doesn't connect to the canon chain!",
+                    block.get_hash()
+                )));
+            };
+            branch.push(block);
+            hash = prev_hash;
+        }
+    }
+
+    async fn invalidate_block(&self, hash: &Hash) -> Result<(), AppError> {
+        log_blk_info!("Blockchain.invalidate_block() | hash: {}", hash);
+        self.blockchain_repo.insert_invalid_block(hash)
+    }
+
+    async fn is_invalid_block(&self, hash: &Hash) -> Result<bool, AppError> {
+        self.blockchain_repo.is_invalid_block(hash)
+    }
+
     async fn get_tip_info(&self) -> Result<Option<(Hash, BlockHeight)>, AppError> {
         if let Some(cached) = self.tip_cache.lock().await.as_ref() {
             return Ok(Some(cached.clone()));
@@ -112,24 +235,41 @@ impl Blockchain for DefaultBlockchain {
         Ok(Some(tip))
     }
 
-    async fn get_unknown_block_heights(
-        &self,
-        remote_tip_info: (Hash, BlockHeight),
-    ) -> Result<Option<RangeInclusive<BlockHeight>>, AppError> {
-        let (_, remote_tip_height) = remote_tip_info;
-        let local_tip_info = self.get_tip_info().await?;
-
-        let Some((_, local_tip_height)) = local_tip_info else {
-            return Ok(Some(BlockHeight::genesis()..=remote_tip_height));
+    async fn get_block_locator(&self) -> Result<Vec<Hash>, AppError> {
+        let Some((_, tip_height)) = self.get_tip_info().await? else {
+            return Ok(Vec::new());
         };
 
-        // TODO: handle reorgs...
-
-        if remote_tip_height <= local_tip_height {
-            return Ok(None);
+        let mut locator = Vec::new();
+        let mut height = tip_height.as_u64();
+        let mut step = 1;
+        loop {
+            let hash = self
+                .blockchain_repo
+                .get_block_hash_by_height(None, &BlockHeight::from(height))?
+                .ok_or_else(|| {
+                    AppError::internal(format!("Missing canon block hash at height {height}"))
+                })?;
+            locator.push(hash);
+
+            if height == 0 {
+                break;
+            }
+            if locator.len() >= Self::LOCATOR_DENSE_LEN {
+                step *= 2;
+            }
+            height = height.saturating_sub(step);
         }
+        Ok(locator)
+    }
 
-        Ok(Some(local_tip_height.next()..=remote_tip_height))
+    async fn find_fork_point(&self, locator: &[Hash]) -> Result<Option<BlockHeight>, AppError> {
+        for hash in locator {
+            if let Some(block) = self.get_canon_block(hash).await? {
+                return Ok(Some(block.get_height()));
+            }
+        }
+        Ok(None)
     }
 
     async fn has_canon_block(&self, hash: &Hash) -> Result<bool, AppError> {
@@ -238,6 +378,9 @@ impl Blockchain for DefaultBlockchain {
 }
 
 impl DefaultBlockchain {
+    /// Number of consecutive tip hashes leading a block locator before its step starts doubling.
+    const LOCATOR_DENSE_LEN: usize = 10;
+
     pub fn new(
         blockchain_repo: Arc<dyn BlockchainRepository>,
         outbox_repo: Arc<dyn OutboxRepository>,
@@ -250,24 +393,26 @@ impl DefaultBlockchain {
     }
 
     async fn block_if_canon(&self, block: Block) -> Result<Option<Block>, AppError> {
-        let Some((tip_hash, tip_height)) = self.get_tip_info().await? else {
+        let Some((_, tip_height)) = self.get_tip_info().await? else {
             return Ok(None);
         };
-        if block.get_height() > tip_height {
+        let block_height = block.get_height();
+        if block_height > tip_height {
             return Ok(None);
         }
-        if block.get_height() < tip_height {
-            // Forks not currently supported. Any block below tip is canon
-            return Ok(Some(block));
-        }
-        if block.get_hash() == tip_hash {
+
+        // Side branch blocks are known, yet never indexed by height.
+        let canon_hash = self
+            .blockchain_repo
+            .get_block_hash_by_height(None, &block_height)?;
+        if canon_hash.is_some_and(|canon_hash| canon_hash == block.get_hash()) {
             Ok(Some(block))
         } else {
             Ok(None)
         }
     }
 
-    async fn append_block(&self, block: Block) -> Result<(), AppError> {
+    async fn append_block(&self, block: Block, chain_work: u128) -> Result<(), AppError> {
         let hash = block.get_hash();
         let height = block.get_height();
 
@@ -281,9 +426,37 @@ impl DefaultBlockchain {
         unit_of_work.run_in_transaction(Box::new(move |ctx| {
             blockchain_repo.insert_block(Some(ctx), &block)?;
             blockchain_repo.insert_height(Some(ctx), height.clone(), &hash)?;
+            blockchain_repo.insert_chain_work(Some(ctx), &hash, chain_work)?;
             outbox_repo.insert_entry(Some(ctx), outbox_entry.clone())?;
             Ok(AtomicTransactionOutput::new(()))
         }))?;
         Ok(())
     }
+
+    async fn insert_side_branch_block(
+        &self,
+        block: Block,
+        chain_work: u128,
+        reorganize: bool,
+    ) -> Result<(), AppError> {
+        let hash = block.get_hash();
+
+        let blockchain_repo = self.blockchain_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let outbox_entry =
+            reorganize.then(|| OutboxEntry::new(OutboxEvent::BlockchainReorganize(block.clone())));
+
+        let unit_of_work = self
+            .blockchain_repo
+            .get_blockchain_append_block_unit_of_work();
+        unit_of_work.run_in_transaction(Box::new(move |ctx| {
+            blockchain_repo.insert_block(Some(ctx), &block)?;
+            blockchain_repo.insert_chain_work(Some(ctx), &hash, chain_work)?;
+            if let Some(outbox_entry) = &outbox_entry {
+                outbox_repo.insert_entry(Some(ctx), outbox_entry.clone())?;
+            }
+            Ok(AtomicTransactionOutput::new(()))
+        }))?;
+        Ok(())
+    }
 }
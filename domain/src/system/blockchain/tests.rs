@@ -1,5 +1,7 @@
 use super::*;
-use crate::entities::block::BlockHeight;
+use crate::entities::block::{
+    BlockDifficultyTarget, BlockHeight, BlockTemplate, NonValidatedBlock,
+};
 use crate::entities::transaction::TransactionAmount;
 use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
 use crate::repos::blockchain::MockBlockchainRepository;
@@ -7,7 +9,12 @@ use crate::repos::outbox::MockOutboxRepository;
 use crate::types::hash::Hash;
 use crate::types::sign::PublicKey;
 use crate::types::time::DateTime;
-use std::sync::Arc;
+use common::tx::UnitOfWork;
+use common::tx::ctx::AtomicTransactionContext;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 fn create_test_genesis_config() -> GenesisConfig {
     let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
@@ -23,6 +30,51 @@ fn create_test_genesis_config() -> GenesisConfig {
     GenesisConfig::new_unchecked(vec![utxo], timestamp)
 }
 
+// Mockall doesn't support mocking Fn objects. See https://github.com/asomers/mockall/issues/139.
+#[derive(Debug)]
+struct MockUnitOfWork;
+
+impl UnitOfWork for MockUnitOfWork {
+    fn run_in_transaction(
+        &self,
+        f: Box<
+            dyn for<'a> FnMut(
+                    &'a mut dyn AtomicTransactionContext,
+                ) -> Result<AtomicTransactionOutput, AppError>
+                + Send,
+        >,
+    ) -> Result<AtomicTransactionOutput, AppError> {
+        struct MockTxCtx;
+        impl AtomicTransactionContext for MockTxCtx {
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<MockTxCtx>()
+            }
+
+            fn as_any(&self) -> Box<dyn std::any::Any> {
+                let ctx = MockTxCtx;
+                Box::new(ctx)
+            }
+        }
+        let f = RefCell::new(f);
+        let mut ctx = MockTxCtx;
+        f.borrow_mut()(&mut ctx)
+    }
+}
+
+fn create_test_genesis_block() -> Block {
+    Block::_new_validated(NonValidatedBlock::new_genesis(create_test_genesis_config()).unwrap())
+}
+
+fn create_test_child_block(parent: &Block) -> Block {
+    let txs = parent
+        .get_transactions()
+        .iter()
+        .map(|tx| tx.clone().invalidate())
+        .collect();
+    let template = BlockTemplate::new(parent, txs, BlockDifficultyTarget::initial());
+    Block::_new_validated(NonValidatedBlock::from_template(template).unwrap())
+}
+
 #[tokio::test]
 async fn test_get_tip_info_with_empty_blockchain() {
     let mut mock_repo = MockBlockchainRepository::new();
@@ -66,109 +118,161 @@ async fn test_get_tip_info_caching() {
     assert_eq!(tip1, tip2);
 }
 
+fn create_test_height_hash(height: u64) -> Hash {
+    Hash::new([height as u8; 32])
+}
+
 #[tokio::test]
-async fn test_get_unknown_block_heights_empty_local_chain() {
+async fn test_get_block_locator_empty_local_chain() {
     let mut mock_repo = MockBlockchainRepository::new();
     mock_repo.expect_get_tip().returning(|_| Ok(None));
 
     let mock_outbox = MockOutboxRepository::new();
     let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
 
-    let remote_tip_hash = Hash::new([1u8; 32]);
-    let remote_tip_height = BlockHeight::from(5);
-
-    let range = blockchain
-        .get_unknown_block_heights((remote_tip_hash, remote_tip_height.clone()))
-        .await
-        .unwrap();
+    let locator = blockchain.get_block_locator().await.unwrap();
 
-    assert_eq!(range, Some(BlockHeight::genesis()..=remote_tip_height));
+    assert!(
+        locator.is_empty(),
+        "Empty blockchain should have no locator"
+    );
 }
 
 #[tokio::test]
-async fn test_get_unknown_block_heights_local_behind() {
-    let local_hash = Hash::new([2u8; 32]);
-    let local_height = BlockHeight::from(3);
+async fn test_get_block_locator_short_chain() {
+    let local_height = BlockHeight::from(4);
 
     let mut mock_repo = MockBlockchainRepository::new();
     mock_repo
         .expect_get_tip()
-        .returning(move |_| Ok(Some(local_hash.clone())));
+        .returning(|_| Ok(Some(create_test_height_hash(4))));
     mock_repo
         .expect_get_height()
         .returning(move |_, _| Ok(Some(local_height.clone())));
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(|_, height| Ok(Some(create_test_height_hash(height.as_u64()))));
 
     let mock_outbox = MockOutboxRepository::new();
     let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
 
-    let remote_tip_hash = Hash::new([1u8; 32]);
-    let remote_tip_height = BlockHeight::from(7);
-
-    let range = blockchain
-        .get_unknown_block_heights((remote_tip_hash, remote_tip_height.clone()))
-        .await
-        .unwrap();
+    let locator = blockchain.get_block_locator().await.unwrap();
 
-    assert_eq!(range, Some(BlockHeight::from(4)..=remote_tip_height));
+    let expected: Vec<_> = (0..=4).rev().map(create_test_height_hash).collect();
+    assert_eq!(locator, expected);
 }
 
 #[tokio::test]
-async fn test_get_unknown_block_heights_local_up_to_date() {
-    let local_hash = Hash::new([2u8; 32]);
-    let local_height = BlockHeight::from(5);
+async fn test_get_block_locator_long_chain() {
+    let local_height = BlockHeight::from(30);
 
     let mut mock_repo = MockBlockchainRepository::new();
     mock_repo
         .expect_get_tip()
-        .returning(move |_| Ok(Some(local_hash.clone())));
+        .returning(|_| Ok(Some(create_test_height_hash(30))));
     mock_repo
         .expect_get_height()
         .returning(move |_, _| Ok(Some(local_height.clone())));
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(|_, height| Ok(Some(create_test_height_hash(height.as_u64()))));
 
     let mock_outbox = MockOutboxRepository::new();
     let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
 
-    let remote_tip_hash = Hash::new([1u8; 32]);
-    let remote_tip_height = BlockHeight::from(5);
-
-    let range = blockchain
-        .get_unknown_block_heights((remote_tip_hash, remote_tip_height))
-        .await
-        .unwrap();
+    let locator = blockchain.get_block_locator().await.unwrap();
 
-    assert!(
-        range.is_none(),
-        "Should return None when local is up-to-date"
-    );
+    // Dense near the tip, then exponentially sparse, always ending at genesis.
+    let expected: Vec<_> = (21..=30)
+        .rev()
+        .chain([19, 15, 7, 0])
+        .map(create_test_height_hash)
+        .collect();
+    assert_eq!(locator, expected);
 }
 
 #[tokio::test]
-async fn test_get_unknown_block_heights_local_ahead() {
-    let local_hash = Hash::new([2u8; 32]);
-    let local_height = BlockHeight::from(10);
+async fn test_find_fork_point_first_canon_hash() {
+    let genesis_block = create_test_genesis_block();
+    let canon_block = create_test_child_block(&genesis_block);
+    let canon_hash = canon_block.get_hash();
+    let canon_height = canon_block.get_height();
+    let tip_hash = canon_hash.clone();
+    let unknown_hash = Hash::new([9u8; 32]);
 
     let mut mock_repo = MockBlockchainRepository::new();
     mock_repo
         .expect_get_tip()
-        .returning(move |_| Ok(Some(local_hash.clone())));
+        .returning(move |_| Ok(Some(tip_hash.clone())));
     mock_repo
         .expect_get_height()
-        .returning(move |_, _| Ok(Some(local_height.clone())));
+        .returning(move |_, _| Ok(Some(canon_height.clone())));
+    {
+        let canon_block = canon_block.clone();
+        let genesis_block = genesis_block.clone();
+        mock_repo.expect_get_block().returning(move |_, hash| {
+            if *hash == canon_block.get_hash() {
+                Ok(Some(canon_block.clone()))
+            } else if *hash == genesis_block.get_hash() {
+                Ok(Some(genesis_block.clone()))
+            } else {
+                Ok(None)
+            }
+        });
+    }
+    {
+        let canon_hash = canon_hash.clone();
+        mock_repo
+            .expect_get_block_hash_by_height()
+            .returning(move |_, _| Ok(Some(canon_hash.clone())));
+    }
 
     let mock_outbox = MockOutboxRepository::new();
     let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
 
-    let remote_tip_hash = Hash::new([1u8; 32]);
-    let remote_tip_height = BlockHeight::from(5);
+    let locator = vec![unknown_hash, canon_hash, genesis_block.get_hash()];
+    let fork_point = blockchain.find_fork_point(&locator).await.unwrap();
+
+    assert_eq!(fork_point, Some(canon_block.get_height()));
+}
+
+#[tokio::test]
+async fn test_find_fork_point_side_branch_hash_skipped() {
+    let genesis_block = create_test_genesis_block();
+    let canon_block = create_test_child_block(&genesis_block);
+    let side_block = create_test_child_block(&canon_block);
+    let tip_hash = side_block.get_hash();
+    let tip_height = side_block.get_height();
 
-    let range = blockchain
-        .get_unknown_block_heights((remote_tip_hash, remote_tip_height))
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(tip_hash.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(move |_, _| Ok(Some(tip_height.clone())));
+    {
+        let side_block = side_block.clone();
+        mock_repo
+            .expect_get_block()
+            .returning(move |_, _| Ok(Some(side_block.clone())));
+    }
+    // A different block is canon at the side block's height.
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(|_, _| Ok(Some(Hash::new([7u8; 32]))));
+
+    let mock_outbox = MockOutboxRepository::new();
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    let fork_point = blockchain
+        .find_fork_point(&[side_block.get_hash()])
         .await
         .unwrap();
 
     assert!(
-        range.is_none(),
-        "Should return None when local is ahead of remote"
+        fork_point.is_none(),
+        "Side branch blocks should never be reported as fork points"
     );
 }
 
@@ -180,11 +284,15 @@ async fn test_has_canon_block_found() {
     );
     let block_hash = genesis_block.get_hash();
     let block_hash_clone1 = block_hash.clone();
+    let block_hash_clone2 = block_hash.clone();
 
     let mut mock_repo = MockBlockchainRepository::new();
     mock_repo
         .expect_get_block()
         .returning(move |_, _| Ok(Some(genesis_block.clone())));
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(move |_, _| Ok(Some(block_hash_clone2.clone())));
     mock_repo
         .expect_get_tip()
         .returning(move |_| Ok(Some(block_hash.clone())));
@@ -260,6 +368,10 @@ async fn test_set_tip() {
     let block_height = genesis_block.get_height();
 
     let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_insert_height()
+        .times(1)
+        .returning(|_, _, _| Ok(()));
     mock_repo.expect_set_tip().times(1).returning(|_, _| Ok(()));
 
     let mock_outbox = MockOutboxRepository::new();
@@ -280,11 +392,15 @@ async fn test_get_canon_block_found() {
     );
     let block_hash = genesis_block.get_hash();
     let block_hash_clone1 = block_hash.clone();
+    let block_hash_clone2 = block_hash.clone();
 
     let mut mock_repo = MockBlockchainRepository::new();
     mock_repo
         .expect_get_block()
         .returning(move |_, _| Ok(Some(genesis_block.clone())));
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(move |_, _| Ok(Some(block_hash_clone2.clone())));
     mock_repo
         .expect_get_tip()
         .returning(move |_| Ok(Some(block_hash.clone())));
@@ -302,6 +418,80 @@ async fn test_get_canon_block_found() {
     assert!(result.is_some(), "Should find canonical block");
 }
 
+#[tokio::test]
+async fn test_get_canon_block_side_branch() {
+    let genesis_block = Block::_new_validated(
+        crate::entities::block::NonValidatedBlock::new_genesis(create_test_genesis_config())
+            .unwrap(),
+    );
+    let block_hash = genesis_block.get_hash();
+    let canon_hash = Hash::new([1u8; 32]);
+    let canon_hash_clone1 = canon_hash.clone();
+
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_block()
+        .returning(move |_, _| Ok(Some(genesis_block.clone())));
+    // Another block holds the height on the canon chain
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(move |_, _| Ok(Some(canon_hash.clone())));
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(canon_hash_clone1.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(|_, _| Ok(Some(BlockHeight::from(5))));
+
+    let mock_outbox = MockOutboxRepository::new();
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    let result = blockchain.get_canon_block(&block_hash).await.unwrap();
+    assert!(
+        result.is_none(),
+        "Side branch block should not be treated as canonical"
+    );
+}
+
+#[tokio::test]
+async fn test_add_block_unknown_parent() {
+    let genesis_block = Block::_new_validated(
+        crate::entities::block::NonValidatedBlock::new_genesis(create_test_genesis_config())
+            .unwrap(),
+    );
+    let txs = genesis_block
+        .get_transactions()
+        .iter()
+        .map(|tx| tx.clone().invalidate())
+        .collect();
+    let template = BlockTemplate::new(&genesis_block, txs, BlockDifficultyTarget::initial());
+    let block = Block::_new_validated(NonValidatedBlock::from_template(template).unwrap());
+    let tip_hash = Hash::new([1u8; 32]);
+
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(tip_hash.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(|_, _| Ok(Some(BlockHeight::from(5))));
+    mock_repo.expect_get_chain_work().returning(|_, _| Ok(None));
+
+    let mock_outbox = MockOutboxRepository::new();
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    let result = blockchain.add_block(block).await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::BlockValidation(
+                BlockValidationError::ContinuityMismatch { .. }
+            ))
+        ),
+        "Block extending an unknown parent should be rejected"
+    );
+}
+
 #[tokio::test]
 async fn test_get_canon_block_not_found() {
     let fake_hash = Hash::new([99u8; 32]);
@@ -610,3 +800,209 @@ async fn test_get_canon_blocks_by_height_range_empty_blockchain() {
         "Error should mention block count mismatch"
     );
 }
+
+#[tokio::test]
+async fn test_add_block_side_branch_with_less_work() {
+    let genesis_block = create_test_genesis_block();
+    let genesis_hash = genesis_block.get_hash();
+    let block = create_test_child_block(&genesis_block);
+    let expected_chain_work = 1 + block.get_difficulty_target().get_work();
+    let tip_hash = Hash::new([1u8; 32]);
+
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(tip_hash.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(|_, _| Ok(Some(BlockHeight::from(5))));
+    // The canon chain outweighs the branch
+    mock_repo.expect_get_chain_work().returning(move |_, hash| {
+        if *hash == genesis_hash {
+            Ok(Some(1))
+        } else {
+            Ok(Some(expected_chain_work * 2))
+        }
+    });
+    mock_repo
+        .expect_get_blockchain_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_insert_block()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mock_repo
+        .expect_insert_chain_work()
+        .withf(move |_, _, chain_work| *chain_work == expected_chain_work)
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    // Side branch blocks are never indexed by height
+    mock_repo.expect_insert_height().times(0);
+
+    let mut mock_outbox = MockOutboxRepository::new();
+    mock_outbox.expect_insert_entry().times(0);
+
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    blockchain.add_block(block).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_add_block_side_branch_with_more_work_reorganizes() {
+    let genesis_block = create_test_genesis_block();
+    let genesis_hash = genesis_block.get_hash();
+    let block = create_test_child_block(&genesis_block);
+    let block_hash = block.get_hash();
+    let tip_hash = Hash::new([1u8; 32]);
+
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(tip_hash.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(|_, _| Ok(Some(BlockHeight::from(1))));
+    // The branch outweighs the canon chain
+    mock_repo.expect_get_chain_work().returning(move |_, hash| {
+        if *hash == genesis_hash {
+            Ok(Some(10))
+        } else {
+            Ok(Some(11))
+        }
+    });
+    mock_repo
+        .expect_get_blockchain_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_insert_block()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mock_repo
+        .expect_insert_chain_work()
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    mock_repo.expect_insert_height().times(0);
+
+    let mut mock_outbox = MockOutboxRepository::new();
+    mock_outbox
+        .expect_insert_entry()
+        .withf(move |_, entry| {
+            matches!(
+                entry.get_event(),
+                OutboxEvent::BlockchainReorganize(block) if block.get_hash() == block_hash
+            )
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    blockchain.add_block(block).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rewind_tip() {
+    let genesis_block = create_test_genesis_block();
+    let genesis_hash = genesis_block.get_hash();
+    let genesis_hash_clone = genesis_hash.clone();
+    let tip_hash = Hash::new([1u8; 32]);
+    let deleted_heights = Arc::new(Mutex::new(Vec::new()));
+    let deleted_heights_clone = deleted_heights.clone();
+
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(tip_hash.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(|_, _| Ok(Some(BlockHeight::from(3))));
+    let block = genesis_block.clone();
+    mock_repo
+        .expect_get_block()
+        .returning(move |_, _| Ok(Some(block.clone())));
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(move |_, _| Ok(Some(genesis_hash_clone.clone())));
+    mock_repo
+        .expect_set_tip()
+        .withf({
+            let genesis_hash = genesis_hash.clone();
+            move |_, hash| *hash == genesis_hash
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mock_repo
+        .expect_delete_height()
+        .returning(move |_, height| {
+            deleted_heights_clone.lock().unwrap().push(height.clone());
+            Ok(())
+        });
+
+    let mock_outbox = MockOutboxRepository::new();
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    blockchain.rewind_tip(&genesis_block).await.unwrap();
+
+    assert_eq!(
+        *deleted_heights.lock().unwrap(),
+        vec![
+            BlockHeight::from(1),
+            BlockHeight::from(2),
+            BlockHeight::from(3)
+        ],
+        "Heights above the new tip should be dropped"
+    );
+    let tip = blockchain.get_tip_info().await.unwrap();
+    assert_eq!(tip, Some((genesis_hash, BlockHeight::genesis())));
+}
+
+#[tokio::test]
+async fn test_get_branch_walks_back_to_fork_point() {
+    let genesis_block = create_test_genesis_block();
+    let genesis_hash = genesis_block.get_hash();
+    let branch_block1 = create_test_child_block(&genesis_block);
+    let branch_block2 = create_test_child_block(&branch_block1);
+    let branch_hash = branch_block2.get_hash();
+    let tip_hash = Hash::new([1u8; 32]);
+
+    let blocks = [&genesis_block, &branch_block1, &branch_block2]
+        .into_iter()
+        .map(|block| (block.get_hash(), block.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let mut mock_repo = MockBlockchainRepository::new();
+    mock_repo
+        .expect_get_tip()
+        .returning(move |_| Ok(Some(tip_hash.clone())));
+    mock_repo
+        .expect_get_height()
+        .returning(|_, _| Ok(Some(BlockHeight::from(2))));
+    mock_repo
+        .expect_get_block()
+        .returning(move |_, hash| Ok(blocks.get(hash).cloned()));
+    // Only the genesis block is shared with the canon chain
+    mock_repo
+        .expect_get_block_hash_by_height()
+        .returning(move |_, height| {
+            if *height == BlockHeight::genesis() {
+                Ok(Some(genesis_hash.clone()))
+            } else {
+                Ok(Some(Hash::new([height.as_u64() as u8; 32])))
+            }
+        });
+
+    let mock_outbox = MockOutboxRepository::new();
+    let blockchain = DefaultBlockchain::new(Arc::new(mock_repo), Arc::new(mock_outbox));
+
+    let (fork_block, branch) = blockchain.get_branch(&branch_hash).await.unwrap();
+
+    assert_eq!(fork_block.get_hash(), genesis_block.get_hash());
+    assert_eq!(
+        branch
+            .iter()
+            .map(|block| block.get_hash())
+            .collect::<Vec<_>>(),
+        vec![branch_block1.get_hash(), branch_block2.get_hash()],
+        "Branch blocks should be returned in ascending height"
+    );
+}
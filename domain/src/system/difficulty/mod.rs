@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests;
+
+use crate::entities::block::{Block, BlockDifficultyTarget, BlockHeight};
+use crate::system::blockchain::Blockchain;
+use async_trait::async_trait;
+use common::error::AppError;
+use std::sync::Arc;
+
+/// Derives the proof-of-work target blocks are expected to be mined against.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub(crate) trait DifficultyAdjuster: Send + Sync + std::fmt::Debug {
+    /// Computes the expected difficulty target for a block at the given height.<br />
+    /// Relies on the canon blocks preceding that height.
+    async fn get_difficulty_target(
+        &self,
+        height: &BlockHeight,
+    ) -> Result<BlockDifficultyTarget, AppError>;
+}
+
+/// Linearly Weighted Moving Average (LWMA) difficulty retargeting.<br />
+/// Recent solve times carry more weight, allowing for quick reactions to hash rate changes.
+#[derive(Debug)]
+pub(crate) struct LwmaDifficultyAdjuster {
+    blockchain: Arc<dyn Blockchain>,
+    target_block_interval_ms: u64,
+}
+
+#[async_trait]
+impl DifficultyAdjuster for LwmaDifficultyAdjuster {
+    async fn get_difficulty_target(
+        &self,
+        height: &BlockHeight,
+    ) -> Result<BlockDifficultyTarget, AppError> {
+        // The genesis block is excluded from the window.
+        // Its timestamp is configured and its target is never mined against.
+        // At least two mined blocks are required for a single solve time.
+        let height = height.as_u64();
+        if height <= 2 {
+            return Ok(BlockDifficultyTarget::initial());
+        }
+
+        let last_height = height - 1;
+        let first_height = last_height.saturating_sub(Self::WINDOW_SIZE).max(1);
+        let blocks = self
+            .blockchain
+            .get_canon_blocks_by_height_range(
+                BlockHeight::from(first_height)..=BlockHeight::from(last_height),
+            )
+            .await?;
+        if blocks.len() as u64 != last_height - first_height + 1 {
+            return Err(AppError::internal(format!(
+                "Missing blocks for difficulty adjustment! | Height: {}",
+                height
+            )));
+        }
+
+        let target = Self::compute_target(&blocks, self.target_block_interval_ms);
+        Ok(target)
+    }
+}
+
+impl LwmaDifficultyAdjuster {
+    /// Number of solve times considered.
+    const WINDOW_SIZE: u64 = 30;
+
+    /// Solve times are capped to limit the effect of timestamp manipulation.
+    const MAX_SOLVE_TIME_FACTOR: u64 = 6;
+
+    pub(crate) fn new(blockchain: Arc<dyn Blockchain>, target_block_interval_secs: u64) -> Self {
+        Self {
+            blockchain,
+            target_block_interval_ms: target_block_interval_secs.max(1) * 1000,
+        }
+    }
+
+    /// Computes the next target from a window of consecutive blocks (ascending height).
+    pub(super) fn compute_target(
+        blocks: &[Block],
+        target_block_interval_ms: u64,
+    ) -> BlockDifficultyTarget {
+        let solve_time_count = blocks.len().saturating_sub(1) as u128;
+        if solve_time_count == 0 {
+            return BlockDifficultyTarget::initial();
+        }
+
+        let max_solve_time = Self::MAX_SOLVE_TIME_FACTOR * target_block_interval_ms;
+        let mut weighted_solve_times: u128 = 0;
+        let mut avg_target: u128 = 0;
+        let mut avg_target_remainders: u128 = 0;
+        for (weight, pair) in (1..).zip(blocks.windows(2)) {
+            let solve_time = pair[1]
+                .get_timestamp()
+                .to_ms()
+                .saturating_sub(pair[0].get_timestamp().to_ms())
+                .clamp(1, max_solve_time);
+            weighted_solve_times += weight * solve_time as u128;
+
+            // Averaged piecewise, the target sum would overflow.
+            let target = pair[1].get_difficulty_target().as_u128();
+            avg_target += target / solve_time_count;
+            avg_target_remainders += target % solve_time_count;
+        }
+        avg_target += avg_target_remainders / solve_time_count;
+
+        // next = avg_target * weighted_solve_times / (n * (n + 1) / 2 * T)
+        let expected_weighted_solve_times =
+            solve_time_count * (solve_time_count + 1) / 2 * target_block_interval_ms as u128;
+        let next_target = (avg_target / expected_weighted_solve_times)
+            .checked_mul(weighted_solve_times)
+            .and_then(|quotient| {
+                let remainder = avg_target % expected_weighted_solve_times * weighted_solve_times
+                    / expected_weighted_solve_times;
+                quotient.checked_add(remainder)
+            })
+            .unwrap_or(u128::MAX)
+            .max(1);
+        BlockDifficultyTarget::new(next_target)
+    }
+}
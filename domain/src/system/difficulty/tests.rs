@@ -0,0 +1,229 @@
+use super::*;
+use crate::entities::block::{BlockTemplate, NonValidatedBlock};
+use crate::entities::transaction::{NonValidatedTransaction, TransactionAmount, TransactionOutput};
+use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
+use crate::system::blockchain::MockBlockchain;
+use crate::types::sign::PublicKey;
+use crate::types::time::DateTime;
+use crate::types::wallet::WalletAddress;
+use std::str::FromStr;
+
+const TARGET_BLOCK_INTERVAL_SECS: u64 = 30;
+const TARGET_BLOCK_INTERVAL_MS: u64 = TARGET_BLOCK_INTERVAL_SECS * 1000;
+
+fn create_test_genesis_config() -> GenesisConfig {
+    let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+
+    let utxo =
+        GenesisConfigUtxoFunds::new_unchecked(wallet_pub_key, TransactionAmount::new(1000000000));
+
+    let timestamp = DateTime::from_ms(1725799696000);
+    GenesisConfig::new_unchecked(vec![utxo], timestamp)
+}
+
+fn create_test_block(timestamp_ms: u64, difficulty_target: BlockDifficultyTarget) -> Block {
+    let prev_block = Block::_new_validated(
+        NonValidatedBlock::new_genesis(create_test_genesis_config()).unwrap(),
+    );
+    let recipient =
+        WalletAddress::from_str("54b73c091395a30874a397cbfcd54c7348175a01ee6ccf0a1133f8f8b3a19e7d")
+            .unwrap();
+    let output = TransactionOutput::new(recipient, TransactionAmount::new(50));
+    let tx = NonValidatedTransaction::new(vec![], vec![output], DateTime::from_ms(timestamp_ms))
+        .unwrap();
+
+    let mut template = BlockTemplate::new(&prev_block, vec![tx], difficulty_target);
+    template.set_timestamp(DateTime::from_ms(timestamp_ms));
+    Block::_new_validated(NonValidatedBlock::from_template(template).unwrap())
+}
+
+/// Creates consecutive blocks sharing the same target, spaced by the given solve time.
+fn create_test_blocks(
+    count: usize,
+    solve_time_ms: u64,
+    difficulty_target: BlockDifficultyTarget,
+) -> Vec<Block> {
+    (0..count as u64)
+        .map(|i| create_test_block(1725799696000 + i * solve_time_ms, difficulty_target.clone()))
+        .collect()
+}
+
+// ============================================================================
+// compute_target()
+// ============================================================================
+
+#[test]
+fn test_compute_target_on_schedule_unchanged() {
+    let difficulty_target = BlockDifficultyTarget::initial();
+    let blocks = create_test_blocks(10, TARGET_BLOCK_INTERVAL_MS, difficulty_target.clone());
+
+    let next_target = LwmaDifficultyAdjuster::compute_target(&blocks, TARGET_BLOCK_INTERVAL_MS);
+    assert_eq!(
+        next_target, difficulty_target,
+        "On-schedule blocks should retain the target"
+    );
+}
+
+#[test]
+fn test_compute_target_fast_blocks_harder() {
+    let difficulty_target = BlockDifficultyTarget::initial();
+    let blocks = create_test_blocks(10, TARGET_BLOCK_INTERVAL_MS / 2, difficulty_target.clone());
+
+    let next_target = LwmaDifficultyAdjuster::compute_target(&blocks, TARGET_BLOCK_INTERVAL_MS);
+    assert_eq!(
+        next_target.as_u128(),
+        difficulty_target.as_u128() / 2,
+        "Blocks mined twice as fast should halve the target"
+    );
+}
+
+#[test]
+fn test_compute_target_slow_blocks_easier() {
+    let difficulty_target = BlockDifficultyTarget::initial();
+    let blocks = create_test_blocks(10, TARGET_BLOCK_INTERVAL_MS * 2, difficulty_target.clone());
+
+    let next_target = LwmaDifficultyAdjuster::compute_target(&blocks, TARGET_BLOCK_INTERVAL_MS);
+    assert_eq!(
+        next_target.as_u128(),
+        difficulty_target.as_u128() * 2,
+        "Blocks mined twice as slow should double the target"
+    );
+}
+
+#[test]
+fn test_compute_target_caps_solve_times() {
+    let difficulty_target = BlockDifficultyTarget::initial();
+    let capped_blocks = create_test_blocks(
+        10,
+        TARGET_BLOCK_INTERVAL_MS * LwmaDifficultyAdjuster::MAX_SOLVE_TIME_FACTOR,
+        difficulty_target.clone(),
+    );
+    let stalled_blocks = create_test_blocks(10, TARGET_BLOCK_INTERVAL_MS * 100, difficulty_target);
+
+    assert_eq!(
+        LwmaDifficultyAdjuster::compute_target(&stalled_blocks, TARGET_BLOCK_INTERVAL_MS),
+        LwmaDifficultyAdjuster::compute_target(&capped_blocks, TARGET_BLOCK_INTERVAL_MS),
+        "Solve times beyond the cap should not affect the target"
+    );
+}
+
+#[test]
+fn test_compute_target_saturates() {
+    let difficulty_target = BlockDifficultyTarget::new(u128::MAX);
+    let blocks = create_test_blocks(10, TARGET_BLOCK_INTERVAL_MS * 2, difficulty_target);
+
+    let next_target = LwmaDifficultyAdjuster::compute_target(&blocks, TARGET_BLOCK_INTERVAL_MS);
+    assert_eq!(
+        next_target.as_u128(),
+        u128::MAX,
+        "Target should saturate instead of overflowing"
+    );
+}
+
+// ============================================================================
+// get_difficulty_target()
+// ============================================================================
+
+#[tokio::test]
+async fn test_get_difficulty_target_early_heights_initial() {
+    // No blockchain expectations, early heights require no history.
+    let adjuster =
+        LwmaDifficultyAdjuster::new(Arc::new(MockBlockchain::new()), TARGET_BLOCK_INTERVAL_SECS);
+
+    for height in 0..=2 {
+        let target = adjuster
+            .get_difficulty_target(&BlockHeight::from(height))
+            .await
+            .unwrap();
+        assert_eq!(target, BlockDifficultyTarget::initial());
+    }
+}
+
+#[tokio::test]
+async fn test_get_difficulty_target_excludes_genesis() {
+    let blocks = create_test_blocks(
+        4,
+        TARGET_BLOCK_INTERVAL_MS / 2,
+        BlockDifficultyTarget::initial(),
+    );
+    let expected_target = LwmaDifficultyAdjuster::compute_target(&blocks, TARGET_BLOCK_INTERVAL_MS);
+
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_get_canon_blocks_by_height_range()
+        .withf(|range| range == &(BlockHeight::from(1)..=BlockHeight::from(4)))
+        .times(1)
+        .returning(move |_| Ok(blocks.clone()));
+    let adjuster =
+        LwmaDifficultyAdjuster::new(Arc::new(mock_blockchain), TARGET_BLOCK_INTERVAL_SECS);
+
+    let target = adjuster
+        .get_difficulty_target(&BlockHeight::from(5))
+        .await
+        .unwrap();
+    assert_eq!(target, expected_target);
+}
+
+#[tokio::test]
+async fn test_get_difficulty_target_window_size() {
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_get_canon_blocks_by_height_range()
+        .withf(|range| range == &(BlockHeight::from(70)..=BlockHeight::from(100)))
+        .times(1)
+        .returning(|_| {
+            Ok(create_test_blocks(
+                31,
+                TARGET_BLOCK_INTERVAL_MS,
+                BlockDifficultyTarget::initial(),
+            ))
+        });
+    let adjuster =
+        LwmaDifficultyAdjuster::new(Arc::new(mock_blockchain), TARGET_BLOCK_INTERVAL_SECS);
+
+    let target = adjuster
+        .get_difficulty_target(&BlockHeight::from(101))
+        .await
+        .unwrap();
+    assert_eq!(target, BlockDifficultyTarget::initial());
+}
+
+#[tokio::test]
+async fn test_get_difficulty_target_missing_blocks() {
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_get_canon_blocks_by_height_range()
+        .returning(|_| Ok(vec![]));
+    let adjuster =
+        LwmaDifficultyAdjuster::new(Arc::new(mock_blockchain), TARGET_BLOCK_INTERVAL_SECS);
+
+    let result = adjuster.get_difficulty_target(&BlockHeight::from(10)).await;
+    assert!(result.is_err(), "Incomplete block window should fail");
+}
+
+// ============================================================================
+// BlockDifficultyTarget::get_work()
+// ============================================================================
+
+#[test]
+fn test_get_work_initial_target() {
+    assert_eq!(
+        BlockDifficultyTarget::initial().get_work(),
+        1 << 16,
+        "Initial target should expect 2^16 hashing attempts"
+    );
+}
+
+#[test]
+fn test_get_work_lower_target_more_work() {
+    let easy_target = BlockDifficultyTarget::new(u128::MAX >> 8);
+    let hard_target = BlockDifficultyTarget::new(u128::MAX >> 9);
+    assert_eq!(easy_target.get_work() * 2, hard_target.get_work());
+}
+
+#[test]
+fn test_get_work_max_target() {
+    assert_eq!(BlockDifficultyTarget::new(u128::MAX).get_work(), 1);
+}
@@ -1,39 +1,140 @@
 #[cfg(test)]
 mod tests;
 
-use crate::entities::block::Block;
-use crate::entities::transaction::Transaction;
+use crate::entities::block::{Block, BlockHeight};
+use crate::entities::transaction::{Transaction, TransactionAmount, TransactionOutPoint, Utxo};
+use crate::system::utxo::UtxoSetReader;
 use crate::types::hash::Hash;
+use crate::types::time::DateTime;
 use async_trait::async_trait;
-use common::error::AppError;
-use common::log_mempool_info;
+use common::error::{AppError, TransactionValidationError};
 use common::params::PaginationParams;
-use std::collections::HashMap;
+use common::{log_mempool_debug, log_mempool_info};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 use tokio::sync::RwLock;
 
 #[async_trait]
 pub(crate) trait Mempool: Send + Sync + std::fmt::Debug {
-    /// Updates the mempool by removing transactions that are included in the given block.
+    /// Updates the mempool by removing transactions that are included in the given block.<br />
+    /// Transactions conflicting with the block's ones (spending the same outpoints) are evicted as well,
+    /// along with their descendants.<br />
+    /// Transactions whose inputs are no longer spendable, or which have expired, are evicted alongside their descendants.<br />
+    /// Expects the UTXO set to already reflect the given block.
     async fn apply_block(&self, block: &Block) -> Result<(), AppError>;
 
     /// Adds a transaction to the mempool.<br />
-    /// Inserting a transaction with a conflicting hash will overwrite the existing one.
+    /// Inserting a transaction with an already pooled hash leaves the pooled one untouched.<br />
+    /// Transactions spending an outpoint already spent by a pooled transaction are rejected.<br />
+    /// With replace-by-fee enabled, they may instead replace the conflicting transactions (and their descendants)
+    /// by paying a strictly higher absolute fee and fee rate.<br />
+    /// Once full, lower fee rate transactions are evicted to make room for higher paying ones.<br />
+    /// Evicting a transaction evicts its pooled descendants as well.
     async fn add_transaction(&self, tx: Transaction) -> Result<(), AppError>;
 
+    /// Adds a transaction first pooled at the given time, such as one persisted across restarts.<br />
+    /// Admission rules are those of [`Mempool::add_transaction`].<br />
+    /// The transaction expires relative to the given time, being evicted right away if already expired.
+    async fn restore_transaction(
+        &self,
+        tx: Transaction,
+        added_at: DateTime,
+    ) -> Result<(), AppError>;
+
     /// Retrieves a transaction from the mempool by its hash.
     async fn get_transaction(&self, tx_hash: &Hash) -> Option<Transaction>;
 
-    /// Retrieves a paginated transactions from the mempool.<br />
+    /// Retrieves a paginated transactions from the mempool, highest fee rate first.<br />
     /// Returns a tuple containing the list of transactions and the total count of transactions in the mempool.
     async fn get_paginated_transactions(
         &self,
         pagination: PaginationParams,
     ) -> Result<(Vec<Transaction>, usize), AppError>;
+
+    /// Returns the minimum fee rate (per encoded byte) required for admission.<br />
+    /// Starts off at the minimum relay fee rate, rising as the mempool fills up and evicts transactions.
+    async fn get_min_fee_rate(&self) -> TransactionAmount;
+
+    /// Resolves the given outpoints against the outputs of pooled transactions.<br />
+    /// Outpoints not created by a pooled transaction are skipped.<br />
+    /// The resulting pending UTXOs are reported at the given block height.
+    async fn get_pending_utxos(
+        &self,
+        outpoints: &[TransactionOutPoint],
+        block_height: &BlockHeight,
+    ) -> Vec<Utxo>;
+
+    /// Retrieves up to `limit` transactions to be included in a block, highest fee rate first.<br />
+    /// Pooled ancestors always precede their descendants.
+    async fn get_block_candidates(&self, limit: usize) -> Vec<Transaction>;
+
+    /// Retrieves every pooled transaction alongside the time it was added at.<br />
+    /// Pooled ancestors always precede their descendants.
+    async fn get_pooled_transactions(&self) -> Vec<(Transaction, DateTime)>;
 }
 
 #[derive(Debug)]
 pub(crate) struct DefaultMempool {
-    transactions: RwLock<HashMap<Hash, Transaction>>,
+    state: RwLock<MempoolState>,
+    /// Maximum total encoded size of the pooled transactions (in bytes).
+    max_size: usize,
+    min_relay_fee_rate: TransactionAmount,
+    /// Whether conflicting transactions may be replaced by higher paying ones.
+    replace_by_fee: bool,
+    /// Maximum number of transactions a single replacement may evict.
+    max_replacement_evictions: usize,
+    /// Maximum age of pooled transactions (in milliseconds).
+    expiry_ms: u64,
+    utxo_set_r: Arc<dyn UtxoSetReader>,
+}
+
+#[derive(Debug)]
+struct MempoolState {
+    transactions: HashMap<Hash, MempoolEntry>,
+    /// Transaction hashes ordered by ascending fee rate.
+    by_fee_rate: BTreeSet<(FeeRate, Hash)>,
+    /// Pooled transaction hashes by the outpoints they spend.
+    spenders: HashMap<TransactionOutPoint, Hash>,
+    total_size: usize,
+    /// Minimum fee rate raised by evictions.<br />
+    /// Reset once the mempool drains below half its capacity.
+    eviction_fee_rate: TransactionAmount,
+    /// Number of transactions evicted (descendants included) by reason.
+    eviction_counts: HashMap<MempoolEvictionReason, usize>,
+}
+
+#[derive(Debug)]
+struct MempoolEntry {
+    tx: Transaction,
+    size: usize,
+    /// Pooled transactions whose outputs are spent by this one.
+    parents: HashSet<Hash>,
+    /// Pooled transactions spending this one's outputs.
+    children: HashSet<Hash>,
+    added_at: DateTime,
+}
+
+/// Reasons for pooled transactions to be evicted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) enum MempoolEvictionReason {
+    /// Spends an outpoint spent by a confirmed transaction.
+    Conflict,
+    /// Spends an outpoint no longer present in the UTXO set.
+    InputsUnspendable,
+    /// Pooled for longer than the expiry age.
+    Expired,
+    /// Priced out by a higher fee rate transaction, the mempool being full.
+    SizeLimit,
+    /// Replaced by a conflicting transaction paying a higher fee.
+    Replaced,
+}
+
+/// Fee paid per encoded byte, compared without rounding.
+#[derive(Clone, Copy, Debug)]
+struct FeeRate {
+    fee: u128,
+    size: u128,
 }
 
 #[async_trait]
@@ -41,11 +142,36 @@ impl Mempool for DefaultMempool {
     async fn apply_block(&self, block: &Block) -> Result<(), AppError> {
         log_mempool_info!("Mempool.apply_block() | block ({:?}) ", &block);
 
+        let mut state = self.state.write().await;
         for tx in block.get_transactions() {
             let tx_hash = tx.get_hash();
-            self.rm_transaction(&tx_hash).await;
+            self.rm_entry(&mut state, &tx_hash);
+
+            // Pending transactions double-spending confirmed inputs may never be confirmed.
+            for txin in tx.get_inputs() {
+                let outpoint = txin.get_previous_output();
+                let Some(spender_hash) = state.spenders.get(outpoint).cloned() else {
+                    continue;
+                };
+                self.evict_entry_with_descendants(
+                    &mut state,
+                    &spender_hash,
+                    MempoolEvictionReason::Conflict,
+                );
+            }
+        }
+
+        // Inputs may also have been invalidated by blocks disconnected during a reorganization.
+        for tx_hash in self.find_unspendable(&state)? {
+            self.evict_entry_with_descendants(
+                &mut state,
+                &tx_hash,
+                MempoolEvictionReason::InputsUnspendable,
+            );
         }
 
+        self.expire_entries(&mut state, &DateTime::now());
+
         log_mempool_info!(
             "Mempool.apply_block(): Transactions successfully updated for block ({}) ",
             block.get_hash()
@@ -54,43 +180,628 @@ impl Mempool for DefaultMempool {
     }
 
     async fn add_transaction(&self, tx: Transaction) -> Result<(), AppError> {
-        let tx_hash = tx.get_hash();
-        let mut txs = self.transactions.write().await;
-        txs.insert(tx_hash, tx);
+        let entry = MempoolEntry::new(tx, DateTime::now())?;
+        let mut state = self.state.write().await;
+        self.add_entry(&mut state, entry)
+    }
+
+    async fn restore_transaction(
+        &self,
+        tx: Transaction,
+        added_at: DateTime,
+    ) -> Result<(), AppError> {
+        let entry = MempoolEntry::new(tx, added_at)?;
+        let mut state = self.state.write().await;
+        self.add_entry(&mut state, entry)?;
+        self.expire_entries(&mut state, &DateTime::now());
         Ok(())
     }
 
     async fn get_transaction(&self, tx_hash: &Hash) -> Option<Transaction> {
-        let txs = self.transactions.read().await;
-        txs.get(tx_hash).cloned()
+        let state = self.state.read().await;
+        state
+            .transactions
+            .get(tx_hash)
+            .map(|entry| entry.tx.clone())
     }
 
     async fn get_paginated_transactions(
         &self,
         pagination: PaginationParams,
     ) -> Result<(Vec<Transaction>, usize), AppError> {
-        let txs = self.transactions.read().await;
+        let state = self.state.read().await;
 
-        let count = txs.len();
-        let transactions = txs
-            .values()
+        let count = state.transactions.len();
+        let transactions = state
+            .by_fee_rate
+            .iter()
+            .rev()
             .skip(pagination.skip)
             .take(pagination.limit)
-            .cloned()
+            .filter_map(|(_, tx_hash)| state.transactions.get(tx_hash))
+            .map(|entry| entry.tx.clone())
             .collect();
         Ok((transactions, count))
     }
+
+    async fn get_min_fee_rate(&self) -> TransactionAmount {
+        let state = self.state.read().await;
+        if state.eviction_fee_rate > self.min_relay_fee_rate {
+            state.eviction_fee_rate
+        } else {
+            self.min_relay_fee_rate
+        }
+    }
+
+    async fn get_pending_utxos(
+        &self,
+        outpoints: &[TransactionOutPoint],
+        block_height: &BlockHeight,
+    ) -> Vec<Utxo> {
+        let state = self.state.read().await;
+        outpoints
+            .iter()
+            .filter_map(|outpoint| {
+                let entry = state.transactions.get(outpoint.get_tx_id())?;
+                let output = entry.tx.get_outputs().get(outpoint.get_tx_output_index())?;
+                Some(Utxo::new(
+                    outpoint.clone(),
+                    output.clone(),
+                    block_height.clone(),
+                    false,
+                ))
+            })
+            .collect()
+    }
+
+    async fn get_block_candidates(&self, limit: usize) -> Vec<Transaction> {
+        let state = self.state.read().await;
+        Self::collect_ordered_entries(&state, limit)
+            .into_iter()
+            .map(|entry| entry.tx.clone())
+            .collect()
+    }
+
+    async fn get_pooled_transactions(&self) -> Vec<(Transaction, DateTime)> {
+        let state = self.state.read().await;
+        Self::collect_ordered_entries(&state, usize::MAX)
+            .into_iter()
+            .map(|entry| (entry.tx.clone(), entry.added_at.clone()))
+            .collect()
+    }
 }
 
 impl DefaultMempool {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(
+        max_size: usize,
+        min_relay_fee_rate: TransactionAmount,
+        replace_by_fee: bool,
+        max_replacement_evictions: usize,
+        expiry_secs: u64,
+        utxo_set_r: Arc<dyn UtxoSetReader>,
+    ) -> Self {
         Self {
-            transactions: RwLock::new(HashMap::new()),
+            state: RwLock::new(MempoolState {
+                transactions: HashMap::new(),
+                by_fee_rate: BTreeSet::new(),
+                spenders: HashMap::new(),
+                total_size: 0,
+                eviction_fee_rate: TransactionAmount::new(0),
+                eviction_counts: HashMap::new(),
+            }),
+            max_size,
+            min_relay_fee_rate,
+            replace_by_fee,
+            max_replacement_evictions,
+            expiry_ms: expiry_secs.saturating_mul(1000),
+            utxo_set_r,
+        }
+    }
+
+    /// Collects up to `limit` pooled entries, highest fee rate first.<br />
+    /// Pooled ancestors always precede their descendants.
+    fn collect_ordered_entries(state: &MempoolState, limit: usize) -> Vec<&MempoolEntry> {
+        let mut emitted = HashSet::new();
+        let mut candidates = Vec::new();
+        for (_, tx_hash) in state.by_fee_rate.iter().rev() {
+            if candidates.len() >= limit {
+                break;
+            }
+            // Depth-first walk emitting unemitted ancestors ahead of their descendants.
+            let mut stack = vec![(tx_hash, false)];
+            while let Some((tx_hash, parents_visited)) = stack.pop() {
+                if candidates.len() >= limit || emitted.contains(tx_hash) {
+                    continue;
+                }
+                let Some(entry) = state.transactions.get(tx_hash) else {
+                    continue;
+                };
+                if parents_visited {
+                    emitted.insert(tx_hash.clone());
+                    candidates.push(entry);
+                    continue;
+                }
+                stack.push((tx_hash, true));
+                stack.extend(
+                    entry
+                        .parents
+                        .iter()
+                        .filter(|parent_hash| !emitted.contains(*parent_hash))
+                        .map(|parent_hash| (parent_hash, false)),
+                );
+            }
+        }
+        candidates
+    }
+
+    /// Admits the given entry, replacing conflicting transactions and evicting lower paying ones as needed.
+    fn add_entry(&self, state: &mut MempoolState, entry: MempoolEntry) -> Result<(), AppError> {
+        // Pooled transactions are kept as is, their ids committing to everything but signatures.
+        if state.transactions.contains_key(&entry.tx.get_hash()) {
+            return Ok(());
+        }
+
+        let replaced_hashes = self.validate_conflicts(state, &entry)?;
+        let replaced = replaced_hashes
+            .iter()
+            .filter_map(|tx_hash| state.remove(tx_hash))
+            .collect::<Vec<_>>();
+        let evicted_hashes = match self.select_evictions(state, &entry) {
+            Ok(evicted_hashes) => evicted_hashes,
+            Err(err) => {
+                // Restore the replaced transactions, the replacement being rejected.
+                for replaced_entry in replaced {
+                    state.insert(replaced_entry);
+                }
+                return Err(err);
+            }
+        };
+        let replaced_count = state.count_evictions(MempoolEvictionReason::Replaced, replaced.len());
+        for replaced_hash in &replaced_hashes {
+            log_mempool_debug!(
+                "Mempool.add_transaction(): Replaced transaction ({}) with ({}) | Reason: {:?} (total: {})",
+                replaced_hash,
+                entry.tx.get_hash(),
+                MempoolEvictionReason::Replaced,
+                replaced_count
+            );
+        }
+
+        for evicted_hash in evicted_hashes {
+            let evicted = self.evict_entry_with_descendants(
+                state,
+                &evicted_hash,
+                MempoolEvictionReason::SizeLimit,
+            );
+            if evicted.is_empty() {
+                continue;
+            }
+
+            // Newcomers must outbid evicted transactions by the minimum relay fee rate.
+            let mut eviction_fee_rate = FeeRate::of_package(&evicted).ceil_per_byte();
+            eviction_fee_rate.checked_add_assign(self.min_relay_fee_rate)?;
+            if eviction_fee_rate > state.eviction_fee_rate {
+                state.eviction_fee_rate = eviction_fee_rate;
+            }
+        }
+
+        state.insert(entry);
+        Ok(())
+    }
+
+    fn rm_entry(&self, state: &mut MempoolState, tx_hash: &Hash) -> Option<MempoolEntry> {
+        let entry = state.remove(tx_hash)?;
+        if state.total_size < self.max_size / 2 {
+            state.eviction_fee_rate = TransactionAmount::new(0);
         }
+        Some(entry)
+    }
+
+    /// Removes the given transaction along with all of its pooled descendants.<br />
+    /// Returns the removed entries, the given transaction's first.
+    fn rm_entry_with_descendants(
+        &self,
+        state: &mut MempoolState,
+        tx_hash: &Hash,
+    ) -> Vec<MempoolEntry> {
+        state
+            .collect_with_descendants(tx_hash)
+            .iter()
+            .filter_map(|tx_hash| self.rm_entry(state, tx_hash))
+            .collect()
+    }
+
+    /// Evicts the given transaction along with all of its pooled descendants, counting them under the given reason.<br />
+    /// Returns the evicted entries, the given transaction's first.
+    fn evict_entry_with_descendants(
+        &self,
+        state: &mut MempoolState,
+        tx_hash: &Hash,
+        reason: MempoolEvictionReason,
+    ) -> Vec<MempoolEntry> {
+        let evicted = self.rm_entry_with_descendants(state, tx_hash);
+        if evicted.is_empty() {
+            return evicted;
+        }
+        let count = state.count_evictions(reason, evicted.len());
+        log_mempool_debug!(
+            "Mempool: Evicted transaction ({}) and {} descendant(s) | Reason: {:?} (total: {})",
+            tx_hash,
+            evicted.len() - 1,
+            reason,
+            count
+        );
+        evicted
+    }
+
+    /// Finds pooled transactions spending outpoints neither present in the UTXO set,
+    /// nor created by other pooled transactions.
+    fn find_unspendable(&self, state: &MempoolState) -> Result<Vec<Hash>, AppError> {
+        let mut unspendable = Vec::new();
+        for (tx_hash, entry) in &state.transactions {
+            let outpoints = entry
+                .tx
+                .get_inputs()
+                .iter()
+                .map(|txin| txin.get_previous_output())
+                .filter(|outpoint| !state.transactions.contains_key(outpoint.get_tx_id()))
+                .cloned()
+                .collect::<Vec<_>>();
+            if outpoints.is_empty() {
+                continue;
+            }
+            let utxos = self
+                .utxo_set_r
+                .get_multiple_utxos_by_outpoints(&outpoints)?;
+            if utxos.len() < outpoints.len() {
+                unspendable.push(tx_hash.clone());
+            }
+        }
+        Ok(unspendable)
+    }
+
+    /// Evicts transactions pooled for longer than the expiry age, along with their descendants.
+    fn expire_entries(&self, state: &mut MempoolState, now: &DateTime) {
+        let expired_hashes = state
+            .transactions
+            .iter()
+            .filter(|(_, entry)| {
+                now.to_ms().saturating_sub(entry.added_at.to_ms()) > self.expiry_ms
+            })
+            .map(|(tx_hash, _)| tx_hash.clone())
+            .collect::<Vec<_>>();
+        for tx_hash in expired_hashes {
+            self.evict_entry_with_descendants(state, &tx_hash, MempoolEvictionReason::Expired);
+        }
+    }
+
+    /// Pooled transactions may not spend the same outpoint.<br />
+    /// Only one of them could ever be confirmed.<br />
+    /// With replace-by-fee enabled, the given entry may replace the conflicting transactions instead.<br />
+    /// Returns the hashes of the transactions to be replaced (conflicting ones and their descendants).
+    fn validate_conflicts(
+        &self,
+        state: &MempoolState,
+        entry: &MempoolEntry,
+    ) -> Result<Vec<Hash>, AppError> {
+        let tx_id = entry.tx.get_hash().to_string();
+        let mut conflicting_hashes = Vec::new();
+        for txin in entry.tx.get_inputs() {
+            let outpoint = txin.get_previous_output();
+            let Some(spender_hash) = state.spenders.get(outpoint) else {
+                continue;
+            };
+            if !self.replace_by_fee {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::MempoolConflict {
+                        tx_id,
+                        outpoint: outpoint.to_string(),
+                        conflicting_tx_id: spender_hash.to_string(),
+                    },
+                ));
+            }
+            if !conflicting_hashes.contains(spender_hash) {
+                conflicting_hashes.push(spender_hash.clone());
+            }
+        }
+
+        let fee_rate = entry.get_fee_rate();
+        let mut replaced = HashSet::new();
+        let mut replaced_hashes = Vec::new();
+        for conflicting_hash in &conflicting_hashes {
+            let Some(conflicting) = state.transactions.get(conflicting_hash) else {
+                continue;
+            };
+            if conflicting.get_fee_rate() >= fee_rate {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::ReplacementInsufficientFeeRate {
+                        tx_id,
+                        conflicting_tx_id: conflicting_hash.to_string(),
+                    },
+                ));
+            }
+            for tx_hash in state.collect_with_descendants(conflicting_hash) {
+                if replaced.insert(tx_hash.clone()) {
+                    replaced_hashes.push(tx_hash);
+                }
+            }
+        }
+        if replaced_hashes.len() > self.max_replacement_evictions {
+            return Err(AppError::TransactionValidation(
+                TransactionValidationError::ReplacementTooManyEvictions {
+                    tx_id,
+                    evictions: replaced_hashes.len(),
+                    max_evictions: self.max_replacement_evictions,
+                },
+            ));
+        }
+
+        // Replacements may not depend on the transactions they replace.
+        let ancestors = state.collect_ancestors(&state.find_parents(&entry.tx));
+        if let Some(ancestor_hash) = ancestors.iter().find(|tx_hash| replaced.contains(*tx_hash)) {
+            return Err(AppError::TransactionValidation(
+                TransactionValidationError::ReplacementSpendsConflict {
+                    tx_id,
+                    conflicting_tx_id: ancestor_hash.to_string(),
+                },
+            ));
+        }
+
+        let mut replaced_fee = TransactionAmount::new(0);
+        for replaced_entry in replaced_hashes
+            .iter()
+            .filter_map(|tx_hash| state.transactions.get(tx_hash))
+        {
+            replaced_fee.checked_add_assign(replaced_entry.tx.get_fee())?;
+        }
+        if !replaced_hashes.is_empty() && entry.tx.get_fee() <= replaced_fee {
+            return Err(AppError::TransactionValidation(
+                TransactionValidationError::ReplacementInsufficientFee {
+                    tx_id,
+                    fee: entry.tx.get_fee().as_u128(),
+                    replaced_fee: replaced_fee.as_u128(),
+                },
+            ));
+        }
+
+        Ok(replaced_hashes)
+    }
+
+    /// Selects the lowest fee rate transactions to be evicted for the given entry to fit.<br />
+    /// Evicting a transaction evicts its descendants too, so their combined fee rate must be lower.<br />
+    /// Ancestors of the given entry are never evicted.<br />
+    /// Fails if room can't be made without evicting transactions paying an equal or higher fee rate.
+    fn select_evictions(
+        &self,
+        state: &MempoolState,
+        entry: &MempoolEntry,
+    ) -> Result<Vec<Hash>, AppError> {
+        let fee_rate = entry.get_fee_rate();
+        let ancestors = state.collect_ancestors(&state.find_parents(&entry.tx));
+        let mut size = state.total_size.saturating_add(entry.size);
+        let mut evicted = HashSet::new();
+        let mut evicted_hashes = Vec::new();
+        let mut candidates = state.by_fee_rate.iter();
+        while size > self.max_size {
+            let Some((_, candidate_hash)) = candidates
+                .next()
+                .filter(|(candidate_fee_rate, _)| *candidate_fee_rate < fee_rate)
+            else {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::MempoolFull {
+                        tx_id: entry.tx.get_hash().to_string(),
+                    },
+                ));
+            };
+            if evicted.contains(candidate_hash) || ancestors.contains(candidate_hash) {
+                continue;
+            }
+
+            let package = state
+                .collect_with_descendants(candidate_hash)
+                .into_iter()
+                .filter(|tx_hash| !evicted.contains(tx_hash))
+                .filter_map(|tx_hash| state.transactions.get(&tx_hash))
+                .collect::<Vec<_>>();
+            if FeeRate::of_package(package.iter().copied()) >= fee_rate {
+                continue;
+            }
+            for package_entry in package {
+                size -= package_entry.size;
+                evicted.insert(package_entry.tx.get_hash());
+            }
+            evicted_hashes.push(candidate_hash.clone());
+        }
+        Ok(evicted_hashes)
+    }
+}
+
+impl MempoolState {
+    /// Adds to the eviction count of the given reason, returning the updated count.
+    fn count_evictions(&mut self, reason: MempoolEvictionReason, count: usize) -> usize {
+        let total = self.eviction_counts.entry(reason).or_default();
+        *total = total.saturating_add(count);
+        *total
+    }
+
+    /// Inserts an entry, linking it to its pooled parents and children.
+    fn insert(&mut self, mut entry: MempoolEntry) {
+        let tx_hash = entry.tx.get_hash();
+        self.by_fee_rate
+            .insert((entry.get_fee_rate(), tx_hash.clone()));
+        entry.parents = self.find_parents(&entry.tx);
+        for parent_hash in &entry.parents {
+            if let Some(parent) = self.transactions.get_mut(parent_hash) {
+                parent.children.insert(tx_hash.clone());
+            }
+        }
+        for txin in entry.tx.get_inputs() {
+            self.spenders
+                .insert(txin.get_previous_output().clone(), tx_hash.clone());
+        }
+        // Pooled children may predate their parent (eg: parent re-added on a chain reorg).
+        for output_index in 0..entry.tx.get_outputs().len() {
+            let outpoint = TransactionOutPoint::new(tx_hash.clone(), output_index);
+            let Some(child_hash) = self.spenders.get(&outpoint) else {
+                continue;
+            };
+            if let Some(child) = self.transactions.get_mut(child_hash) {
+                child.parents.insert(tx_hash.clone());
+                entry.children.insert(child_hash.clone());
+            }
+        }
+        self.total_size += entry.size;
+        self.transactions.insert(tx_hash, entry);
+    }
+
+    /// Removes an entry, unlinking it from its pooled parents and children.<br />
+    /// Descendants are left in place.<br />
+    /// The removed entry may be inserted back as is.
+    fn remove(&mut self, tx_hash: &Hash) -> Option<MempoolEntry> {
+        let mut entry = self.transactions.remove(tx_hash)?;
+        self.by_fee_rate
+            .remove(&(entry.get_fee_rate(), tx_hash.clone()));
+        for txin in entry.tx.get_inputs() {
+            self.spenders.remove(txin.get_previous_output());
+        }
+        for parent_hash in entry.parents.drain() {
+            if let Some(parent) = self.transactions.get_mut(&parent_hash) {
+                parent.children.remove(tx_hash);
+            }
+        }
+        for child_hash in entry.children.drain() {
+            if let Some(child) = self.transactions.get_mut(&child_hash) {
+                child.parents.remove(tx_hash);
+            }
+        }
+        self.total_size -= entry.size;
+        Some(entry)
+    }
+
+    /// Finds the pooled transactions whose outputs are spent by the given one.
+    fn find_parents(&self, tx: &Transaction) -> HashSet<Hash> {
+        tx.get_inputs()
+            .iter()
+            .map(|txin| txin.get_previous_output().get_tx_id())
+            .filter(|parent_hash| self.transactions.contains_key(*parent_hash))
+            .cloned()
+            .collect()
+    }
+
+    /// Collects the given transaction's hash along with those of its pooled descendants.<br />
+    /// Each transaction precedes its descendants.
+    fn collect_with_descendants(&self, tx_hash: &Hash) -> Vec<Hash> {
+        let mut visited = HashSet::from([tx_hash.clone()]);
+        let mut hashes = vec![tx_hash.clone()];
+        let mut i = 0;
+        while let Some(tx_hash) = hashes.get(i) {
+            if let Some(entry) = self.transactions.get(tx_hash) {
+                let children = entry
+                    .children
+                    .iter()
+                    .filter(|child_hash| visited.insert((*child_hash).clone()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                hashes.extend(children);
+            }
+            i += 1;
+        }
+        hashes
+    }
+
+    /// Collects the given pooled parents' hashes along with those of their pooled ancestors.
+    fn collect_ancestors(&self, parents: &HashSet<Hash>) -> HashSet<Hash> {
+        let mut ancestors = HashSet::new();
+        let mut stack = parents.iter().collect::<Vec<_>>();
+        while let Some(tx_hash) = stack.pop() {
+            if !ancestors.insert(tx_hash.clone()) {
+                continue;
+            }
+            if let Some(entry) = self.transactions.get(tx_hash) {
+                stack.extend(entry.parents.iter());
+            }
+        }
+        ancestors
+    }
+}
+
+impl MempoolEntry {
+    fn new(tx: Transaction, added_at: DateTime) -> Result<Self, AppError> {
+        let size = tx.compute_encoded_size()?;
+        Ok(Self {
+            tx,
+            size,
+            parents: HashSet::new(),
+            children: HashSet::new(),
+            added_at,
+        })
+    }
+
+    fn get_fee_rate(&self) -> FeeRate {
+        FeeRate {
+            fee: self.tx.get_fee().as_u128(),
+            size: self.size as u128,
+        }
+    }
+}
+
+impl FeeRate {
+    /// Combined fee rate of a set of entries.
+    fn of_package<'a>(entries: impl IntoIterator<Item = &'a MempoolEntry>) -> Self {
+        entries
+            .into_iter()
+            .fold(Self { fee: 0, size: 0 }, |package, entry| Self {
+                fee: package.fee.saturating_add(entry.tx.get_fee().as_u128()),
+                size: package.size.saturating_add(entry.size as u128),
+            })
+    }
+
+    fn ceil_per_byte(&self) -> TransactionAmount {
+        TransactionAmount::new(self.fee.div_ceil(self.size.max(1)))
+    }
+}
+
+impl Ord for FeeRate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.fee.saturating_mul(other.size);
+        let rhs = other.fee.saturating_mul(self.size);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl PartialOrd for FeeRate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for FeeRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FeeRate {}
+
+/// Expose internal methods for unit testing.
+#[cfg(test)]
+impl DefaultMempool {
+    pub(super) async fn pub_rm_transaction(&self, tx_hash: &Hash) -> Option<Transaction> {
+        let mut state = self.state.write().await;
+        self.rm_entry(&mut state, tx_hash).map(|entry| entry.tx)
+    }
+
+    pub(super) async fn pub_get_total_size(&self) -> usize {
+        self.state.read().await.total_size
+    }
+
+    pub(super) async fn pub_expire_entries(&self, now: &DateTime) {
+        let mut state = self.state.write().await;
+        self.expire_entries(&mut state, now);
     }
 
-    async fn rm_transaction(&self, tx_hash: &Hash) -> Option<Transaction> {
-        let mut txs = self.transactions.write().await;
-        txs.remove(tx_hash)
+    pub(super) async fn pub_get_eviction_count(&self, reason: MempoolEvictionReason) -> usize {
+        let state = self.state.read().await;
+        state.eviction_counts.get(&reason).copied().unwrap_or(0)
     }
 }
@@ -1,12 +1,72 @@
 use super::*;
-use crate::entities::block::Block;
-use crate::entities::transaction::{Transaction, TransactionAmount};
+use crate::entities::block::{Block, BlockHeight};
+use crate::entities::transaction::{Transaction, TransactionAmount, TransactionOutPoint};
 use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
+use crate::system::utxo::MockUtxoSetReader;
 use crate::types::hash::Hash;
 use crate::types::sign::PublicKey;
 use crate::types::time::DateTime;
 use common::params::PaginationParams;
 
+const TEST_MAX_MEMPOOL_SIZE: usize = 1024 * 1024;
+const TEST_MAX_REPLACEMENT_EVICTIONS: usize = 3;
+const TEST_MEMPOOL_EXPIRY_SECS: u64 = 60;
+
+fn create_test_mempool() -> DefaultMempool {
+    DefaultMempool::new(
+        TEST_MAX_MEMPOOL_SIZE,
+        TransactionAmount::new(1),
+        false,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![]),
+    )
+}
+
+fn create_test_rbf_mempool() -> DefaultMempool {
+    DefaultMempool::new(
+        TEST_MAX_MEMPOOL_SIZE,
+        TransactionAmount::new(1),
+        true,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![]),
+    )
+}
+
+/// Creates a UTXO set reader resolving every outpoint, except for the given unspendable ones.
+fn create_mock_utxo_set_reader(unspendable: Vec<TransactionOutPoint>) -> Arc<MockUtxoSetReader> {
+    use crate::entities::transaction::TransactionOutput;
+    use crate::types::wallet::WalletAddress;
+
+    let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+    let output = TransactionOutput::new(
+        WalletAddress::from(&wallet_pub_key),
+        TransactionAmount::new(1000),
+    );
+    let mut mock_utxo_reader = MockUtxoSetReader::new();
+    mock_utxo_reader
+        .expect_get_multiple_utxos_by_outpoints()
+        .returning(move |outpoints| {
+            let utxos = outpoints
+                .iter()
+                .filter(|outpoint| !unspendable.contains(outpoint))
+                .map(|outpoint| {
+                    Utxo::new(
+                        outpoint.clone(),
+                        output.clone(),
+                        BlockHeight::from(1),
+                        false,
+                    )
+                })
+                .collect();
+            Ok(utxos)
+        });
+    Arc::new(mock_utxo_reader)
+}
+
 fn create_test_genesis_config() -> GenesisConfig {
     let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
         .parse::<PublicKey>()
@@ -52,7 +112,67 @@ fn create_mock_transaction(seed: u8) -> Transaction {
     // Create a unique timestamp based on the seed to ensure different hashes
     let timestamp = DateTime::from_ms(1000000000 + (seed as u64) * 1000);
     let tx = NonValidatedTransaction::new(vec![], vec![], timestamp).unwrap();
-    Transaction::_new_validated(tx)
+    Transaction::_new_validated(tx, TransactionAmount::new(0))
+}
+
+fn create_mock_transaction_with_fee(seed: u8, fee: u128) -> Transaction {
+    use crate::entities::transaction::NonValidatedTransaction;
+
+    let timestamp = DateTime::from_ms(1000000000 + (seed as u64) * 1000);
+    let tx = NonValidatedTransaction::new(vec![], vec![], timestamp).unwrap();
+    Transaction::_new_validated(tx, TransactionAmount::new(fee))
+}
+
+fn create_mock_spending_transaction(seed: u8, outpoint_seed: u8) -> Transaction {
+    use crate::entities::transaction::{NonValidatedTransaction, TransactionInput};
+
+    let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+    let outpoint = TransactionOutPoint::new(Hash::new([outpoint_seed; 32]), 0);
+    let input = TransactionInput::new(outpoint, wallet_pub_key);
+    let timestamp = DateTime::from_ms(1000000000 + (seed as u64) * 1000);
+    let tx = NonValidatedTransaction::new(vec![input], vec![], timestamp).unwrap();
+    Transaction::_new_validated(tx, TransactionAmount::new(0))
+}
+
+/// Creates a transaction spending the given outpoints into a single output.
+fn create_mock_chained_transaction(
+    seed: u8,
+    outpoints: Vec<TransactionOutPoint>,
+    fee: u128,
+) -> Transaction {
+    use crate::entities::transaction::TransactionOutput;
+    use crate::entities::transaction::{NonValidatedTransaction, TransactionInput};
+    use crate::types::wallet::WalletAddress;
+
+    let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+    let inputs = outpoints
+        .into_iter()
+        .map(|outpoint| TransactionInput::new(outpoint, wallet_pub_key.clone()))
+        .collect();
+    let output = TransactionOutput::new(
+        WalletAddress::from(&wallet_pub_key),
+        TransactionAmount::new(100),
+    );
+    let timestamp = DateTime::from_ms(1000000000 + (seed as u64) * 1000);
+    let tx = NonValidatedTransaction::new(inputs, vec![output], timestamp).unwrap();
+    Transaction::_new_validated(tx, TransactionAmount::new(fee))
+}
+
+/// Creates a mempool fitting exactly the given number of mock transactions.
+fn create_test_mempool_with_capacity(tx_count: usize) -> DefaultMempool {
+    let tx_size = create_mock_transaction(0).compute_encoded_size().unwrap();
+    DefaultMempool::new(
+        tx_size * tx_count,
+        TransactionAmount::new(1),
+        false,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![]),
+    )
 }
 
 // ============================================================================
@@ -61,7 +181,7 @@ fn create_mock_transaction(seed: u8) -> Transaction {
 
 #[tokio::test]
 async fn test_new_mempool_is_empty() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     let fake_hash = Hash::new([1u8; 32]);
     let result = mempool.get_transaction(&fake_hash).await;
@@ -75,7 +195,7 @@ async fn test_new_mempool_is_empty() {
 
 #[tokio::test]
 async fn test_apply_block_removes_transactions() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Create transactions and add them to mempool
     let tx1 = create_mock_transaction(1);
@@ -122,7 +242,7 @@ async fn test_apply_block_removes_transactions() {
 
 #[tokio::test]
 async fn test_apply_block_with_no_matching_transactions() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add a transaction to mempool
     let tx_in_mempool = create_mock_transaction(1);
@@ -145,7 +265,7 @@ async fn test_apply_block_with_no_matching_transactions() {
 
 #[tokio::test]
 async fn test_apply_block_to_empty_mempool() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Create a block with transactions
     let tx = create_mock_transaction(1);
@@ -166,7 +286,7 @@ async fn test_apply_block_to_empty_mempool() {
 
 #[tokio::test]
 async fn test_add_transaction() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let tx = create_mock_transaction(1);
     let tx_hash = tx.get_hash();
 
@@ -186,7 +306,7 @@ async fn test_add_transaction() {
 
 #[tokio::test]
 async fn test_add_multiple_transactions() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let tx1 = create_mock_transaction(1);
     let tx2 = create_mock_transaction(2);
     let tx3 = create_mock_transaction(3);
@@ -215,7 +335,7 @@ async fn test_add_multiple_transactions() {
 
 #[tokio::test]
 async fn test_add_duplicate_transaction_overwrites() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let tx = create_mock_transaction(1);
     let tx_hash = tx.get_hash();
 
@@ -236,7 +356,7 @@ async fn test_add_duplicate_transaction_overwrites() {
 
 #[tokio::test]
 async fn test_get_nonexistent_transaction() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let fake_hash = Hash::new([99u8; 32]);
 
     let result = mempool.get_transaction(&fake_hash).await;
@@ -253,14 +373,14 @@ async fn test_get_nonexistent_transaction() {
 
 #[tokio::test]
 async fn test_rm_transaction_removes_and_returns() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let tx = create_mock_transaction(1);
     let tx_hash = tx.get_hash();
 
     mempool.add_transaction(tx.clone()).await.unwrap();
 
     // Remove transaction
-    let removed = mempool.rm_transaction(&tx_hash).await;
+    let removed = mempool.pub_rm_transaction(&tx_hash).await;
 
     assert!(removed.is_some(), "Should return removed transaction");
     assert_eq!(
@@ -276,10 +396,10 @@ async fn test_rm_transaction_removes_and_returns() {
 
 #[tokio::test]
 async fn test_rm_nonexistent_transaction() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let fake_hash = Hash::new([99u8; 32]);
 
-    let removed = mempool.rm_transaction(&fake_hash).await;
+    let removed = mempool.pub_rm_transaction(&fake_hash).await;
 
     assert!(
         removed.is_none(),
@@ -293,7 +413,7 @@ async fn test_rm_nonexistent_transaction() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_empty_mempool() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
     let pagination = PaginationParams { skip: 0, limit: 10 };
 
     let result = mempool.get_paginated_transactions(pagination).await;
@@ -306,7 +426,7 @@ async fn test_get_paginated_transactions_empty_mempool() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_first_page() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 5 transactions
     for i in 1..=5 {
@@ -326,7 +446,7 @@ async fn test_get_paginated_transactions_first_page() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_second_page() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 10 transactions
     for i in 1..=10 {
@@ -346,7 +466,7 @@ async fn test_get_paginated_transactions_second_page() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_skip_beyond_total() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 5 transactions
     for i in 1..=5 {
@@ -370,7 +490,7 @@ async fn test_get_paginated_transactions_skip_beyond_total() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_limit_exceeds_remaining() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 7 transactions
     for i in 1..=7 {
@@ -394,7 +514,7 @@ async fn test_get_paginated_transactions_limit_exceeds_remaining() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_limit_zero() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 5 transactions
     for i in 1..=5 {
@@ -418,7 +538,7 @@ async fn test_get_paginated_transactions_limit_zero() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_single_transaction() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 1 transaction
     let tx = create_mock_transaction(1);
@@ -436,7 +556,7 @@ async fn test_get_paginated_transactions_single_transaction() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_exact_page_size() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add exactly 10 transactions
     for i in 1..=10 {
@@ -456,7 +576,7 @@ async fn test_get_paginated_transactions_exact_page_size() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_multiple_pages() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 25 transactions
     for i in 1..=25 {
@@ -491,7 +611,7 @@ async fn test_get_paginated_transactions_multiple_pages() {
 
 #[tokio::test]
 async fn test_get_paginated_transactions_after_removal() {
-    let mempool = DefaultMempool::new();
+    let mempool = create_test_mempool();
 
     // Add 10 transactions
     let mut tx_hashes = vec![];
@@ -502,9 +622,9 @@ async fn test_get_paginated_transactions_after_removal() {
     }
 
     // Remove 3 transactions
-    mempool.rm_transaction(&tx_hashes[0]).await;
-    mempool.rm_transaction(&tx_hashes[1]).await;
-    mempool.rm_transaction(&tx_hashes[2]).await;
+    mempool.pub_rm_transaction(&tx_hashes[0]).await;
+    mempool.pub_rm_transaction(&tx_hashes[1]).await;
+    mempool.pub_rm_transaction(&tx_hashes[2]).await;
 
     let pagination = PaginationParams { skip: 0, limit: 10 };
 
@@ -528,7 +648,7 @@ async fn test_get_paginated_transactions_after_removal() {
 async fn test_concurrent_add_and_get() {
     use tokio::task;
 
-    let mempool = std::sync::Arc::new(DefaultMempool::new());
+    let mempool = std::sync::Arc::new(create_test_mempool());
     let mut handles = vec![];
 
     // Spawn multiple tasks adding transactions
@@ -555,7 +675,7 @@ async fn test_concurrent_add_and_get() {
 async fn test_get_paginated_transactions_concurrent_access() {
     use tokio::task;
 
-    let mempool = std::sync::Arc::new(DefaultMempool::new());
+    let mempool = std::sync::Arc::new(create_test_mempool());
 
     // Add transactions concurrently
     let mut handles = vec![];
@@ -603,3 +723,887 @@ async fn test_get_paginated_transactions_concurrent_access() {
     assert_eq!(txs1.len(), 10, "First page should have 10 items");
     assert_eq!(txs2.len(), 10, "Second page should have 10 items");
 }
+
+// ============================================================================
+// Fee rate prioritization & size limits
+// ============================================================================
+
+#[tokio::test]
+async fn test_get_paginated_transactions_orders_by_fee_rate() {
+    let mempool = create_test_mempool();
+    for (seed, fee) in [(1, 200), (2, 500), (3, 100), (4, 300)] {
+        mempool
+            .add_transaction(create_mock_transaction_with_fee(seed, fee))
+            .await
+            .unwrap();
+    }
+
+    let pagination = PaginationParams { skip: 0, limit: 10 };
+    let (transactions, _) = mempool
+        .get_paginated_transactions(pagination)
+        .await
+        .unwrap();
+
+    let fees = transactions
+        .iter()
+        .map(|tx| tx.get_fee().as_u128())
+        .collect::<Vec<_>>();
+    assert_eq!(fees, vec![500, 300, 200, 100], "Highest fee rate first");
+}
+
+#[tokio::test]
+async fn test_add_transaction_tracks_total_size() {
+    let mempool = create_test_mempool();
+    let tx = create_mock_transaction(1);
+    let tx_hash = tx.get_hash();
+    let tx_size = tx.compute_encoded_size().unwrap();
+
+    mempool.add_transaction(tx.clone()).await.unwrap();
+    mempool.add_transaction(tx).await.unwrap();
+    assert_eq!(
+        mempool.pub_get_total_size().await,
+        tx_size,
+        "Overwriting a transaction should not count it twice"
+    );
+
+    mempool.pub_rm_transaction(&tx_hash).await;
+    assert_eq!(mempool.pub_get_total_size().await, 0);
+}
+
+#[tokio::test]
+async fn test_add_transaction_evicts_lowest_fee_rate_when_full() {
+    let mempool = create_test_mempool_with_capacity(3);
+    let low_fee_tx = create_mock_transaction_with_fee(1, 100);
+    let low_fee_tx_hash = low_fee_tx.get_hash();
+    mempool.add_transaction(low_fee_tx).await.unwrap();
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(2, 200))
+        .await
+        .unwrap();
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(3, 300))
+        .await
+        .unwrap();
+
+    let high_fee_tx = create_mock_transaction_with_fee(4, 400);
+    let high_fee_tx_hash = high_fee_tx.get_hash();
+    mempool.add_transaction(high_fee_tx).await.unwrap();
+
+    assert!(
+        mempool.get_transaction(&low_fee_tx_hash).await.is_none(),
+        "Lowest fee rate transaction should be evicted"
+    );
+    assert!(mempool.get_transaction(&high_fee_tx_hash).await.is_some());
+    let (_, count) = mempool
+        .get_paginated_transactions(PaginationParams { skip: 0, limit: 10 })
+        .await
+        .unwrap();
+    assert_eq!(count, 3);
+}
+
+#[tokio::test]
+async fn test_add_transaction_keeps_pooled_transaction_with_same_hash() {
+    use crate::entities::transaction::{NonValidatedTransaction, TransactionInput};
+    use crate::types::sign::PrivateKey;
+    use std::str::FromStr;
+
+    let private_key = PrivateKey::from_str(&format!("{:0>64}", 1)).expect("Valid private key");
+    let outpoint = TransactionOutPoint::new(Hash::new([1u8; 32]), 0);
+    let input = TransactionInput::new(outpoint, private_key.get_public_key());
+    let unsigned_tx =
+        NonValidatedTransaction::new(vec![input], vec![], DateTime::from_ms(1000000000)).unwrap();
+    // Signatures aren't part of the transaction id, only of its size
+    let signed_tx = unsigned_tx.clone().sign(&private_key).unwrap();
+    assert_eq!(unsigned_tx.get_hash(), signed_tx.get_hash());
+    let tx_hash = unsigned_tx.get_hash();
+    let unsigned_tx = Transaction::_new_validated(unsigned_tx, TransactionAmount::new(200));
+    let signed_tx = Transaction::_new_validated(signed_tx, TransactionAmount::new(200));
+
+    let high_fee_tx = create_mock_transaction_with_fee(2, 100000);
+    let max_size =
+        unsigned_tx.compute_encoded_size().unwrap() + high_fee_tx.compute_encoded_size().unwrap();
+    let mempool = DefaultMempool::new(
+        max_size,
+        TransactionAmount::new(1),
+        false,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![]),
+    );
+    mempool.add_transaction(unsigned_tx).await.unwrap();
+    mempool.add_transaction(high_fee_tx).await.unwrap();
+
+    // The larger transaction wouldn't fit into the full mempool
+    let result = mempool.add_transaction(signed_tx).await;
+
+    assert!(
+        result.is_ok(),
+        "Already pooled transactions should be accepted"
+    );
+    let pooled_tx = mempool.get_transaction(&tx_hash).await;
+    assert!(pooled_tx.is_some(), "Pooled transaction should be kept");
+    assert_eq!(
+        pooled_tx.unwrap().get_inputs()[0].get_signature(),
+        None,
+        "Pooled transaction shouldn't be replaced"
+    );
+}
+
+#[tokio::test]
+async fn test_add_transaction_rejects_low_fee_rate_when_full() {
+    let mempool = create_test_mempool_with_capacity(2);
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(1, 200))
+        .await
+        .unwrap();
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(2, 300))
+        .await
+        .unwrap();
+
+    let low_fee_tx = create_mock_transaction_with_fee(3, 200);
+    let low_fee_tx_hash = low_fee_tx.get_hash();
+    let result = mempool.add_transaction(low_fee_tx).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::TransactionValidation(
+            TransactionValidationError::MempoolFull { .. }
+        ))
+    ));
+    assert!(mempool.get_transaction(&low_fee_tx_hash).await.is_none());
+    let (_, count) = mempool
+        .get_paginated_transactions(PaginationParams { skip: 0, limit: 10 })
+        .await
+        .unwrap();
+    assert_eq!(count, 2, "No transactions should be evicted");
+}
+
+#[tokio::test]
+async fn test_get_min_fee_rate_rises_on_eviction() {
+    let mempool = create_test_mempool_with_capacity(2);
+    let tx_size = create_mock_transaction(0).compute_encoded_size().unwrap() as u128;
+    assert_eq!(
+        mempool.get_min_fee_rate().await,
+        TransactionAmount::new(1),
+        "Should start off at the minimum relay fee rate"
+    );
+
+    // Fee rate of exactly 10 per byte
+    let evicted_fee = 10 * tx_size;
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(1, evicted_fee))
+        .await
+        .unwrap();
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(2, evicted_fee * 2))
+        .await
+        .unwrap();
+    mempool
+        .add_transaction(create_mock_transaction_with_fee(3, evicted_fee * 3))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        mempool.get_min_fee_rate().await,
+        TransactionAmount::new(11),
+        "Should exceed the evicted fee rate by the minimum relay fee rate"
+    );
+}
+
+#[tokio::test]
+async fn test_get_min_fee_rate_resets_once_drained() {
+    let mempool = create_test_mempool_with_capacity(2);
+    let mut tx_hashes = vec![];
+    for seed in 1..=3 {
+        let tx = create_mock_transaction_with_fee(seed, 1000 * seed as u128);
+        tx_hashes.push(tx.get_hash());
+        mempool.add_transaction(tx).await.unwrap();
+    }
+    assert!(mempool.get_min_fee_rate().await > TransactionAmount::new(1));
+
+    for tx_hash in &tx_hashes {
+        mempool.pub_rm_transaction(tx_hash).await;
+    }
+    assert_eq!(mempool.get_min_fee_rate().await, TransactionAmount::new(1));
+}
+
+// ============================================================================
+// Conflict detection
+// ============================================================================
+
+#[tokio::test]
+async fn test_add_transaction_rejects_conflicting_spend() {
+    let mempool = create_test_mempool();
+    let tx = create_mock_spending_transaction(1, 1);
+    let tx_hash = tx.get_hash();
+    mempool.add_transaction(tx).await.unwrap();
+
+    let conflicting_tx = create_mock_spending_transaction(2, 1);
+    let conflicting_tx_hash = conflicting_tx.get_hash();
+    let result = mempool.add_transaction(conflicting_tx).await;
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::MempoolConflict {
+            conflicting_tx_id,
+            ..
+        }) => {
+            assert_eq!(conflicting_tx_id, tx_hash.to_string());
+        }
+        _ => panic!("Expected MempoolConflict error"),
+    }
+    assert!(
+        mempool
+            .get_transaction(&conflicting_tx_hash)
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_add_transaction_accepts_spend_after_removal() {
+    let mempool = create_test_mempool();
+    let tx = create_mock_spending_transaction(1, 1);
+    let tx_hash = tx.get_hash();
+    mempool.add_transaction(tx).await.unwrap();
+    mempool.pub_rm_transaction(&tx_hash).await;
+
+    let result = mempool
+        .add_transaction(create_mock_spending_transaction(2, 1))
+        .await;
+    assert!(
+        result.is_ok(),
+        "Outpoint should be released once its spender is removed"
+    );
+}
+
+#[tokio::test]
+async fn test_apply_block_removes_conflicting_transactions() {
+    let mempool = create_test_mempool();
+    let pending_tx = create_mock_spending_transaction(1, 1);
+    let pending_tx_hash = pending_tx.get_hash();
+    let unrelated_tx = create_mock_spending_transaction(2, 2);
+    let unrelated_tx_hash = unrelated_tx.get_hash();
+    mempool.add_transaction(pending_tx).await.unwrap();
+    mempool.add_transaction(unrelated_tx).await.unwrap();
+
+    // A different transaction spending the same outpoint gets confirmed.
+    let confirmed_tx = create_mock_spending_transaction(3, 1);
+    let block = create_test_block_with_transactions(vec![confirmed_tx]);
+    mempool.apply_block(&block).await.unwrap();
+
+    assert!(
+        mempool.get_transaction(&pending_tx_hash).await.is_none(),
+        "Conflicting transaction should be removed"
+    );
+    assert!(
+        mempool.get_transaction(&unrelated_tx_hash).await.is_some(),
+        "Unrelated transaction should remain"
+    );
+    assert!(
+        mempool
+            .add_transaction(create_mock_spending_transaction(4, 1))
+            .await
+            .is_ok(),
+        "Conflicting spender should no longer hold the outpoint"
+    );
+}
+
+// ============================================================================
+// Chained transactions
+// ============================================================================
+
+#[tokio::test]
+async fn test_get_pending_utxos_resolves_pooled_outputs() {
+    let mempool = create_test_mempool();
+    let parent_tx = create_mock_chained_transaction(
+        1,
+        vec![TransactionOutPoint::new(Hash::new([1u8; 32]), 0)],
+        100,
+    );
+    let parent_output = TransactionOutPoint::new(parent_tx.get_hash(), 0);
+    mempool.add_transaction(parent_tx.clone()).await.unwrap();
+
+    let outpoints = vec![
+        parent_output.clone(),
+        TransactionOutPoint::new(parent_tx.get_hash(), 1),
+        TransactionOutPoint::new(Hash::new([2u8; 32]), 0),
+    ];
+    let block_height = BlockHeight::from(5);
+    let utxos = mempool.get_pending_utxos(&outpoints, &block_height).await;
+
+    assert_eq!(utxos.len(), 1, "Only pooled outputs should be resolved");
+    assert_eq!(utxos[0].get_outpoint(), &parent_output);
+    assert_eq!(
+        utxos[0].get_output().get_amount(),
+        parent_tx.get_outputs()[0].get_amount()
+    );
+    assert_eq!(utxos[0].get_block_height(), &block_height);
+    assert!(!utxos[0].is_coinbase());
+}
+
+#[tokio::test]
+async fn test_get_block_candidates_orders_parents_first() {
+    let mempool = create_test_mempool();
+    let parent_tx = create_mock_chained_transaction(
+        1,
+        vec![TransactionOutPoint::new(Hash::new([1u8; 32]), 0)],
+        100,
+    );
+    let parent_tx_hash = parent_tx.get_hash();
+    // The child outbids both its parent and the unrelated transaction.
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        5000,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    let unrelated_tx = create_mock_transaction_with_fee(3, 1000);
+    let unrelated_tx_hash = unrelated_tx.get_hash();
+    mempool.add_transaction(parent_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+    mempool.add_transaction(unrelated_tx).await.unwrap();
+
+    let candidates = mempool
+        .get_block_candidates(10)
+        .await
+        .iter()
+        .map(|tx| tx.get_hash())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        candidates,
+        vec![parent_tx_hash, child_tx_hash, unrelated_tx_hash],
+        "Parents should precede their children"
+    );
+}
+
+#[tokio::test]
+async fn test_get_block_candidates_respects_limit() {
+    let mempool = create_test_mempool();
+    for seed in 1..=3 {
+        mempool
+            .add_transaction(create_mock_transaction_with_fee(seed, 100 * seed as u128))
+            .await
+            .unwrap();
+    }
+
+    let candidates = mempool.get_block_candidates(2).await;
+    assert_eq!(candidates.len(), 2);
+    assert_eq!(candidates[0].get_fee(), TransactionAmount::new(300));
+}
+
+#[tokio::test]
+async fn test_add_transaction_eviction_cascades_to_descendants() {
+    let parent_tx = create_mock_chained_transaction(
+        1,
+        vec![TransactionOutPoint::new(Hash::new([1u8; 32]), 0)],
+        100,
+    );
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    let max_size =
+        parent_tx.compute_encoded_size().unwrap() + child_tx.compute_encoded_size().unwrap();
+    let mempool = DefaultMempool::new(
+        max_size,
+        TransactionAmount::new(1),
+        false,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![]),
+    );
+    mempool.add_transaction(parent_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    let high_fee_tx = create_mock_transaction_with_fee(3, 100000);
+    let high_fee_tx_hash = high_fee_tx.get_hash();
+    mempool.add_transaction(high_fee_tx).await.unwrap();
+
+    assert!(mempool.get_transaction(&parent_tx_hash).await.is_none());
+    assert!(
+        mempool.get_transaction(&child_tx_hash).await.is_none(),
+        "Evicting a parent should evict its children"
+    );
+    assert!(mempool.get_transaction(&high_fee_tx_hash).await.is_some());
+    assert_eq!(
+        mempool.pub_get_total_size().await,
+        create_mock_transaction(0).compute_encoded_size().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_apply_block_conflict_removal_cascades_to_descendants() {
+    let mempool = create_test_mempool();
+    let parent_tx = create_mock_chained_transaction(
+        1,
+        vec![TransactionOutPoint::new(Hash::new([1u8; 32]), 0)],
+        100,
+    );
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    mempool.add_transaction(parent_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    // A different transaction spending the parent's input gets confirmed.
+    let confirmed_tx = create_mock_spending_transaction(3, 1);
+    let block = create_test_block_with_transactions(vec![confirmed_tx]);
+    mempool.apply_block(&block).await.unwrap();
+
+    assert!(mempool.get_transaction(&parent_tx_hash).await.is_none());
+    assert!(
+        mempool.get_transaction(&child_tx_hash).await.is_none(),
+        "Descendants of conflicting transactions should be removed"
+    );
+    assert_eq!(mempool.pub_get_total_size().await, 0);
+}
+
+#[tokio::test]
+async fn test_apply_block_keeps_children_of_confirmed_transactions() {
+    let mempool = create_test_mempool();
+    let parent_tx = create_mock_chained_transaction(
+        1,
+        vec![TransactionOutPoint::new(Hash::new([1u8; 32]), 0)],
+        100,
+    );
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    mempool.add_transaction(parent_tx.clone()).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    let block = create_test_block_with_transactions(vec![parent_tx]);
+    mempool.apply_block(&block).await.unwrap();
+
+    assert!(mempool.get_transaction(&parent_tx_hash).await.is_none());
+    assert!(
+        mempool.get_transaction(&child_tx_hash).await.is_some(),
+        "Children of confirmed transactions should remain"
+    );
+    let candidates = mempool.get_block_candidates(10).await;
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].get_hash(), child_tx_hash);
+}
+
+#[tokio::test]
+async fn test_add_transaction_eviction_spares_ancestors() {
+    let parent_tx = create_mock_chained_transaction(
+        1,
+        vec![TransactionOutPoint::new(Hash::new([1u8; 32]), 0)],
+        100,
+    );
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        100000,
+    );
+    let max_size =
+        parent_tx.compute_encoded_size().unwrap() + child_tx.compute_encoded_size().unwrap() - 1;
+    let mempool = DefaultMempool::new(
+        max_size,
+        TransactionAmount::new(1),
+        false,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![]),
+    );
+    mempool.add_transaction(parent_tx).await.unwrap();
+
+    let result = mempool.add_transaction(child_tx).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::TransactionValidation(
+            TransactionValidationError::MempoolFull { .. }
+        ))
+    ));
+    assert!(
+        mempool.get_transaction(&parent_tx_hash).await.is_some(),
+        "Parents should not be evicted in favor of their children"
+    );
+}
+
+// ============================================================================
+// Replace-by-fee
+// ============================================================================
+
+fn create_test_outpoint(seed: u8) -> TransactionOutPoint {
+    TransactionOutPoint::new(Hash::new([seed; 32]), 0)
+}
+
+#[tokio::test]
+async fn test_add_transaction_replaces_conflicting_transaction() {
+    let mempool = create_test_rbf_mempool();
+    let original_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let original_tx_hash = original_tx.get_hash();
+    mempool.add_transaction(original_tx).await.unwrap();
+
+    let replacement_tx = create_mock_chained_transaction(2, vec![create_test_outpoint(1)], 200);
+    let replacement_tx_hash = replacement_tx.get_hash();
+    mempool.add_transaction(replacement_tx).await.unwrap();
+
+    assert!(mempool.get_transaction(&original_tx_hash).await.is_none());
+    assert!(
+        mempool
+            .get_transaction(&replacement_tx_hash)
+            .await
+            .is_some()
+    );
+    assert_eq!(
+        mempool
+            .get_pending_utxos(
+                &[TransactionOutPoint::new(original_tx_hash, 0)],
+                &BlockHeight::from(1)
+            )
+            .await
+            .len(),
+        0,
+        "Replaced outputs should no longer be spendable"
+    );
+}
+
+#[tokio::test]
+async fn test_add_transaction_replacement_evicts_descendants() {
+    let mempool = create_test_rbf_mempool();
+    let original_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let original_tx_hash = original_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(original_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    mempool.add_transaction(original_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    let replacement_tx = create_mock_chained_transaction(3, vec![create_test_outpoint(1)], 201);
+    let replacement_tx_hash = replacement_tx.get_hash();
+    mempool
+        .add_transaction(replacement_tx.clone())
+        .await
+        .unwrap();
+
+    assert!(mempool.get_transaction(&original_tx_hash).await.is_none());
+    assert!(
+        mempool.get_transaction(&child_tx_hash).await.is_none(),
+        "Descendants of replaced transactions should be evicted"
+    );
+    assert!(
+        mempool
+            .get_transaction(&replacement_tx_hash)
+            .await
+            .is_some()
+    );
+    assert_eq!(
+        mempool.pub_get_total_size().await,
+        replacement_tx.compute_encoded_size().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_add_transaction_replacement_requires_higher_absolute_fee() {
+    let mempool = create_test_rbf_mempool();
+    let original_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let original_tx_hash = original_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(original_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    mempool.add_transaction(original_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    // Outbids the original's fee rate, but not the fees of the original and its child combined.
+    let replacement_tx = create_mock_chained_transaction(3, vec![create_test_outpoint(1)], 200);
+    let result = mempool.add_transaction(replacement_tx).await;
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(
+            TransactionValidationError::ReplacementInsufficientFee {
+                fee, replaced_fee, ..
+            },
+        ) => {
+            assert_eq!(fee, 200);
+            assert_eq!(replaced_fee, 200);
+        }
+        _ => panic!("Expected ReplacementInsufficientFee error"),
+    }
+    assert!(mempool.get_transaction(&original_tx_hash).await.is_some());
+    assert!(mempool.get_transaction(&child_tx_hash).await.is_some());
+}
+
+#[tokio::test]
+async fn test_add_transaction_replacement_requires_higher_fee_rate() {
+    let mempool = create_test_rbf_mempool();
+    let original_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let original_tx_hash = original_tx.get_hash();
+    mempool.add_transaction(original_tx).await.unwrap();
+
+    let replacement_tx = create_mock_chained_transaction(2, vec![create_test_outpoint(1)], 100);
+    let result = mempool.add_transaction(replacement_tx).await;
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(
+            TransactionValidationError::ReplacementInsufficientFeeRate {
+                conflicting_tx_id, ..
+            },
+        ) => {
+            assert_eq!(conflicting_tx_id, original_tx_hash.to_string());
+        }
+        _ => panic!("Expected ReplacementInsufficientFeeRate error"),
+    }
+    assert!(mempool.get_transaction(&original_tx_hash).await.is_some());
+}
+
+#[tokio::test]
+async fn test_add_transaction_replacement_eviction_limit() {
+    let mempool = create_test_rbf_mempool();
+    let original_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let original_tx_hash = original_tx.get_hash();
+    mempool.add_transaction(original_tx).await.unwrap();
+
+    // Chain enough descendants to exceed the eviction limit.
+    let mut parent_tx_hash = original_tx_hash.clone();
+    for seed in 2..=TEST_MAX_REPLACEMENT_EVICTIONS as u8 + 1 {
+        let child_tx = create_mock_chained_transaction(
+            seed,
+            vec![TransactionOutPoint::new(parent_tx_hash, 0)],
+            100,
+        );
+        parent_tx_hash = child_tx.get_hash();
+        mempool.add_transaction(child_tx).await.unwrap();
+    }
+
+    let replacement_tx = create_mock_chained_transaction(100, vec![create_test_outpoint(1)], 10000);
+    let result = mempool.add_transaction(replacement_tx).await;
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(
+            TransactionValidationError::ReplacementTooManyEvictions {
+                evictions,
+                max_evictions,
+                ..
+            },
+        ) => {
+            assert_eq!(evictions, TEST_MAX_REPLACEMENT_EVICTIONS + 1);
+            assert_eq!(max_evictions, TEST_MAX_REPLACEMENT_EVICTIONS);
+        }
+        _ => panic!("Expected ReplacementTooManyEvictions error"),
+    }
+    assert!(mempool.get_transaction(&original_tx_hash).await.is_some());
+}
+
+#[tokio::test]
+async fn test_add_transaction_replacement_spending_conflict_rejected() {
+    let mempool = create_test_rbf_mempool();
+    let original_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let original_tx_hash = original_tx.get_hash();
+    mempool.add_transaction(original_tx).await.unwrap();
+
+    let replacement_tx = create_mock_chained_transaction(
+        2,
+        vec![
+            create_test_outpoint(1),
+            TransactionOutPoint::new(original_tx_hash.clone(), 0),
+        ],
+        10000,
+    );
+    let result = mempool.add_transaction(replacement_tx).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::TransactionValidation(
+            TransactionValidationError::ReplacementSpendsConflict { .. }
+        ))
+    ));
+    assert!(mempool.get_transaction(&original_tx_hash).await.is_some());
+}
+
+// ============================================================================
+// Revalidation and expiry
+// ============================================================================
+
+#[tokio::test]
+async fn test_apply_block_evicts_transactions_with_unspendable_inputs() {
+    let parent_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    let unrelated_tx = create_mock_chained_transaction(3, vec![create_test_outpoint(3)], 100);
+    let unrelated_tx_hash = unrelated_tx.get_hash();
+    let mempool = DefaultMempool::new(
+        TEST_MAX_MEMPOOL_SIZE,
+        TransactionAmount::new(1),
+        false,
+        TEST_MAX_REPLACEMENT_EVICTIONS,
+        TEST_MEMPOOL_EXPIRY_SECS,
+        create_mock_utxo_set_reader(vec![create_test_outpoint(1)]),
+    );
+    mempool.add_transaction(parent_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+    mempool.add_transaction(unrelated_tx).await.unwrap();
+
+    let block = create_test_block_with_transactions(vec![create_mock_transaction(9)]);
+    mempool.apply_block(&block).await.unwrap();
+
+    assert!(mempool.get_transaction(&parent_tx_hash).await.is_none());
+    assert!(
+        mempool.get_transaction(&child_tx_hash).await.is_none(),
+        "Descendants of unspendable transactions should be evicted"
+    );
+    assert!(mempool.get_transaction(&unrelated_tx_hash).await.is_some());
+    assert_eq!(
+        mempool
+            .pub_get_eviction_count(MempoolEvictionReason::InputsUnspendable)
+            .await,
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_apply_block_counts_conflict_evictions() {
+    let mempool = create_test_mempool();
+    let pooled_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let pooled_tx_hash = pooled_tx.get_hash();
+    mempool.add_transaction(pooled_tx).await.unwrap();
+
+    let confirmed_tx = create_mock_chained_transaction(2, vec![create_test_outpoint(1)], 100);
+    let block = create_test_block_with_transactions(vec![confirmed_tx]);
+    mempool.apply_block(&block).await.unwrap();
+
+    assert!(mempool.get_transaction(&pooled_tx_hash).await.is_none());
+    assert_eq!(
+        mempool
+            .pub_get_eviction_count(MempoolEvictionReason::Conflict)
+            .await,
+        1
+    );
+    assert_eq!(
+        mempool
+            .pub_get_eviction_count(MempoolEvictionReason::InputsUnspendable)
+            .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_expire_entries_evicts_expired_transactions_with_descendants() {
+    let mempool = create_test_mempool();
+    let parent_tx = create_mock_chained_transaction(1, vec![create_test_outpoint(1)], 100);
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx = create_mock_chained_transaction(
+        2,
+        vec![TransactionOutPoint::new(parent_tx_hash.clone(), 0)],
+        100,
+    );
+    let child_tx_hash = child_tx.get_hash();
+    mempool.add_transaction(parent_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    let expired_at =
+        DateTime::from_ms(DateTime::now().to_ms() + (TEST_MEMPOOL_EXPIRY_SECS + 1) * 1000);
+    mempool.pub_expire_entries(&expired_at).await;
+
+    assert!(mempool.get_transaction(&parent_tx_hash).await.is_none());
+    assert!(mempool.get_transaction(&child_tx_hash).await.is_none());
+    assert_eq!(
+        mempool
+            .pub_get_eviction_count(MempoolEvictionReason::Expired)
+            .await,
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_expire_entries_keeps_fresh_transactions() {
+    let mempool = create_test_mempool();
+    let tx = create_mock_transaction(1);
+    let tx_hash = tx.get_hash();
+    mempool.add_transaction(tx).await.unwrap();
+
+    mempool.pub_expire_entries(&DateTime::now()).await;
+
+    assert!(mempool.get_transaction(&tx_hash).await.is_some());
+    assert_eq!(
+        mempool
+            .pub_get_eviction_count(MempoolEvictionReason::Expired)
+            .await,
+        0
+    );
+}
+
+// ============================================================================
+// restore_transaction()
+// ============================================================================
+
+#[tokio::test]
+async fn test_restore_transaction_expires_relative_to_added_at() {
+    let mempool = create_test_mempool();
+    let restored_tx = create_mock_transaction(1);
+    let restored_tx_hash = restored_tx.get_hash();
+    let fresh_tx = create_mock_transaction(2);
+    let fresh_tx_hash = fresh_tx.get_hash();
+    // Pooled shortly before the node restarted
+    let added_at =
+        DateTime::from_ms(DateTime::now().to_ms() - (TEST_MEMPOOL_EXPIRY_SECS - 10) * 1000);
+
+    mempool
+        .restore_transaction(restored_tx, added_at.clone())
+        .await
+        .unwrap();
+    mempool.add_transaction(fresh_tx).await.unwrap();
+
+    let pooled = mempool.get_pooled_transactions().await;
+    let (_, restored_added_at) = pooled
+        .iter()
+        .find(|(tx, _)| tx.get_hash() == restored_tx_hash)
+        .expect("Restored transaction should be pooled");
+    assert_eq!(restored_added_at.to_ms(), added_at.to_ms());
+
+    mempool
+        .pub_expire_entries(&DateTime::from_ms(DateTime::now().to_ms() + 20 * 1000))
+        .await;
+
+    assert!(
+        mempool.get_transaction(&restored_tx_hash).await.is_none(),
+        "Restored transaction should expire relative to when it was first pooled"
+    );
+    assert!(mempool.get_transaction(&fresh_tx_hash).await.is_some());
+}
+
+#[tokio::test]
+async fn test_restore_transaction_evicts_expired() {
+    let mempool = create_test_mempool();
+    let tx = create_mock_transaction(1);
+    let tx_hash = tx.get_hash();
+    let added_at =
+        DateTime::from_ms(DateTime::now().to_ms() - (TEST_MEMPOOL_EXPIRY_SECS + 1) * 1000);
+
+    mempool.restore_transaction(tx, added_at).await.unwrap();
+
+    assert!(mempool.get_transaction(&tx_hash).await.is_none());
+    assert_eq!(
+        mempool
+            .pub_get_eviction_count(MempoolEvictionReason::Expired)
+            .await,
+        1
+    );
+}
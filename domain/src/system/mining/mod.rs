@@ -0,0 +1,96 @@
+mod template;
+#[cfg(test)]
+mod tests;
+mod worker;
+
+pub(crate) use template::{BlockTemplateBuilder, DefaultBlockTemplateBuilder};
+pub(crate) use worker::{BlockMiningWorker, MiningSwitch};
+
+use crate::entities::block::BlockTemplate;
+use async_trait::async_trait;
+use common::error::AppError;
+use common::{log_blk_debug, log_blk_info};
+
+/// Determines whether an in-progress mining job has gone stale (eg: the blockchain tip moved).
+#[async_trait]
+pub trait MiningCancellation: Send + Sync {
+    async fn is_cancelled(&self) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+pub trait BlockMiner: Send + Sync + std::fmt::Debug {
+    /// Performs a proof-of-work nonce search on the given block template.<br />
+    /// Returns the solved template, or `None` if the job got cancelled.
+    async fn mine_block(
+        &self,
+        block_tpl: BlockTemplate,
+        cancellation: &dyn MiningCancellation,
+    ) -> Result<Option<BlockTemplate>, AppError>;
+}
+
+#[derive(Debug)]
+pub struct DefaultBlockMiner {
+    attempts_per_round: u64,
+}
+
+impl DefaultBlockMiner {
+    /// Number of nonces tried in between cancellation checks.
+    const DEFAULT_ATTEMPTS_PER_ROUND: u64 = 10_000;
+
+    pub fn new() -> Self {
+        Self {
+            attempts_per_round: Self::DEFAULT_ATTEMPTS_PER_ROUND,
+        }
+    }
+}
+
+impl Default for DefaultBlockMiner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BlockMiner for DefaultBlockMiner {
+    async fn mine_block(
+        &self,
+        mut block_tpl: BlockTemplate,
+        cancellation: &dyn MiningCancellation,
+    ) -> Result<Option<BlockTemplate>, AppError> {
+        log_blk_debug!(
+            "BlockMiner: Mining block template | Height: {}",
+            block_tpl.get_height()
+        );
+
+        let attempts = self.attempts_per_round;
+        loop {
+            if cancellation.is_cancelled().await? {
+                log_blk_info!(
+                    "BlockMiner: Mining job cancelled | Height: {}",
+                    block_tpl.get_height()
+                );
+                return Ok(None);
+            }
+
+            // Hashing is CPU-bound, keep it off the async runtime.
+            let (tpl, solved) = tokio::task::spawn_blocking(move || {
+                let solved = block_tpl.try_solve(attempts);
+                (block_tpl, solved)
+            })
+            .await
+            .map_err(|err| {
+                AppError::internal(format!("Mining task failed to complete! | Error: {}", err))
+            })?;
+            block_tpl = tpl;
+
+            if solved? {
+                log_blk_info!(
+                    "BlockMiner: Block template solved | Height: {} | Nonce: {}",
+                    block_tpl.get_height(),
+                    block_tpl.get_nonce().as_u64()
+                );
+                return Ok(Some(block_tpl));
+            }
+        }
+    }
+}
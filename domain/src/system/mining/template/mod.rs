@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests;
+
+use crate::encode::TryEncode;
+use crate::entities::block::{Block, BlockHeight, BlockTemplate};
+use crate::entities::transaction::{
+    NonValidatedTransaction, TransactionAmount, TransactionOutPoint, TransactionOutput, Utxo,
+};
+use crate::system::blockchain::Blockchain;
+use crate::system::difficulty::DifficultyAdjuster;
+use crate::system::mempool::Mempool;
+use crate::system::monetary::MonetaryPolicy;
+use crate::system::validation::transaction::TransactionValidator;
+use crate::types::time::DateTime;
+use crate::types::wallet::WalletAddress;
+use async_trait::async_trait;
+use common::error::AppError;
+use common::log_blk_debug;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[async_trait]
+pub(crate) trait BlockTemplateBuilder: Send + Sync + std::fmt::Debug {
+    /// Assembles a block template on top of the blockchain tip out of the mempool's transactions.<br />
+    /// The coinbase, paying the given address, claims the block subsidy along with all collected fees.<br />
+    /// Returns `None` if the blockchain has yet to be bootstrapped.
+    async fn build_block_template(
+        &self,
+        reward_address: WalletAddress,
+    ) -> Result<Option<BlockTemplate>, AppError>;
+}
+
+#[derive(Debug)]
+pub(crate) struct DefaultBlockTemplateBuilder {
+    blockchain: Arc<dyn Blockchain>,
+    mempool: Arc<dyn Mempool>,
+    tx_validator: Arc<dyn TransactionValidator>,
+    difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
+}
+
+impl DefaultBlockTemplateBuilder {
+    /// Encoded size set aside for the block header and the coinbase transaction.
+    const TEMPLATE_SIZE_RESERVE: usize = 1_024;
+
+    pub(crate) fn new(
+        blockchain: Arc<dyn Blockchain>,
+        mempool: Arc<dyn Mempool>,
+        tx_validator: Arc<dyn TransactionValidator>,
+        difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
+    ) -> Self {
+        Self {
+            blockchain,
+            mempool,
+            tx_validator,
+            difficulty_adjuster,
+        }
+    }
+}
+
+#[async_trait]
+impl BlockTemplateBuilder for DefaultBlockTemplateBuilder {
+    async fn build_block_template(
+        &self,
+        reward_address: WalletAddress,
+    ) -> Result<Option<BlockTemplate>, AppError> {
+        let Some((tip_hash, tip_height)) = self.blockchain.get_tip_info().await? else {
+            return Ok(None);
+        };
+        let tip_block = self
+            .blockchain
+            .get_canon_block(&tip_hash)
+            .await?
+            .ok_or(AppError::internal("Tip block not found!"))?;
+        let difficulty_target = self
+            .difficulty_adjuster
+            .get_difficulty_target(&tip_height.next())
+            .await?;
+        let transactions = self
+            .build_template_transactions(&tip_height.next(), reward_address)
+            .await?;
+        let block_tpl = BlockTemplate::new(&tip_block, transactions, difficulty_target);
+        Ok(Some(block_tpl))
+    }
+}
+
+impl DefaultBlockTemplateBuilder {
+    /// Assembles a block template's transactions, starting with the coinbase.<br />
+    /// Mempool transactions that are invalid or conflicting with previously selected ones are skipped.<br />
+    /// Selection stops short of the consensus block size and transaction count limits.<br />
+    /// The coinbase claims the full block subsidy along with all collected fees.
+    async fn build_template_transactions(
+        &self,
+        block_height: &BlockHeight,
+        reward_address: WalletAddress,
+    ) -> Result<Vec<NonValidatedTransaction>, AppError> {
+        // Pooled parents are yielded ahead of their children.
+        let mempool_txs = self
+            .mempool
+            .get_block_candidates(Block::MAX_TRANSACTIONS)
+            .await;
+
+        let max_size = Block::MAX_ENCODED_SIZE - Self::TEMPLATE_SIZE_RESERVE;
+        let mut size = 0;
+        let mut transactions = Vec::new();
+        let mut spent_outpoints = HashSet::<TransactionOutPoint>::new();
+        // Outputs of selected transactions, spendable by subsequent ones.
+        let mut template_utxos = HashMap::<TransactionOutPoint, Utxo>::new();
+        let mut fees = TransactionAmount::new(0);
+        for tx in mempool_txs {
+            // Leave room for the coinbase.
+            if transactions.len() + 1 >= Block::MAX_TRANSACTIONS {
+                break;
+            }
+            let tx = tx.invalidate();
+            let tx_size = tx.try_encode()?.len();
+            if size + tx_size > max_size {
+                continue;
+            }
+            let outpoints = tx
+                .get_inputs()
+                .iter()
+                .map(|txin| txin.get_previous_output().clone())
+                .collect::<Vec<_>>();
+            if outpoints
+                .iter()
+                .any(|outpoint| spent_outpoints.contains(outpoint))
+            {
+                continue;
+            }
+            let pending_utxos = outpoints
+                .iter()
+                .filter_map(|outpoint| template_utxos.get(outpoint))
+                .cloned()
+                .collect();
+            let fee = match self
+                .tx_validator
+                .validate_transaction(tx.clone(), block_height, pending_utxos)
+                .await
+            {
+                Ok(validated_tx) => validated_tx.get_fee(),
+                Err(err) => {
+                    log_blk_debug!(
+                        "BlockTemplateBuilder: Skipping invalid mempool transaction | Hash: {} | Error: {}",
+                        tx.get_hash(),
+                        err
+                    );
+                    continue;
+                }
+            };
+            fees.checked_add_assign(fee)?;
+            size += tx_size;
+            spent_outpoints.extend(outpoints);
+            let tx_hash = tx.get_hash();
+            for (output_index, txout) in tx.get_outputs().iter().enumerate() {
+                let outpoint = TransactionOutPoint::new(tx_hash.clone(), output_index);
+                let utxo = Utxo::new(outpoint.clone(), txout.clone(), block_height.clone(), false);
+                template_utxos.insert(outpoint, utxo);
+            }
+            transactions.push(tx);
+        }
+
+        let mut coinbase_reward = MonetaryPolicy::get_block_subsidy(block_height);
+        coinbase_reward.checked_add_assign(fees)?;
+        let coinbase_tx = NonValidatedTransaction::new_coinbase(
+            block_height.clone(),
+            vec![TransactionOutput::new(reward_address, coinbase_reward)],
+            DateTime::now(),
+        )?;
+        transactions.insert(0, coinbase_tx);
+        Ok(transactions)
+    }
+}
+
+/// Expose internal methods for unit testing.
+#[cfg(test)]
+impl DefaultBlockTemplateBuilder {
+    pub(super) async fn pub_build_template_transactions(
+        &self,
+        block_height: &BlockHeight,
+        reward_address: WalletAddress,
+    ) -> Result<Vec<NonValidatedTransaction>, AppError> {
+        self.build_template_transactions(block_height, reward_address)
+            .await
+    }
+}
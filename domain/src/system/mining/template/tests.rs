@@ -0,0 +1,348 @@
+use super::*;
+use crate::entities::block::{BlockDifficultyTarget, NonValidatedBlock};
+use crate::entities::transaction::{Transaction, TransactionInput};
+use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
+use crate::system::blockchain::MockBlockchain;
+use crate::system::difficulty::MockDifficultyAdjuster;
+use crate::system::mempool::DefaultMempool;
+use crate::system::utxo::MockUtxoSetReader;
+use crate::system::validation::transaction::MockTransactionValidator;
+use crate::types::hash::Hash;
+use crate::types::sign::PublicKey;
+use common::error::TransactionValidationError;
+use std::str::FromStr;
+
+fn create_test_wallet_address(seed: u8) -> WalletAddress {
+    let hex_str = format!("{:0>64}", seed);
+    WalletAddress::from_str(&hex_str).expect("Valid wallet address")
+}
+
+fn create_test_public_key() -> PublicKey {
+    "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key")
+}
+
+fn create_test_hash(seed: u8) -> Hash {
+    let mut bytes = [0u8; 32];
+    bytes[0] = seed;
+    Hash::new(bytes)
+}
+
+fn create_test_genesis_block() -> Block {
+    let utxo = GenesisConfigUtxoFunds::new_unchecked(
+        create_test_public_key(),
+        TransactionAmount::new(1000000000),
+    );
+    let genesis_cfg = GenesisConfig::new_unchecked(vec![utxo], DateTime::from_ms(1725799696000));
+    Block::_new_validated(NonValidatedBlock::new_genesis(genesis_cfg).unwrap())
+}
+
+fn create_test_transaction(seed: u8, outpoint_seed: u8) -> Transaction {
+    let timestamp = DateTime::from_ms(1000000000 + (seed as u64) * 1000);
+    let input = TransactionInput::new(
+        TransactionOutPoint::new(create_test_hash(outpoint_seed), 0),
+        create_test_public_key(),
+    );
+    let output = TransactionOutput::new(
+        create_test_wallet_address(seed),
+        TransactionAmount::new(100),
+    );
+    let tx = NonValidatedTransaction::new(vec![input], vec![output], timestamp).unwrap();
+    Transaction::_new_validated(tx, TransactionAmount::new(10))
+}
+
+fn create_test_mempool() -> DefaultMempool {
+    DefaultMempool::new(
+        Block::MAX_ENCODED_SIZE * 2,
+        TransactionAmount::new(0),
+        false,
+        0,
+        0,
+        Arc::new(MockUtxoSetReader::new()),
+    )
+}
+
+fn create_test_template_builder(
+    mempool: Arc<DefaultMempool>,
+    tx_validator: MockTransactionValidator,
+) -> DefaultBlockTemplateBuilder {
+    DefaultBlockTemplateBuilder::new(
+        Arc::new(MockBlockchain::new()),
+        mempool,
+        Arc::new(tx_validator),
+        Arc::new(MockDifficultyAdjuster::new()),
+    )
+}
+
+// ============================================================================
+// build_block_template()
+// ============================================================================
+
+#[tokio::test]
+async fn test_build_block_template_awaits_genesis() {
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain.expect_get_tip_info().returning(|| Ok(None));
+    let template_builder = DefaultBlockTemplateBuilder::new(
+        Arc::new(mock_blockchain),
+        Arc::new(create_test_mempool()),
+        Arc::new(MockTransactionValidator::new()),
+        Arc::new(MockDifficultyAdjuster::new()),
+    );
+
+    let block_tpl = template_builder
+        .build_block_template(create_test_wallet_address(9))
+        .await
+        .unwrap();
+
+    assert!(
+        block_tpl.is_none(),
+        "No template should be built before genesis"
+    );
+}
+
+#[tokio::test]
+async fn test_build_block_template_extends_tip() {
+    let tip_block = create_test_genesis_block();
+    let tip_hash = tip_block.get_hash();
+    let mut mock_blockchain = MockBlockchain::new();
+    let tip_info = (tip_hash.clone(), tip_block.get_height());
+    mock_blockchain
+        .expect_get_tip_info()
+        .returning(move || Ok(Some(tip_info.clone())));
+    mock_blockchain
+        .expect_get_canon_block()
+        .returning(move |_| Ok(Some(tip_block.clone())));
+    let mut mock_difficulty_adjuster = MockDifficultyAdjuster::new();
+    mock_difficulty_adjuster
+        .expect_get_difficulty_target()
+        .withf(|block_height| *block_height == BlockHeight::from(1))
+        .returning(|_| Ok(BlockDifficultyTarget::_new_stub()));
+    let mempool = Arc::new(create_test_mempool());
+    mempool
+        .add_transaction(create_test_transaction(1, 1))
+        .await
+        .unwrap();
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    mock_tx_validator
+        .expect_validate_transaction()
+        .returning(|tx, _, _| Ok(Transaction::_new_validated(tx, TransactionAmount::new(10))));
+    let template_builder = DefaultBlockTemplateBuilder::new(
+        Arc::new(mock_blockchain),
+        mempool,
+        Arc::new(mock_tx_validator),
+        Arc::new(mock_difficulty_adjuster),
+    );
+
+    let block_tpl = template_builder
+        .build_block_template(create_test_wallet_address(9))
+        .await
+        .unwrap()
+        .expect("Template should be built on top of the tip");
+
+    assert_eq!(block_tpl.get_height(), BlockHeight::from(1));
+    assert_eq!(block_tpl.get_prev_block_hash(), Some(tip_hash));
+}
+
+// ============================================================================
+// build_template_transactions()
+// ============================================================================
+
+#[tokio::test]
+async fn test_build_template_transactions_coinbase_first() {
+    let mempool = Arc::new(create_test_mempool());
+    mempool
+        .add_transaction(create_test_transaction(1, 1))
+        .await
+        .unwrap();
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    mock_tx_validator
+        .expect_validate_transaction()
+        .returning(|tx, _, _| Ok(Transaction::_new_validated(tx, TransactionAmount::new(10))));
+    let reward_address = create_test_wallet_address(9);
+
+    let template_builder = create_test_template_builder(mempool, mock_tx_validator);
+
+    let txs = template_builder
+        .pub_build_template_transactions(&BlockHeight::from(1), reward_address.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(txs.len(), 2);
+    assert!(txs[0].is_coinbase_tx(), "Coinbase should come first");
+    assert_eq!(
+        txs[0].get_coinbase_height(),
+        Some(&BlockHeight::from(1)),
+        "Coinbase should commit to the block height"
+    );
+    assert_eq!(txs[0].get_outputs()[0].get_recipient(), &reward_address);
+    assert!(!txs[1].is_coinbase_tx());
+
+    let subsidy = MonetaryPolicy::get_block_subsidy(&BlockHeight::from(1));
+    assert_eq!(
+        txs[0].get_outputs()[0].get_amount().as_u128(),
+        subsidy.as_u128() + 10,
+        "Coinbase should claim the block subsidy plus fees"
+    );
+}
+
+#[tokio::test]
+async fn test_build_template_transactions_skips_oversized() {
+    let mempool = Arc::new(create_test_mempool());
+    let input = TransactionInput::new(
+        TransactionOutPoint::new(create_test_hash(1), 0),
+        create_test_public_key(),
+    );
+    let outputs = (0..20_000)
+        .map(|_| TransactionOutput::new(create_test_wallet_address(1), TransactionAmount::new(100)))
+        .collect();
+    let oversized_tx =
+        NonValidatedTransaction::new(vec![input], outputs, DateTime::from_ms(1000000000)).unwrap();
+    assert!(oversized_tx.try_encode().unwrap().len() > Block::MAX_ENCODED_SIZE);
+    mempool
+        .add_transaction(Transaction::_new_validated(
+            oversized_tx,
+            TransactionAmount::new(0),
+        ))
+        .await
+        .unwrap();
+    let small_tx = create_test_transaction(2, 2);
+    let small_tx_hash = small_tx.get_hash();
+    mempool.add_transaction(small_tx).await.unwrap();
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    mock_tx_validator
+        .expect_validate_transaction()
+        .returning(|tx, _, _| Ok(Transaction::_new_validated(tx, TransactionAmount::new(10))));
+
+    let template_builder = create_test_template_builder(mempool, mock_tx_validator);
+
+    let txs = template_builder
+        .pub_build_template_transactions(&BlockHeight::from(1), create_test_wallet_address(9))
+        .await
+        .unwrap();
+
+    assert_eq!(txs.len(), 2, "Oversized transaction should be skipped");
+    assert_eq!(txs[1].get_hash(), small_tx_hash);
+}
+
+#[tokio::test]
+async fn test_build_template_transactions_skips_invalid() {
+    let mempool = Arc::new(create_test_mempool());
+    let invalid_tx = create_test_transaction(1, 1);
+    let invalid_tx_hash = invalid_tx.get_hash();
+    mempool.add_transaction(invalid_tx).await.unwrap();
+    mempool
+        .add_transaction(create_test_transaction(2, 2))
+        .await
+        .unwrap();
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    let rejected_tx_hash = invalid_tx_hash.clone();
+    mock_tx_validator
+        .expect_validate_transaction()
+        .returning(move |tx, _, _| {
+            if tx.get_hash() == rejected_tx_hash {
+                Err(AppError::TransactionValidation(
+                    TransactionValidationError::InputUtxoNotFound {
+                        tx_id: tx.get_hash().to_string(),
+                        outpoint: tx.get_inputs()[0].get_previous_output().to_string(),
+                    },
+                ))
+            } else {
+                Ok(Transaction::_new_validated(tx, TransactionAmount::new(10)))
+            }
+        });
+
+    let template_builder = create_test_template_builder(mempool, mock_tx_validator);
+
+    let txs = template_builder
+        .pub_build_template_transactions(&BlockHeight::from(1), create_test_wallet_address(9))
+        .await
+        .unwrap();
+
+    assert_eq!(txs.len(), 2, "Invalid transaction should be skipped");
+    assert!(txs.iter().all(|tx| tx.get_hash() != invalid_tx_hash));
+}
+
+#[tokio::test]
+async fn test_build_template_transactions_excludes_conflicts() {
+    let mempool = Arc::new(create_test_mempool());
+    mempool
+        .add_transaction(create_test_transaction(1, 1))
+        .await
+        .unwrap();
+    // The mempool rejects conflicting spends up front.
+    assert!(
+        mempool
+            .add_transaction(create_test_transaction(2, 1))
+            .await
+            .is_err()
+    );
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    mock_tx_validator
+        .expect_validate_transaction()
+        .times(1)
+        .returning(|tx, _, _| Ok(Transaction::_new_validated(tx, TransactionAmount::new(10))));
+
+    let template_builder = create_test_template_builder(mempool, mock_tx_validator);
+
+    let txs = template_builder
+        .pub_build_template_transactions(&BlockHeight::from(1), create_test_wallet_address(9))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        txs.len(),
+        2,
+        "Only one of the conflicting transactions should be included"
+    );
+}
+
+#[tokio::test]
+async fn test_build_template_transactions_chains_pending_outputs() {
+    let mempool = Arc::new(create_test_mempool());
+    let parent_tx = create_test_transaction(1, 1);
+    let parent_outpoint = TransactionOutPoint::new(parent_tx.get_hash(), 0);
+    let child_input = TransactionInput::new(parent_outpoint.clone(), create_test_public_key());
+    let child_output =
+        TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(50));
+    let child_tx = NonValidatedTransaction::new(
+        vec![child_input],
+        vec![child_output],
+        DateTime::from_ms(1000002000),
+    )
+    .unwrap();
+    // The child outbids its parent.
+    let child_tx = Transaction::_new_validated(child_tx, TransactionAmount::new(1000));
+    let parent_tx_hash = parent_tx.get_hash();
+    let child_tx_hash = child_tx.get_hash();
+    mempool.add_transaction(parent_tx).await.unwrap();
+    mempool.add_transaction(child_tx).await.unwrap();
+
+    let expected_child_tx_hash = child_tx_hash.clone();
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    mock_tx_validator
+        .expect_validate_transaction()
+        .withf(move |tx, _, pending_utxos| {
+            if tx.get_hash() != expected_child_tx_hash {
+                return pending_utxos.is_empty();
+            }
+            pending_utxos.len() == 1 && pending_utxos[0].get_outpoint() == &parent_outpoint
+        })
+        .times(2)
+        .returning(|tx, _, _| Ok(Transaction::_new_validated(tx, TransactionAmount::new(10))));
+
+    let template_builder = create_test_template_builder(mempool, mock_tx_validator);
+
+    let txs = template_builder
+        .pub_build_template_transactions(&BlockHeight::from(1), create_test_wallet_address(9))
+        .await
+        .unwrap();
+
+    assert_eq!(txs.len(), 3);
+    assert_eq!(
+        txs[1].get_hash(),
+        parent_tx_hash,
+        "Parent should come first"
+    );
+    assert_eq!(txs[2].get_hash(), child_tx_hash);
+}
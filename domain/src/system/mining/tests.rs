@@ -0,0 +1,105 @@
+use super::*;
+use crate::entities::block::{Block, BlockDifficultyTarget, NonValidatedBlock};
+use crate::entities::transaction::{NonValidatedTransaction, TransactionAmount, TransactionOutput};
+use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
+use crate::types::sign::PublicKey;
+use crate::types::time::DateTime;
+use crate::types::wallet::WalletAddress;
+use std::str::FromStr;
+
+fn create_test_genesis_config() -> GenesisConfig {
+    let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+
+    let utxo =
+        GenesisConfigUtxoFunds::new_unchecked(wallet_pub_key, TransactionAmount::new(1000000000));
+
+    let timestamp = DateTime::from_ms(1725799696000);
+    GenesisConfig::new_unchecked(vec![utxo], timestamp)
+}
+
+fn create_test_block_template(difficulty_target: BlockDifficultyTarget) -> BlockTemplate {
+    let prev_block = Block::_new_validated(
+        NonValidatedBlock::new_genesis(create_test_genesis_config()).unwrap(),
+    );
+    let recipient =
+        WalletAddress::from_str("54b73c091395a30874a397cbfcd54c7348175a01ee6ccf0a1133f8f8b3a19e7d")
+            .unwrap();
+    let output = TransactionOutput::new(recipient, TransactionAmount::new(50));
+    let tx =
+        NonValidatedTransaction::new(vec![], vec![output], DateTime::from_ms(1000000000)).unwrap();
+    BlockTemplate::new(&prev_block, vec![tx], difficulty_target)
+}
+
+struct NeverCancelled;
+
+#[async_trait]
+impl MiningCancellation for NeverCancelled {
+    async fn is_cancelled(&self) -> Result<bool, AppError> {
+        Ok(false)
+    }
+}
+
+struct AlwaysCancelled;
+
+#[async_trait]
+impl MiningCancellation for AlwaysCancelled {
+    async fn is_cancelled(&self) -> Result<bool, AppError> {
+        Ok(true)
+    }
+}
+
+// ============================================================================
+// mine_block()
+// ============================================================================
+
+#[tokio::test]
+async fn test_mine_block_meets_difficulty_target() {
+    let miner = DefaultBlockMiner::new();
+    // Expect roughly 256 attempts
+    let difficulty_target = BlockDifficultyTarget::new(u128::MAX >> 8);
+    let block_tpl = create_test_block_template(difficulty_target.clone());
+
+    let solved_tpl = miner
+        .mine_block(block_tpl, &NeverCancelled)
+        .await
+        .unwrap()
+        .expect("Mining should not be cancelled");
+
+    let block = NonValidatedBlock::from_template(solved_tpl).unwrap();
+    assert!(
+        difficulty_target.is_met_by(&block.get_hash()),
+        "Mined block hash should meet the difficulty target"
+    );
+}
+
+#[tokio::test]
+async fn test_mine_block_cancelled() {
+    let miner = DefaultBlockMiner::new();
+    let block_tpl = create_test_block_template(BlockDifficultyTarget::new(0));
+
+    let result = miner.mine_block(block_tpl, &AlwaysCancelled).await.unwrap();
+    assert!(
+        result.is_none(),
+        "Cancelled mining job should yield no block"
+    );
+}
+
+#[test]
+fn test_try_solve_exhausts_attempts() {
+    let mut block_tpl = create_test_block_template(BlockDifficultyTarget::new(0));
+
+    let solved = block_tpl.try_solve(10).unwrap();
+    assert!(!solved, "Impossible target should not be met");
+    assert_eq!(block_tpl.get_nonce().as_u64(), 10);
+}
+
+#[test]
+fn test_try_solve_trivial_target() {
+    let mut block_tpl = create_test_block_template(BlockDifficultyTarget::new(u128::MAX));
+
+    let solved = block_tpl.try_solve(1).unwrap();
+    assert!(solved, "Trivial target should be met on the first attempt");
+    assert_eq!(block_tpl.get_nonce().as_u64(), 0);
+}
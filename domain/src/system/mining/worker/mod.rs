@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod tests;
+
+use super::{BlockMiner, BlockTemplateBuilder, MiningCancellation};
+use crate::system::blockchain::Blockchain;
+use crate::system::node::cmd::{CommandResponderFactory, CommandSender};
+use crate::types::hash::Hash;
+use crate::types::wallet::WalletAddress;
+use async_trait::async_trait;
+use common::error::AppError;
+use common::{log_node_debug, log_node_error, log_node_info};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time;
+
+/// Runtime toggle for the background mining worker.<br />
+/// Mining is active for as long as a reward address is set.
+#[derive(Debug)]
+pub(crate) struct MiningSwitch {
+    reward_address: watch::Sender<Option<WalletAddress>>,
+    default_reward_address: Option<WalletAddress>,
+}
+
+impl MiningSwitch {
+    pub(crate) fn new(default_reward_address: Option<WalletAddress>, enabled: bool) -> Self {
+        let reward_address = enabled.then(|| default_reward_address.clone()).flatten();
+        let (reward_address, _) = watch::channel(reward_address);
+        Self {
+            reward_address,
+            default_reward_address,
+        }
+    }
+
+    /// Enables mining, paying coinbase rewards to the given address.<br />
+    /// Falls back to the configured reward address if none is provided.
+    pub(crate) fn enable(
+        &self,
+        reward_address: Option<WalletAddress>,
+    ) -> Result<WalletAddress, AppError> {
+        let reward_address = reward_address
+            .or(self.default_reward_address.clone())
+            .ok_or(AppError::precondition_failed(
+                "No mining reward address configured! Please provide one.",
+            ))?;
+        self.reward_address
+            .send_replace(Some(reward_address.clone()));
+        Ok(reward_address)
+    }
+
+    pub(crate) fn disable(&self) {
+        self.reward_address.send_replace(None);
+    }
+
+    /// Returns the active reward address, or `None` if mining is disabled.
+    pub(crate) fn get_reward_address(&self) -> Option<WalletAddress> {
+        self.reward_address.borrow().clone()
+    }
+
+    fn subscribe(&self) -> watch::Receiver<Option<WalletAddress>> {
+        self.reward_address.subscribe()
+    }
+}
+
+/// Continuously mines blocks on top of the blockchain tip.<br />
+/// Solved blocks are submitted through the node's command channel.
+#[derive(Debug)]
+pub(crate) struct BlockMiningWorker {
+    blockchain: Arc<dyn Blockchain>,
+    template_builder: Arc<dyn BlockTemplateBuilder>,
+    block_miner: Arc<dyn BlockMiner>,
+    mining_switch: Arc<MiningSwitch>,
+    cmd_tx: Arc<dyn CommandSender>,
+    cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+}
+
+impl BlockMiningWorker {
+    /// Delay before starting another mining round after an unsuccessful one.
+    const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Interval in between checks for the blockchain tip moving past a submitted block's parent.
+    const TIP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Maximum wait for a submitted block to move the blockchain tip.<br />
+    /// Blocks failing to get appended leave the tip in place, the template gets rebuilt instead.
+    const TIP_CHANGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub(crate) fn new(
+        blockchain: Arc<dyn Blockchain>,
+        template_builder: Arc<dyn BlockTemplateBuilder>,
+        block_miner: Arc<dyn BlockMiner>,
+        mining_switch: Arc<MiningSwitch>,
+        cmd_tx: Arc<dyn CommandSender>,
+        cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
+    ) -> Self {
+        Self {
+            blockchain,
+            template_builder,
+            block_miner,
+            mining_switch,
+            cmd_tx,
+            cmd_tx_res_factory,
+        }
+    }
+
+    pub(crate) async fn run(
+        self: Arc<Self>,
+        mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
+    ) {
+        let mut switch_rx = self.mining_switch.subscribe();
+
+        loop {
+            let reward_address = switch_rx.borrow_and_update().clone();
+            let Some(reward_address) = reward_address else {
+                // Idle until mining gets enabled
+                tokio::select! {
+                    _ = shutdown_rx.recv() => break,
+                    _ = switch_rx.changed() => continue,
+                }
+            };
+
+            tokio::select! {
+                // Mine next block
+                result = self.mine_next_block(reward_address) => {
+                    if let Err(err) = result {
+                        log_node_error!("Failed to mine block! | Error: {:?}", err);
+
+                        // Exit fast on shutdown signal
+                        tokio::select! {
+                            _ = shutdown_rx.recv() => break,
+                            _ = time::sleep(Self::RETRY_INTERVAL) => {},
+                        }
+                    }
+                }
+
+                // Handle shutdown signal
+                _ = shutdown_rx.recv() => {
+                    break;
+                }
+            }
+        }
+
+        log_node_info!("Block mining worker task exiting....");
+    }
+
+    /// Runs a single mining round on top of the current blockchain tip.
+    async fn mine_next_block(&self, reward_address: WalletAddress) -> Result<(), AppError> {
+        let Some(block_tpl) = self
+            .template_builder
+            .build_block_template(reward_address)
+            .await?
+        else {
+            log_node_debug!("BlockMiningWorker: Awaiting genesis block");
+            time::sleep(Self::RETRY_INTERVAL).await;
+            return Ok(());
+        };
+        let tip_hash = block_tpl
+            .get_prev_block_hash()
+            .ok_or(AppError::internal("Block template lacks a parent block!"))?;
+
+        let cancellation = WorkerMiningCancellation::new(
+            self.blockchain.clone(),
+            self.mining_switch.clone(),
+            tip_hash.clone(),
+        );
+        let Some(block_tpl) = self
+            .block_miner
+            .mine_block(block_tpl, &cancellation)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let (command, res_fut) = self
+            .cmd_tx_res_factory
+            .build_blk_cmd_handle_mine_block(block_tpl);
+        self.cmd_tx.send(command).await?;
+        let block = res_fut.await?;
+        log_node_info!(
+            "BlockMiningWorker: Mined block | Height: {} | Hash: {}",
+            block.get_height(),
+            block.get_hash()
+        );
+
+        // The tip is only moved once the block gets appended.
+        // Avoid mining a competing block on top of the same parent in the meantime.
+        let tip_changed =
+            Self::await_tip_change(&self.blockchain, &tip_hash, Self::TIP_CHANGE_TIMEOUT).await?;
+        if !tip_changed {
+            log_node_error!(
+                "BlockMiningWorker: Mined block was not appended in time, rebuilding template | Hash: {}",
+                block.get_hash()
+            );
+        }
+        Ok(())
+    }
+
+    /// Waits for the blockchain tip to move past the given block.<br />
+    /// Returns whether it did so before the timeout elapsed.
+    async fn await_tip_change(
+        blockchain: &Arc<dyn Blockchain>,
+        prev_tip_hash: &Hash,
+        timeout: Duration,
+    ) -> Result<bool, AppError> {
+        let poll = async {
+            loop {
+                let tip_info = blockchain.get_tip_info().await?;
+                if tip_info.is_none_or(|(tip_hash, _)| &tip_hash != prev_tip_hash) {
+                    return Ok(());
+                }
+                time::sleep(Self::TIP_POLL_INTERVAL).await;
+            }
+        };
+        match time::timeout(timeout, poll).await {
+            Ok(res) => res.map(|_| true),
+            Err(_) => Ok(false),
+        }
+    }
+}
+
+/// Cancels mining once the blockchain tip moves or the worker gets toggled.
+struct WorkerMiningCancellation {
+    blockchain: Arc<dyn Blockchain>,
+    mining_switch: Arc<MiningSwitch>,
+    tip_hash: Hash,
+}
+
+impl WorkerMiningCancellation {
+    fn new(
+        blockchain: Arc<dyn Blockchain>,
+        mining_switch: Arc<MiningSwitch>,
+        tip_hash: Hash,
+    ) -> Self {
+        Self {
+            blockchain,
+            mining_switch,
+            tip_hash,
+        }
+    }
+}
+
+#[async_trait]
+impl MiningCancellation for WorkerMiningCancellation {
+    async fn is_cancelled(&self) -> Result<bool, AppError> {
+        if self.mining_switch.get_reward_address().is_none() {
+            return Ok(true);
+        }
+        let tip_info = self.blockchain.get_tip_info().await?;
+        Ok(tip_info.is_none_or(|(tip_hash, _)| tip_hash != self.tip_hash))
+    }
+}
+
+/// Expose internal methods for unit testing.
+#[cfg(test)]
+impl BlockMiningWorker {
+    pub(super) async fn pub_await_tip_change(
+        blockchain: &Arc<dyn Blockchain>,
+        prev_tip_hash: &Hash,
+        timeout: Duration,
+    ) -> Result<bool, AppError> {
+        Self::await_tip_change(blockchain, prev_tip_hash, timeout).await
+    }
+}
+
+/// Expose internal methods for unit testing.
+#[cfg(test)]
+impl WorkerMiningCancellation {
+    pub(super) fn pub_new(
+        blockchain: Arc<dyn Blockchain>,
+        mining_switch: Arc<MiningSwitch>,
+        tip_hash: Hash,
+    ) -> Self {
+        Self::new(blockchain, mining_switch, tip_hash)
+    }
+}
@@ -0,0 +1,143 @@
+use super::*;
+use crate::entities::block::BlockHeight;
+use crate::system::blockchain::MockBlockchain;
+use std::str::FromStr;
+
+fn create_test_wallet_address(seed: u8) -> WalletAddress {
+    let hex_str = format!("{:0>64}", seed);
+    WalletAddress::from_str(&hex_str).expect("Valid wallet address")
+}
+
+fn create_test_hash(seed: u8) -> Hash {
+    let mut bytes = [0u8; 32];
+    bytes[0] = seed;
+    Hash::new(bytes)
+}
+
+fn create_mock_blockchain_with_tip(tip_hash: Hash) -> MockBlockchain {
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_get_tip_info()
+        .returning(move || Ok(Some((tip_hash.clone(), BlockHeight::from(1)))));
+    mock_blockchain
+}
+
+// ============================================================================
+// MiningSwitch
+// ============================================================================
+
+#[test]
+fn test_mining_switch_disabled() {
+    let switch = MiningSwitch::new(Some(create_test_wallet_address(1)), false);
+    assert!(
+        switch.get_reward_address().is_none(),
+        "Mining should start out disabled"
+    );
+}
+
+#[test]
+fn test_mining_switch_enabled_with_default_address() {
+    let reward_address = create_test_wallet_address(1);
+    let switch = MiningSwitch::new(Some(reward_address.clone()), true);
+    assert_eq!(switch.get_reward_address(), Some(reward_address));
+}
+
+#[test]
+fn test_mining_switch_enable_override_address() {
+    let switch = MiningSwitch::new(Some(create_test_wallet_address(1)), false);
+    let reward_address = create_test_wallet_address(2);
+
+    let result = switch.enable(Some(reward_address.clone()));
+    assert_eq!(result.unwrap(), reward_address);
+    assert_eq!(switch.get_reward_address(), Some(reward_address));
+}
+
+#[test]
+fn test_mining_switch_enable_without_address() {
+    let switch = MiningSwitch::new(None, false);
+
+    let result = switch.enable(None);
+    assert!(
+        matches!(result, Err(AppError::PreconditionFailed(_))),
+        "Enabling mining without a reward address should fail"
+    );
+    assert!(switch.get_reward_address().is_none());
+}
+
+#[test]
+fn test_mining_switch_disable() {
+    let switch = MiningSwitch::new(Some(create_test_wallet_address(1)), true);
+    switch.disable();
+    assert!(switch.get_reward_address().is_none());
+}
+
+// ============================================================================
+// WorkerMiningCancellation
+// ============================================================================
+
+#[tokio::test]
+async fn test_cancellation_tip_unchanged() {
+    let tip_hash = create_test_hash(1);
+    let mock_blockchain = create_mock_blockchain_with_tip(tip_hash.clone());
+    let switch = MiningSwitch::new(Some(create_test_wallet_address(1)), true);
+    let cancellation =
+        WorkerMiningCancellation::pub_new(Arc::new(mock_blockchain), Arc::new(switch), tip_hash);
+
+    assert!(!cancellation.is_cancelled().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_cancellation_tip_changed() {
+    let mock_blockchain = create_mock_blockchain_with_tip(create_test_hash(2));
+    let switch = MiningSwitch::new(Some(create_test_wallet_address(1)), true);
+    let cancellation = WorkerMiningCancellation::pub_new(
+        Arc::new(mock_blockchain),
+        Arc::new(switch),
+        create_test_hash(1),
+    );
+
+    assert!(cancellation.is_cancelled().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_cancellation_mining_disabled() {
+    let tip_hash = create_test_hash(1);
+    let mock_blockchain = create_mock_blockchain_with_tip(tip_hash.clone());
+    let switch = Arc::new(MiningSwitch::new(Some(create_test_wallet_address(1)), true));
+    let cancellation =
+        WorkerMiningCancellation::pub_new(Arc::new(mock_blockchain), switch.clone(), tip_hash);
+
+    switch.disable();
+    assert!(cancellation.is_cancelled().await.unwrap());
+}
+
+// ============================================================================
+// BlockMiningWorker::await_tip_change()
+// ============================================================================
+
+#[tokio::test]
+async fn test_await_tip_change_tip_moved() {
+    let blockchain: Arc<dyn Blockchain> =
+        Arc::new(create_mock_blockchain_with_tip(create_test_hash(2)));
+
+    let result = BlockMiningWorker::pub_await_tip_change(
+        &blockchain,
+        &create_test_hash(1),
+        Duration::from_secs(1),
+    )
+    .await;
+    assert!(result.unwrap(), "Moved tip should be detected");
+}
+
+#[tokio::test]
+async fn test_await_tip_change_times_out() {
+    let tip_hash = create_test_hash(1);
+    let blockchain: Arc<dyn Blockchain> =
+        Arc::new(create_mock_blockchain_with_tip(tip_hash.clone()));
+
+    // A submitted block that never gets appended must not stall the worker.
+    let result =
+        BlockMiningWorker::pub_await_tip_change(&blockchain, &tip_hash, Duration::from_millis(250))
+            .await;
+    assert!(!result.unwrap(), "Unchanged tip should time out");
+}
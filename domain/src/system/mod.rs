@@ -1,5 +1,8 @@
 pub(crate) mod blockchain;
+pub(crate) mod difficulty;
 pub(crate) mod mempool;
+pub mod mining;
+pub(crate) mod monetary;
 pub mod network;
 pub mod node;
 pub mod queue;
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests;
+
+use crate::entities::block::BlockHeight;
+use crate::entities::transaction::TransactionAmount;
+
+/// Consensus-level monetary policy governing the issuance of new coins.<br />
+/// New coins are minted exclusively through coinbase transactions.<br />
+/// The block subsidy starts off at an initial reward, halving at fixed block intervals.
+#[derive(Debug)]
+pub(crate) struct MonetaryPolicy;
+
+impl MonetaryPolicy {
+    /// Subsidy paid out for blocks preceding the first halving.
+    const INITIAL_BLOCK_SUBSIDY: u128 = 5_000_000_000;
+
+    /// Number of blocks in between subsidy halvings.
+    const HALVING_INTERVAL: u64 = 210_000;
+
+    /// Returns the newly minted amount a block at the given height may claim.
+    pub(crate) fn get_block_subsidy(height: &BlockHeight) -> TransactionAmount {
+        let halvings = height.as_u64() / Self::HALVING_INTERVAL;
+        let subsidy = u32::try_from(halvings)
+            .ok()
+            .and_then(|halvings| Self::INITIAL_BLOCK_SUBSIDY.checked_shr(halvings))
+            .unwrap_or(0);
+        TransactionAmount::new(subsidy)
+    }
+}
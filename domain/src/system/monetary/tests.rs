@@ -0,0 +1,41 @@
+use super::*;
+
+// ============================================================================
+// get_block_subsidy()
+// ============================================================================
+
+#[test]
+fn test_get_block_subsidy_initial() {
+    let subsidy = MonetaryPolicy::get_block_subsidy(&BlockHeight::from(1));
+    assert_eq!(subsidy.as_u128(), MonetaryPolicy::INITIAL_BLOCK_SUBSIDY);
+}
+
+#[test]
+fn test_get_block_subsidy_last_block_before_halving() {
+    let height = BlockHeight::from(MonetaryPolicy::HALVING_INTERVAL - 1);
+    let subsidy = MonetaryPolicy::get_block_subsidy(&height);
+    assert_eq!(subsidy.as_u128(), MonetaryPolicy::INITIAL_BLOCK_SUBSIDY);
+}
+
+#[test]
+fn test_get_block_subsidy_halvings() {
+    for halvings in 1..4 {
+        let height = BlockHeight::from(MonetaryPolicy::HALVING_INTERVAL * halvings);
+        let subsidy = MonetaryPolicy::get_block_subsidy(&height);
+        assert_eq!(
+            subsidy.as_u128(),
+            MonetaryPolicy::INITIAL_BLOCK_SUBSIDY >> halvings,
+            "Subsidy should halve every interval"
+        );
+    }
+}
+
+#[test]
+fn test_get_block_subsidy_exhausted() {
+    let height = BlockHeight::from(MonetaryPolicy::HALVING_INTERVAL * 64);
+    let subsidy = MonetaryPolicy::get_block_subsidy(&height);
+    assert_eq!(subsidy.as_u128(), 0, "Subsidy should eventually run out");
+
+    let subsidy = MonetaryPolicy::get_block_subsidy(&BlockHeight::from(u64::MAX));
+    assert_eq!(subsidy.as_u128(), 0);
+}
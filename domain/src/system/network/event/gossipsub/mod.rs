@@ -1,5 +1,6 @@
 use crate::encode::{TryDecode, TryEncode};
 use crate::entities::block::Block;
+use crate::entities::transaction::Transaction;
 use crate::ext::AppErrorConvertibleDomain;
 use bincode::{Decode, Encode};
 use common::error::AppError;
@@ -7,6 +8,7 @@ use common::error::AppError;
 #[derive(Debug, Clone, Encode, Decode)]
 pub enum GossipsubNetworkEvent {
     BroadcastNewBlock(Block),
+    BroadcastNewTransaction(Transaction),
 }
 
 impl TryEncode for GossipsubNetworkEvent {
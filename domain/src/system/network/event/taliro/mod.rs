@@ -1,6 +1,7 @@
 use crate::encode::{TryDecode, TryEncode};
 use crate::entities::block::BlockHeight;
 use crate::ext::AppErrorConvertibleDomain;
+use crate::types::hash::Hash;
 use crate::types::network::NetworkPeerId;
 use bincode::{Decode, Encode};
 use common::error::AppError;
@@ -21,7 +22,9 @@ pub struct TaliroNetworkEventHeader {
 pub enum TaliroNetworkData {
     GetBlockchainTip,
     GetBlockByHeight(BlockHeight),
+    GetBlockByHash(Hash),
     GetBlocksByHeightRange(RangeInclusive<BlockHeight>),
+    GetForkPoint(Vec<Hash>),
 }
 
 impl TaliroNetworkEventHeader {
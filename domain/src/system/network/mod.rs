@@ -18,6 +18,7 @@ pub trait P2PNetworkEngine: Debug {
     ) -> Result<Arc<dyn P2PNetworkHandle>, AppError>;
 }
 
+#[cfg_attr(test, mockall::automock)]
 pub trait P2PNetworkHandle: Debug + Send + Sync {
     fn publish_network_event(&self, event: NetworkEvent) -> Result<(), AppError>;
 }
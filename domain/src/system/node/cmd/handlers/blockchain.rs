@@ -1,16 +1,22 @@
 use super::super::CommandResponder;
 use super::CommandHandlerControlFlow;
-use crate::entities::block::{Block, BlockHeight, BlockTemplate, NonValidatedBlock};
+use crate::entities::block::{
+    Block, BlockDifficultyTarget, BlockHeight, BlockTemplate, NonValidatedBlock,
+};
 use crate::genesis::config::GenesisConfig;
 use crate::system::blockchain::Blockchain;
+use crate::system::difficulty::DifficultyAdjuster;
 use crate::system::mempool::Mempool;
+use crate::system::mining::{BlockTemplateBuilder, MiningSwitch};
 use crate::system::network::P2PNetworkHandle;
 use crate::system::network::event::{GossipsubNetworkEvent, NetworkEvent};
 use crate::system::utxo::UtxoSetWriter;
 use crate::system::validation::block::BlockValidator;
+use crate::system::validation::transaction::TransactionValidator;
 use crate::types::hash::Hash;
+use crate::types::wallet::WalletAddress;
 use common::error::AppError;
-use common::{log_node_debug, log_node_error};
+use common::{log_node_debug, log_node_error, log_node_info};
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
@@ -19,25 +25,38 @@ use std::sync::Arc;
 pub(crate) struct BlockchainCommandHandler {
     blockchain: Arc<dyn Blockchain>,
     block_validator: Arc<dyn BlockValidator>,
+    tx_validator: Arc<dyn TransactionValidator>,
+    difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
     utxo_set_w: Arc<dyn UtxoSetWriter>,
     mempool: Arc<dyn Mempool>,
     network: Arc<dyn P2PNetworkHandle>,
+    template_builder: Arc<dyn BlockTemplateBuilder>,
+    mining_switch: Arc<MiningSwitch>,
 }
 
 impl BlockchainCommandHandler {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         blockchain: Arc<dyn Blockchain>,
         block_validator: Arc<dyn BlockValidator>,
+        tx_validator: Arc<dyn TransactionValidator>,
+        difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
         utxo_set_w: Arc<dyn UtxoSetWriter>,
         mempool: Arc<dyn Mempool>,
         network: Arc<dyn P2PNetworkHandle>,
+        template_builder: Arc<dyn BlockTemplateBuilder>,
+        mining_switch: Arc<MiningSwitch>,
     ) -> Self {
         Self {
             blockchain,
             block_validator,
+            tx_validator,
+            difficulty_adjuster,
             utxo_set_w,
             mempool,
             network,
+            template_builder,
+            mining_switch,
         }
     }
 
@@ -81,6 +100,33 @@ impl BlockchainCommandHandler {
         Ok(CommandHandlerControlFlow::Continue)
     }
 
+    /// Handle submission of an externally mined block.
+    pub(in crate::system::node) async fn handle_submit_block(
+        &self,
+        block: NonValidatedBlock,
+        responder: Box<dyn CommandResponder<Result<Block, AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "BlockchainCommandHandler: Submitting block | Height: {} | Hash: {}",
+            block.get_height(),
+            block.get_hash()
+        );
+
+        let res = async {
+            let block = self.block_validator.validate_block(block).await?;
+            self.blockchain.add_block(block.clone()).await?;
+            Ok(block)
+        }
+        .await;
+
+        if let Err(ref err) = res {
+            log_node_debug!("Rejected submitted block! | Error: {}", err);
+        }
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
     /// Handle block append operation.
     pub(in crate::system::node) async fn handle_block_append(
         &self,
@@ -96,7 +142,7 @@ impl BlockchainCommandHandler {
             block.get_hash()
         );
 
-        let res = self.append_block_internal(block).await;
+        let res = self.connect_chain_internal(block, true).await;
 
         if let Err(ref err) = res {
             log_node_error!("Failed to append block! | Error: {}", err);
@@ -106,9 +152,252 @@ impl BlockchainCommandHandler {
         Ok(CommandHandlerControlFlow::Continue)
     }
 
-    async fn append_block_internal(&self, block: Block) -> Result<(), AppError> {
+    /// Handle switching over to a side branch outweighing the canon chain.
+    pub(in crate::system::node) async fn handle_block_reorganize(
+        &self,
+        block: Block,
+        responder: Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "BlockchainCommandHandler: Reorganizing blockchain | Height: {} | Hash: {}",
+            block.get_height(),
+            block.get_hash()
+        );
+
+        let res = self.connect_chain_internal(block, false).await;
+
+        if let Err(ref err) = res {
+            log_node_error!("Failed to reorganize blockchain! | Error: {}", err);
+        }
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Connects the block onto the canon chain, reorganizing onto its branch if need be.
+    /// `content_validated` tells whether the block contents were validated against the current tip.
+    async fn connect_chain_internal(
+        &self,
+        block: Block,
+        content_validated: bool,
+    ) -> Result<(), AppError> {
+        // Outbox events aren't processed in order.
+        // The block may already have been connected alongside a heavier branch.
+        if self.blockchain.has_canon_block(&block.get_hash()).await? {
+            return Ok(());
+        }
+
+        let tip_info = self.blockchain.get_tip_info().await?;
+        let tip_hash = tip_info.as_ref().map(|(tip_hash, _)| tip_hash);
+        if block.get_prev_block_hash().as_ref() == tip_hash {
+            if content_validated {
+                self.connect_block(&block).await?;
+            } else if let Err(err) = self.connect_branch_block(&block).await {
+                // Side branch blocks may end up building on the tip once their parent got connected.
+                if !err.is_transient() {
+                    self.blockchain.invalidate_block(&block.get_hash()).await?;
+                }
+                return Err(err);
+            }
+        } else {
+            let chain_work = self.get_chain_work(&block.get_hash()).await?;
+            let tip_chain_work = match tip_hash {
+                Some(tip_hash) => self.get_chain_work(tip_hash).await?,
+                None => 0,
+            };
+            // The canon chain may have outgrown the branch in the meantime.
+            if chain_work <= tip_chain_work {
+                return Ok(());
+            }
+            let (fork_block, branch) = self.blockchain.get_branch(&block.get_hash()).await?;
+            // Branches building on top of a block that previously failed to connect are skipped.
+            for branch_block in &branch {
+                if self
+                    .blockchain
+                    .is_invalid_block(&branch_block.get_hash())
+                    .await?
+                {
+                    log_node_debug!(
+                        "Skipping reorganization onto invalid branch! | Height: {}, Hash: {}, Invalid ancestor: {}",
+                        block.get_height(),
+                        block.get_hash(),
+                        branch_block.get_hash()
+                    );
+                    self.blockchain.invalidate_block(&block.get_hash()).await?;
+                    return Ok(());
+                }
+            }
+            self.reorganize_internal(&block, fork_block, branch).await?;
+        }
+
+        self.broadcast_block(&block);
+        Ok(())
+    }
+
+    async fn connect_branch_block(&self, branch_block: &Block) -> Result<(), AppError> {
+        // Branch block contents are validated against the state they're connected onto.
+        // Their transaction fees only become known at this point.
+        let branch_block = branch_block.clone().invalidate();
+        let fees = self
+            .block_validator
+            .validate_block_content(&branch_block)
+            .await?;
+        let branch_block = Block::_new_validated_with_fees(branch_block, fees);
+        self.blockchain.replace_block(&branch_block).await?;
+        self.connect_block(&branch_block).await
+    }
+
+    async fn reorganize_internal(
+        &self,
+        block: &Block,
+        fork_block: Block,
+        branch: Vec<Block>,
+    ) -> Result<(), AppError> {
+        let disconnected = match self.blockchain.get_tip_info().await? {
+            Some((_, tip_height)) if tip_height > fork_block.get_height() => {
+                self.blockchain
+                    .get_canon_blocks_by_height_range(fork_block.get_height().next()..=tip_height)
+                    .await?
+            }
+            _ => Vec::new(),
+        };
+
+        log_node_info!(
+            "Reorganizing blockchain | Fork height: {} | Disconnecting {} block(s) | Connecting {} block(s)",
+            fork_block.get_height(),
+            disconnected.len(),
+            branch.len()
+        );
+
+        self.rewind_chain(&fork_block, &disconnected).await?;
+        for (index, branch_block) in branch.iter().enumerate() {
+            if let Err(err) = self.connect_branch_block(branch_block).await {
+                log_node_error!(
+                    "Failed to connect branch block! Restoring previous chain... | Height: {}, Hash: {}, Error: {}",
+                    branch_block.get_height(),
+                    branch_block.get_hash(),
+                    err
+                );
+                // Storage failures may resolve themselves, consensus ones never will.
+                if !err.is_transient() {
+                    for invalid_block in &branch[index..] {
+                        if let Err(err) = self
+                            .blockchain
+                            .invalidate_block(&invalid_block.get_hash())
+                            .await
+                        {
+                            log_node_error!(
+                                "Failed to mark branch block as invalid! | Hash: {}, Error: {}",
+                                invalid_block.get_hash(),
+                                err
+                            );
+                        }
+                    }
+                }
+                self.restore_chain(&fork_block, &branch[..index], &disconnected)
+                    .await;
+                return Err(err);
+            }
+        }
+
+        // Return transactions no longer confirmed to the mempool.
+        // Those conflicting with the new chain, or priced out of the mempool, are dropped.
+        if let Some((_, tip_height)) = self.blockchain.get_tip_info().await? {
+            let block_height = tip_height.next();
+            for tx in disconnected
+                .iter()
+                .flat_map(|block| block.get_transactions())
+                .filter(|tx| !tx.is_coinbase_tx())
+            {
+                let tx_hash = tx.get_hash();
+                let res = async {
+                    // Disconnected transactions may spend the outputs of earlier re-added ones.
+                    let outpoints = tx
+                        .get_inputs()
+                        .iter()
+                        .map(|txin| txin.get_previous_output().clone())
+                        .collect::<Vec<_>>();
+                    let pending_utxos = self
+                        .mempool
+                        .get_pending_utxos(&outpoints, &block_height)
+                        .await;
+                    let tx = self
+                        .tx_validator
+                        .validate_transaction(tx.clone().invalidate(), &block_height, pending_utxos)
+                        .await?;
+                    self.mempool.add_transaction(tx).await
+                }
+                .await;
+                if let Err(err) = res {
+                    log_node_debug!(
+                        "Dropping disconnected transaction! | Hash: {}, Error: {}",
+                        tx_hash,
+                        err
+                    );
+                }
+            }
+        }
+
+        log_node_info!(
+            "Blockchain reorganized | Tip height: {} | Tip hash: {}",
+            block.get_height(),
+            block.get_hash()
+        );
+        Ok(())
+    }
+
+    /// Rewinds the canon chain down to the given block.<br />
+    /// The blocks above it (ascending height) are reverted from the UTXO set.
+    async fn rewind_chain(&self, block: &Block, connected: &[Block]) -> Result<(), AppError> {
+        for connected_block in connected.iter().rev() {
+            self.utxo_set_w
+                .revert_block(connected_block)
+                .map_err(|err| {
+                    AppError::internal(format!(
+                        "Failed to revert block from UTXO set! | Hash: {} | Height: {} | Error: {}",
+                        connected_block.get_hash(),
+                        connected_block.get_height(),
+                        err,
+                    ))
+                })?;
+        }
+        self.blockchain.rewind_tip(block).await
+    }
+
+    /// Restores the canon chain disconnected during a failed reorganization.<br />
+    /// Failures are logged rather than returned, so that as much of it as possible gets restored.
+    async fn restore_chain(&self, fork_block: &Block, connected: &[Block], disconnected: &[Block]) {
+        if let Err(err) = self.rewind_chain(fork_block, connected).await {
+            log_node_error!(
+                "Failed to rewind partially connected branch! | Fork hash: {}, Error: {}",
+                fork_block.get_hash(),
+                err
+            );
+        }
+        for block in disconnected {
+            if let Err(err) = self.connect_block(block).await {
+                log_node_error!(
+                    "Failed to reconnect block! Manual intervention may be required. | Height: {}, Hash: {}, Error: {}",
+                    block.get_height(),
+                    block.get_hash(),
+                    err
+                );
+                // Subsequent blocks can't be connected on top of a missing parent.
+                break;
+            }
+        }
+    }
+
+    async fn get_chain_work(&self, hash: &Hash) -> Result<u128, AppError> {
+        self.blockchain
+            .get_chain_work(hash)
+            .await?
+            .ok_or_else(|| AppError::internal(format!("Missing chain work for block ({})!", hash)))
+    }
+
+    async fn connect_block(&self, block: &Block) -> Result<(), AppError> {
         // Apply to UTXO set
-        self.utxo_set_w.apply_block(&block).map_err(|err| {
+        self.utxo_set_w.apply_block(block).map_err(|err| {
             AppError::internal(format!(
                 "Failed to apply block to UTXO set! | Hash: {} | Height: {} | Error: {}",
                 block.get_hash(),
@@ -118,7 +407,7 @@ impl BlockchainCommandHandler {
         })?;
 
         // Apply to Mempool
-        self.mempool.apply_block(&block).await.map_err(|err| {
+        self.mempool.apply_block(block).await.map_err(|err| {
             log_node_error!(
                 "UTXO set updated but mempool update failed! Manual intervention may be required."
             );
@@ -131,7 +420,7 @@ impl BlockchainCommandHandler {
         })?;
 
         // Update Blockchain tip
-        self.blockchain.set_tip(&block).await.map_err(|err| {
+        self.blockchain.set_tip(block).await.map_err(|err| {
             log_node_error!(
                 "UTXO and mempool updated but blockchain tip update failed! System state inconsistent."
             );
@@ -144,7 +433,10 @@ impl BlockchainCommandHandler {
                 ),
             )
         })?;
+        Ok(())
+    }
 
+    fn broadcast_block(&self, block: &Block) {
         // Broadcast to Network
         let network_event =
             NetworkEvent::Gossipsub(GossipsubNetworkEvent::BroadcastNewBlock(block.clone()));
@@ -158,11 +450,10 @@ impl BlockchainCommandHandler {
             );
             // Don't fail the entire operation if broadcast fails...
         }
-
-        Ok(())
     }
 
     /// Get blockchain tip information.
+    #[allow(clippy::type_complexity)]
     pub(in crate::system::node) async fn handle_get_tip_info(
         &self,
         responder: Box<dyn CommandResponder<Result<Option<(Hash, BlockHeight)>, AppError>> + Send>,
@@ -174,6 +465,130 @@ impl BlockchainCommandHandler {
         Ok(CommandHandlerControlFlow::Continue)
     }
 
+    /// Get blockchain tip information, alongside its cumulative chain work.
+    #[allow(clippy::type_complexity)]
+    pub(in crate::system::node) async fn handle_get_tip_chain_info(
+        &self,
+        responder: Box<
+            dyn CommandResponder<Result<Option<(Hash, BlockHeight, u128)>, AppError>> + Send,
+        >,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!("BlockchainCommandHandler: Getting tip chain info");
+
+        let res = async {
+            let Some((tip_hash, tip_height)) = self.blockchain.get_tip_info().await? else {
+                return Ok(None);
+            };
+            let chain_work = self.get_chain_work(&tip_hash).await?;
+            Ok(Some((tip_hash, tip_height, chain_work)))
+        }
+        .await;
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Locate the fork point of a peer's chain, given its block locator.
+    #[allow(clippy::type_complexity)]
+    pub(in crate::system::node) async fn handle_get_fork_point(
+        &self,
+        locator: Vec<Hash>,
+        responder: Box<
+            dyn CommandResponder<Result<Option<(BlockHeight, BlockHeight)>, AppError>> + Send,
+        >,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "BlockchainCommandHandler: Locating fork point out of {} locator hash(es)",
+            locator.len()
+        );
+
+        let res = async {
+            let Some((_, tip_height)) = self.blockchain.get_tip_info().await? else {
+                return Ok(None);
+            };
+            let fork_point = self.blockchain.find_fork_point(&locator).await?;
+            Ok(fork_point.map(|fork_height| (fork_height, tip_height)))
+        }
+        .await;
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Get the expected difficulty target for a block at the given height.
+    pub(in crate::system::node) async fn handle_get_difficulty_target(
+        &self,
+        block_height: BlockHeight,
+        responder: Box<dyn CommandResponder<Result<BlockDifficultyTarget, AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "BlockchainCommandHandler: Getting difficulty target for height: {}",
+            block_height
+        );
+
+        let res = self
+            .difficulty_adjuster
+            .get_difficulty_target(&block_height)
+            .await;
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Assemble a block template out of the mempool, on top of the blockchain tip.
+    pub(in crate::system::node) async fn handle_get_block_template(
+        &self,
+        reward_address: WalletAddress,
+        responder: Box<dyn CommandResponder<Result<BlockTemplate, AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "BlockchainCommandHandler: Building block template | Reward address: {}",
+            reward_address
+        );
+
+        let res = self
+            .template_builder
+            .build_block_template(reward_address)
+            .await
+            .and_then(|block_tpl| {
+                block_tpl.ok_or(AppError::precondition_failed(
+                    "Blockchain not bootstrapped yet! Please initiate genesis.",
+                ))
+            });
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Start the background mining worker.
+    pub(in crate::system::node) async fn handle_start_mining(
+        &self,
+        reward_address: Option<WalletAddress>,
+        responder: Box<dyn CommandResponder<Result<WalletAddress, AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!("BlockchainCommandHandler: Starting mining");
+
+        let res = self.mining_switch.enable(reward_address);
+        if let Ok(ref reward_address) = res {
+            log_node_info!("Mining enabled | Reward address: {}", reward_address);
+        }
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Stop the background mining worker.
+    pub(in crate::system::node) async fn handle_stop_mining(
+        &self,
+        responder: Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!("BlockchainCommandHandler: Stopping mining");
+
+        self.mining_switch.disable();
+        log_node_info!("Mining disabled");
+
+        responder.respond(Ok(()));
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
     /// Get block by hash.
     pub(in crate::system::node) async fn handle_get_block(
         &self,
@@ -1,10 +1,15 @@
 use super::super::CommandResponder;
 use super::CommandHandlerControlFlow;
+use crate::entities::block::BlockHeight;
 use crate::entities::transaction::{NonValidatedTransaction, Transaction};
+use crate::system::blockchain::Blockchain;
 use crate::system::mempool::Mempool;
+use crate::system::network::P2PNetworkHandle;
+use crate::system::network::event::{GossipsubNetworkEvent, NetworkEvent};
 use crate::system::validation::transaction::TransactionValidator;
 use crate::types::hash::Hash;
-use common::error::AppError;
+use crate::types::network::NetworkPeerId;
+use common::error::{AppError, TransactionValidationError};
 use common::params::PaginationParams;
 use common::{log_node_debug, log_node_error};
 use std::sync::Arc;
@@ -12,18 +17,24 @@ use std::sync::Arc;
 /// Handles mempool-related commands.
 #[derive(Debug, Clone)]
 pub(crate) struct MempoolCommandHandler {
+    blockchain: Arc<dyn Blockchain>,
     mempool: Arc<dyn Mempool>,
     tx_validator: Arc<dyn TransactionValidator>,
+    network: Arc<dyn P2PNetworkHandle>,
 }
 
 impl MempoolCommandHandler {
     pub(crate) fn new(
+        blockchain: Arc<dyn Blockchain>,
         mempool: Arc<dyn Mempool>,
         tx_validator: Arc<dyn TransactionValidator>,
+        network: Arc<dyn P2PNetworkHandle>,
     ) -> Self {
         Self {
+            blockchain,
             mempool,
             tx_validator,
+            network,
         }
     }
 
@@ -38,22 +49,132 @@ impl MempoolCommandHandler {
             tx.get_hash()
         );
 
-        let res = async {
-            let tx = self.tx_validator.validate_transaction(tx).await?;
-            self.mempool.add_transaction(tx.clone()).await?;
-            Ok(tx)
+        let res = self.admit_transaction(tx).await;
+
+        match res {
+            Ok(ref tx) => self.broadcast_transaction(tx),
+            Err(ref err) => log_node_error!("Failed to place transaction: {}", err),
+        }
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Handle receiving a mempool transaction from a peer.<br />
+    /// Received transactions are revalidated before being added to the mempool.<br />
+    /// They're not broadcast any further, gossipsub relaying them across the mesh already.
+    pub(in crate::system::node) async fn handle_receive_transaction(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        tx: NonValidatedTransaction,
+        responder: Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        let tx_hash = tx.get_hash();
+        log_node_debug!(
+            "MempoolCommandHandler: Received transaction from peer: {} | Hash: {}",
+            origin_peer_id,
+            tx_hash
+        );
+
+        // Peers may declare any id, and a forged one could shadow a known transaction.
+        if let Err(err) = tx.validate_hash() {
+            log_node_debug!(
+                "Rejected transaction from peer: {} | Hash: {}, Error: {}",
+                origin_peer_id,
+                tx_hash,
+                err
+            );
+            responder.respond(Err(err));
+            return Ok(CommandHandlerControlFlow::Continue);
         }
-        .await;
+
+        // Skip if we already have this transaction.
+        if self.mempool.get_transaction(&tx_hash).await.is_some() {
+            log_node_debug!("Skipping already known transaction: {}", tx_hash);
+            responder.respond(Ok(()));
+            return Ok(CommandHandlerControlFlow::Continue);
+        }
+
+        let res = self.admit_transaction(tx).await.map(|_| ());
 
         if let Err(ref err) = res {
-            log_node_error!("Failed to place transaction: {}", err);
+            // Peers may relay transactions we've since seen confirmed or conflicting.
+            log_node_debug!(
+                "Rejected transaction from peer: {} | Hash: {}, Error: {}",
+                origin_peer_id,
+                tx_hash,
+                err
+            );
         }
 
         responder.respond(res);
         Ok(CommandHandlerControlFlow::Continue)
     }
 
+    /// Validates a transaction and adds it to the mempool.
+    async fn admit_transaction(
+        &self,
+        tx: NonValidatedTransaction,
+    ) -> Result<Transaction, AppError> {
+        // Mempool transactions are validated against the next block to be mined.
+        let block_height = match self.blockchain.get_tip_info().await? {
+            Some((_, tip_height)) => tip_height.next(),
+            None => BlockHeight::genesis(),
+        };
+        // Inputs may spend the outputs of transactions still pending in the mempool.
+        let outpoints = tx
+            .get_inputs()
+            .iter()
+            .map(|txin| txin.get_previous_output().clone())
+            .collect::<Vec<_>>();
+        let pending_utxos = self
+            .mempool
+            .get_pending_utxos(&outpoints, &block_height)
+            .await;
+        let tx = self
+            .tx_validator
+            .validate_transaction(tx, &block_height, pending_utxos)
+            .await?;
+        self.validate_min_fee(&tx).await?;
+        self.mempool.add_transaction(tx.clone()).await?;
+        Ok(tx)
+    }
+
+    fn broadcast_transaction(&self, tx: &Transaction) {
+        // Broadcast to Network
+        let network_event =
+            NetworkEvent::Gossipsub(GossipsubNetworkEvent::BroadcastNewTransaction(tx.clone()));
+
+        if let Err(err) = self.network.publish_network_event(network_event) {
+            log_node_error!(
+                "Transaction placed successfully but network broadcast failed: {} | Hash: {}",
+                err,
+                tx.get_hash()
+            );
+            // Don't fail the entire operation if broadcast fails...
+        }
+    }
+
+    /// Transactions must pay at least the mempool's minimum fee rate for their encoded size.
+    async fn validate_min_fee(&self, tx: &Transaction) -> Result<(), AppError> {
+        let size = tx.compute_encoded_size()? as u128;
+        let min_fee_rate = self.mempool.get_min_fee_rate().await;
+        let min_fee = min_fee_rate.as_u128().saturating_mul(size);
+        let fee = tx.get_fee().as_u128();
+        if fee < min_fee {
+            return Err(AppError::TransactionValidation(
+                TransactionValidationError::InsufficientFee {
+                    tx_id: tx.get_hash().to_string(),
+                    fee,
+                    min_fee,
+                },
+            ));
+        }
+        Ok(())
+    }
+
     /// Get paginated transactions from mempool.
+    #[allow(clippy::type_complexity)]
     pub(in crate::system::node) async fn handle_get_paginated_transactions(
         &self,
         pagination: PaginationParams,
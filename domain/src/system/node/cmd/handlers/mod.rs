@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod tests;
+
 mod blockchain;
 mod mempool;
 mod network;
@@ -75,14 +78,52 @@ impl CommandDispatcher {
                     .handle_mine_block(block_tpl, responder)
                     .await
             }
+            BlockchainCommand::SubmitBlock(block, responder) => {
+                self.blockchain_handler
+                    .handle_submit_block(block, responder)
+                    .await
+            }
             BlockchainCommand::HandleBlockAppend(block, responder) => {
                 self.blockchain_handler
                     .handle_block_append(block, responder)
                     .await
             }
+            BlockchainCommand::HandleBlockReorganize(block, responder) => {
+                self.blockchain_handler
+                    .handle_block_reorganize(block, responder)
+                    .await
+            }
             BlockchainCommand::GetTipInfo(responder) => {
                 self.blockchain_handler.handle_get_tip_info(responder).await
             }
+            BlockchainCommand::GetTipChainInfo(responder) => {
+                self.blockchain_handler
+                    .handle_get_tip_chain_info(responder)
+                    .await
+            }
+            BlockchainCommand::GetForkPoint(locator, responder) => {
+                self.blockchain_handler
+                    .handle_get_fork_point(locator, responder)
+                    .await
+            }
+            BlockchainCommand::GetDifficultyTarget(block_height, responder) => {
+                self.blockchain_handler
+                    .handle_get_difficulty_target(block_height, responder)
+                    .await
+            }
+            BlockchainCommand::GetBlockTemplate(reward_address, responder) => {
+                self.blockchain_handler
+                    .handle_get_block_template(reward_address, responder)
+                    .await
+            }
+            BlockchainCommand::StartMining(reward_address, responder) => {
+                self.blockchain_handler
+                    .handle_start_mining(reward_address, responder)
+                    .await
+            }
+            BlockchainCommand::StopMining(responder) => {
+                self.blockchain_handler.handle_stop_mining(responder).await
+            }
             BlockchainCommand::GetBlock(block_hash, responder) => {
                 self.blockchain_handler
                     .handle_get_block(block_hash, responder)
@@ -132,6 +173,16 @@ impl CommandDispatcher {
                     .await
             }
             UtxoCommand::GetUtxos(responder) => self.utxo_handler.handle_get_utxos(responder).await,
+            UtxoCommand::GetUtxosByAddress(address, responder) => {
+                self.utxo_handler
+                    .handle_get_utxos_by_address(address, responder)
+                    .await
+            }
+            UtxoCommand::GetBalanceByAddress(address, responder) => {
+                self.utxo_handler
+                    .handle_get_balance_by_address(address, responder)
+                    .await
+            }
         }
     }
 
@@ -161,11 +212,21 @@ impl CommandDispatcher {
                     .handle_receive_blockchain_tip_info(origin_peer_id, block_info, responder)
                     .await
             }
+            P2PCommand::HandleReceiveForkPoint(origin_peer_id, fork_point, responder) => {
+                self.network_handler
+                    .handle_receive_fork_point(origin_peer_id, fork_point, responder)
+                    .await
+            }
             P2PCommand::HandleReceiveBlocks(origin_peer_id, blocks, responder) => {
                 self.network_handler
                     .handle_receive_blocks(origin_peer_id, blocks, responder)
                     .await
             }
+            P2PCommand::HandleReceiveTransaction(origin_peer_id, tx, responder) => {
+                self.mempool_handler
+                    .handle_receive_transaction(origin_peer_id, tx, responder)
+                    .await
+            }
             P2PCommand::ProxyForwardNetworkEvent(event, responder) => {
                 self.network_handler
                     .handle_forward_network_event(event, responder)
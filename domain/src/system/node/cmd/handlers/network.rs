@@ -3,12 +3,15 @@ use super::CommandHandlerControlFlow;
 use crate::entities::block::{BlockHeight, NonValidatedBlock};
 use crate::system::blockchain::Blockchain;
 use crate::system::network::P2PNetworkHandle;
-use crate::system::network::event::{AddPeerResponse, NetworkEvent};
+use crate::system::network::event::{
+    AddPeerResponse, NetworkEvent, TaliroNetworkData, TaliroNetworkEvent,
+};
 use crate::system::queue::BlockSyncQueue;
 use crate::types::hash::Hash;
 use crate::types::network::{NetworkAddress, NetworkIdentityKeypair, NetworkPeerId};
 use common::error::{AppError, NetworkError};
 use common::{log_node_debug, log_node_error};
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 /// Handles network/P2P-related commands.
@@ -32,11 +35,11 @@ impl NetworkCommandHandler {
         }
     }
 
-    /// Handle receiving blockchain tip info from a peer.
+    /// Handle receiving blockchain tip info, alongside its chain work, from a peer.
     pub(in crate::system::node) async fn handle_receive_blockchain_tip_info(
         &self,
         origin_peer_id: NetworkPeerId,
-        block_info: Option<(Hash, BlockHeight)>,
+        block_info: Option<(Hash, BlockHeight, u128)>,
         responder: Box<dyn CommandResponder<Result<(), AppError>> + Send>,
     ) -> Result<CommandHandlerControlFlow, AppError> {
         log_node_debug!(
@@ -59,24 +62,105 @@ impl NetworkCommandHandler {
     async fn handle_receive_blockchain_tip_info_internal(
         &self,
         origin_peer_id: NetworkPeerId,
-        block_info: Option<(Hash, BlockHeight)>,
+        block_info: Option<(Hash, BlockHeight, u128)>,
     ) -> Result<(), AppError> {
-        let Some(tip_info) = block_info else {
+        let Some((remote_tip_hash, remote_tip_height, remote_chain_work)) = block_info else {
             return Ok(());
         };
 
-        let Some(unknown_heights) = self.blockchain.get_unknown_block_heights(tip_info).await?
-        else {
+        if self.blockchain.has_known_block(&remote_tip_hash).await? {
+            return Ok(());
+        }
+
+        let Some((local_tip_hash, _)) = self.blockchain.get_tip_info().await? else {
+            return self
+                .request_blocks(BlockHeight::genesis()..=remote_tip_height, origin_peer_id)
+                .await;
+        };
+
+        // Only chains outweighing our own are worth syncing, regardless of their height.
+        let local_chain_work = self
+            .blockchain
+            .get_chain_work(&local_tip_hash)
+            .await?
+            .ok_or_else(|| {
+                AppError::internal(format!(
+                    "Missing chain work for block ({})!",
+                    local_tip_hash
+                ))
+            })?;
+        if remote_chain_work <= local_chain_work {
+            return Ok(());
+        }
+
+        // The remote chain may have forked off anywhere below our tip.
+        // Have the peer locate the fork point so that only its branch gets requested.
+        let locator = self.blockchain.get_block_locator().await?;
+
+        log_node_debug!(
+            "Requesting fork point from peer: {} | Locator length: {}",
+            origin_peer_id,
+            locator.len()
+        );
+
+        let event_data = TaliroNetworkData::GetForkPoint(locator);
+        let event = NetworkEvent::Taliro(TaliroNetworkEvent::new(origin_peer_id, event_data));
+        self.network.publish_network_event(event)
+    }
+
+    /// Handle receiving the fork point of a peer's chain, alongside its tip height.
+    pub(in crate::system::node) async fn handle_receive_fork_point(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        fork_point: Option<(BlockHeight, BlockHeight)>,
+        responder: Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "NetworkCommandHandler: Received fork point from peer: {}",
+            origin_peer_id
+        );
+
+        let res = self
+            .handle_receive_fork_point_internal(origin_peer_id, fork_point)
+            .await;
+
+        if let Err(ref err) = res {
+            log_node_error!("Failed to handle fork point: {}", err);
+        }
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    async fn handle_receive_fork_point_internal(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        fork_point: Option<(BlockHeight, BlockHeight)>,
+    ) -> Result<(), AppError> {
+        let Some((fork_height, remote_tip_height)) = fork_point else {
             return Ok(());
         };
 
+        if remote_tip_height <= fork_height {
+            return Ok(());
+        }
+
+        self.request_blocks(fork_height.next()..=remote_tip_height, origin_peer_id)
+            .await
+    }
+
+    async fn request_blocks(
+        &self,
+        heights: RangeInclusive<BlockHeight>,
+        origin_peer_id: NetworkPeerId,
+    ) -> Result<(), AppError> {
         log_node_debug!(
             "Requesting unknown blocks in range: {:?} from peer: {}",
-            unknown_heights,
+            heights,
             origin_peer_id
         );
 
-        for height in unknown_heights.start().as_u64()..=unknown_heights.end().as_u64() {
+        for height in heights.start().as_u64()..=heights.end().as_u64() {
             let height = height.into();
 
             if self.block_sync_queue.is_in_progress(&height).await {
@@ -130,7 +214,7 @@ impl NetworkCommandHandler {
             let block_hash = block.get_hash();
 
             // Skip if we already have this block.
-            if self.blockchain.has_canon_block(&block_hash).await? {
+            if self.blockchain.has_known_block(&block_hash).await? {
                 log_node_debug!("Skipping already known block: {}", block_hash);
                 continue;
             }
@@ -143,6 +227,7 @@ impl NetworkCommandHandler {
     }
 
     /// Get self network information.
+    #[allow(clippy::type_complexity)]
     pub(in crate::system::node) async fn handle_get_self_info(
         &self,
         responder: Box<
@@ -0,0 +1,464 @@
+use super::*;
+use crate::encode::TryEncode;
+use crate::entities::block::{
+    Block, BlockDifficultyTarget, BlockHeight, BlockTemplate, NonValidatedBlock,
+};
+use crate::entities::transaction::{
+    NonValidatedTransaction, Transaction, TransactionAmount, TransactionInput, TransactionOutPoint,
+    TransactionOutput,
+};
+use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
+use crate::system::blockchain::MockBlockchain;
+use crate::system::difficulty::MockDifficultyAdjuster;
+use crate::system::mempool::{DefaultMempool, Mempool};
+use crate::system::mining::{DefaultBlockTemplateBuilder, MiningSwitch};
+use crate::system::network::MockP2PNetworkHandle;
+use crate::system::node::cmd::CommandResponder;
+use crate::system::utxo::{MockUtxoSetReader, MockUtxoSetWriter};
+use crate::system::validation::block::MockBlockValidator;
+use crate::system::validation::transaction::MockTransactionValidator;
+use crate::types::hash::Hash;
+use crate::types::network::NetworkPeerId;
+use crate::types::sign::PublicKey;
+use crate::types::time::DateTime;
+use crate::types::wallet::WalletAddress;
+use common::error::{BlockValidationError, TransactionValidationError};
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+fn create_test_genesis_config() -> GenesisConfig {
+    let wallet_pub_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+
+    let utxo =
+        GenesisConfigUtxoFunds::new_unchecked(wallet_pub_key, TransactionAmount::new(1000000000));
+
+    // 2025-09-08T12:34:56Z as Unix timestamp in milliseconds
+    let timestamp = DateTime::from_ms(1725799696000);
+
+    GenesisConfig::new_unchecked(vec![utxo], timestamp)
+}
+
+#[derive(Debug)]
+struct TestResponder<T>(oneshot::Sender<T>);
+
+impl<T: Send + std::fmt::Debug> CommandResponder<T> for TestResponder<T> {
+    fn respond(self: Box<Self>, value: T) {
+        let _ = self.0.send(value);
+    }
+}
+
+fn create_test_genesis_block() -> Block {
+    Block::_new_validated(NonValidatedBlock::new_genesis(create_test_genesis_config()).unwrap())
+}
+
+fn create_test_child_block(parent: &Block, difficulty_target: BlockDifficultyTarget) -> Block {
+    let txs = parent
+        .get_transactions()
+        .iter()
+        .map(|tx| tx.clone().invalidate())
+        .collect();
+    let template = BlockTemplate::new(parent, txs, difficulty_target);
+    Block::_new_validated(NonValidatedBlock::from_template(template).unwrap())
+}
+
+fn create_test_handler(
+    blockchain: MockBlockchain,
+    block_validator: MockBlockValidator,
+    utxo_set_w: MockUtxoSetWriter,
+) -> BlockchainCommandHandler {
+    let blockchain = Arc::new(blockchain);
+    let mempool = Arc::new(DefaultMempool::new(
+        Block::MAX_ENCODED_SIZE * 2,
+        TransactionAmount::new(0),
+        false,
+        0,
+        0,
+        Arc::new(MockUtxoSetReader::new()),
+    ));
+    let template_builder = DefaultBlockTemplateBuilder::new(
+        blockchain.clone(),
+        mempool.clone(),
+        Arc::new(MockTransactionValidator::new()),
+        Arc::new(MockDifficultyAdjuster::new()),
+    );
+    BlockchainCommandHandler::new(
+        blockchain,
+        Arc::new(block_validator),
+        Arc::new(MockTransactionValidator::new()),
+        Arc::new(MockDifficultyAdjuster::new()),
+        Arc::new(utxo_set_w),
+        mempool,
+        Arc::new(MockP2PNetworkHandle::new()),
+        Arc::new(template_builder),
+        Arc::new(MiningSwitch::new(None, false)),
+    )
+}
+
+async fn reorganize(handler: &BlockchainCommandHandler, block: Block) -> Result<(), AppError> {
+    let (tx, rx) = oneshot::channel();
+    handler
+        .handle_block_reorganize(block, Box::new(TestResponder(tx)))
+        .await
+        .unwrap();
+    rx.await.unwrap()
+}
+
+// ============================================================================
+// handle_block_reorganize()
+// ============================================================================
+
+#[tokio::test]
+async fn test_reorganize_onto_tip_validates_block_content() {
+    let genesis_block = create_test_genesis_block();
+    let block = create_test_child_block(&genesis_block, BlockDifficultyTarget::initial());
+    let block_hash = block.get_hash();
+    let invalidated = Arc::new(Mutex::new(Vec::<Hash>::new()));
+
+    // The side branch block's parent got connected before the block itself
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_has_canon_block()
+        .returning(|_| Ok(false));
+    mock_blockchain.expect_get_tip_info().returning({
+        let tip_hash = genesis_block.get_hash();
+        move || Ok(Some((tip_hash.clone(), BlockHeight::from(0))))
+    });
+    mock_blockchain.expect_set_tip().times(0);
+    mock_blockchain.expect_replace_block().times(0);
+    mock_blockchain.expect_invalidate_block().returning({
+        let invalidated = invalidated.clone();
+        move |hash| {
+            invalidated.lock().unwrap().push(hash.clone());
+            Ok(())
+        }
+    });
+
+    // The block spends an output already spent on the canon chain
+    let mut mock_block_validator = MockBlockValidator::new();
+    mock_block_validator
+        .expect_validate_block_content()
+        .times(1)
+        .returning(|block| {
+            Err(AppError::TransactionValidation(
+                TransactionValidationError::InputUtxoNotFound {
+                    tx_id: block.get_transactions()[0].get_hash().to_string(),
+                    outpoint: String::new(),
+                },
+            ))
+        });
+
+    let mut mock_utxo_set_w = MockUtxoSetWriter::new();
+    mock_utxo_set_w.expect_apply_block().times(0);
+
+    let handler = create_test_handler(mock_blockchain, mock_block_validator, mock_utxo_set_w);
+
+    let result = reorganize(&handler, block).await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::TransactionValidation(
+                TransactionValidationError::InputUtxoNotFound { .. }
+            ))
+        ),
+        "Side branch blocks with invalid spends shouldn't be connected onto the tip"
+    );
+    assert_eq!(
+        *invalidated.lock().unwrap(),
+        vec![block_hash],
+        "Side branch block should be marked as invalid"
+    );
+}
+
+#[tokio::test]
+async fn test_reorganize_failed_branch_restores_previous_chain() {
+    let genesis_block = create_test_genesis_block();
+    let canon_block = create_test_child_block(&genesis_block, BlockDifficultyTarget::initial());
+    let branch_block1 =
+        create_test_child_block(&genesis_block, BlockDifficultyTarget::new(u128::MAX >> 17));
+    let branch_block2 = create_test_child_block(&branch_block1, BlockDifficultyTarget::initial());
+    let branch_hash = branch_block2.get_hash();
+
+    let tip = Arc::new(Mutex::new((canon_block.get_hash(), BlockHeight::from(1))));
+    let invalidated = Arc::new(Mutex::new(Vec::<Hash>::new()));
+    let applied = Arc::new(Mutex::new(Vec::<Hash>::new()));
+    let reverted = Arc::new(Mutex::new(Vec::<Hash>::new()));
+    let replaced = Arc::new(Mutex::new(Vec::<Block>::new()));
+
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_has_canon_block()
+        .returning(|_| Ok(false));
+    mock_blockchain.expect_get_tip_info().returning({
+        let tip = tip.clone();
+        move || Ok(Some(tip.lock().unwrap().clone()))
+    });
+    mock_blockchain.expect_get_chain_work().returning({
+        let branch_hash = branch_hash.clone();
+        move |hash| Ok(Some(if *hash == branch_hash { 3 } else { 2 }))
+    });
+    mock_blockchain.expect_get_branch().returning({
+        let fork_block = genesis_block.clone();
+        let branch = vec![branch_block1.clone(), branch_block2.clone()];
+        move |_| Ok((fork_block.clone(), branch.clone()))
+    });
+    mock_blockchain
+        .expect_is_invalid_block()
+        .returning(|_| Ok(false));
+    mock_blockchain
+        .expect_get_canon_blocks_by_height_range()
+        .returning({
+            let canon_block = canon_block.clone();
+            move |_| Ok(vec![canon_block.clone()])
+        });
+    mock_blockchain.expect_rewind_tip().returning({
+        let tip = tip.clone();
+        move |block| {
+            *tip.lock().unwrap() = (block.get_hash(), block.get_height());
+            Ok(())
+        }
+    });
+    mock_blockchain.expect_set_tip().returning({
+        let tip = tip.clone();
+        move |block| {
+            *tip.lock().unwrap() = (block.get_hash(), block.get_height());
+            Ok(())
+        }
+    });
+    mock_blockchain.expect_invalidate_block().returning({
+        let invalidated = invalidated.clone();
+        move |hash| {
+            invalidated.lock().unwrap().push(hash.clone());
+            Ok(())
+        }
+    });
+    mock_blockchain.expect_replace_block().returning({
+        let replaced = replaced.clone();
+        move |block| {
+            replaced.lock().unwrap().push(block.clone());
+            Ok(())
+        }
+    });
+
+    // The second branch block breaks consensus rules
+    let mut mock_block_validator = MockBlockValidator::new();
+    mock_block_validator
+        .expect_validate_block_content()
+        .returning({
+            let branch_hash = branch_hash.clone();
+            move |block| {
+                if block.get_hash() == branch_hash {
+                    Err(AppError::BlockValidation(
+                        BlockValidationError::DuplicateTransactions,
+                    ))
+                } else {
+                    Ok(vec![
+                        TransactionAmount::new(7);
+                        block.get_transactions().len()
+                    ])
+                }
+            }
+        });
+
+    let mut mock_utxo_set_w = MockUtxoSetWriter::new();
+    mock_utxo_set_w.expect_apply_block().returning({
+        let applied = applied.clone();
+        move |block| {
+            applied.lock().unwrap().push(block.get_hash());
+            Ok(())
+        }
+    });
+    mock_utxo_set_w.expect_revert_block().returning({
+        let reverted = reverted.clone();
+        move |block| {
+            reverted.lock().unwrap().push(block.get_hash());
+            Ok(())
+        }
+    });
+
+    let handler = create_test_handler(mock_blockchain, mock_block_validator, mock_utxo_set_w);
+
+    let result = reorganize(&handler, branch_block2.clone()).await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::BlockValidation(
+                BlockValidationError::DuplicateTransactions
+            ))
+        ),
+        "Reorganization onto an invalid branch should fail"
+    );
+
+    assert_eq!(
+        *tip.lock().unwrap(),
+        (canon_block.get_hash(), BlockHeight::from(1)),
+        "Previous canon tip should be restored"
+    );
+    assert_eq!(
+        *applied.lock().unwrap(),
+        vec![branch_block1.get_hash(), canon_block.get_hash()],
+        "Previous canon block should be reconnected after the connected branch block"
+    );
+    assert_eq!(
+        *reverted.lock().unwrap(),
+        vec![canon_block.get_hash(), branch_block1.get_hash()],
+        "Connected branch blocks should be reverted"
+    );
+    assert_eq!(
+        *invalidated.lock().unwrap(),
+        vec![branch_hash],
+        "Only the failing branch block should be marked as invalid"
+    );
+
+    let replaced = replaced.lock().unwrap();
+    assert_eq!(
+        replaced.len(),
+        1,
+        "Only the connected branch block should be re-stored"
+    );
+    assert_eq!(replaced[0].get_hash(), branch_block1.get_hash());
+    assert!(
+        replaced[0]
+            .get_transactions()
+            .iter()
+            .all(|tx| tx.get_fee() == TransactionAmount::new(7)),
+        "Connected branch block should be re-stored with its transaction fees"
+    );
+}
+
+#[tokio::test]
+async fn test_reorganize_skips_descendants_of_invalid_block() {
+    let genesis_block = create_test_genesis_block();
+    let canon_block = create_test_child_block(&genesis_block, BlockDifficultyTarget::initial());
+    let invalid_block =
+        create_test_child_block(&genesis_block, BlockDifficultyTarget::new(u128::MAX >> 17));
+    let invalid_hash = invalid_block.get_hash();
+    let branch_block = create_test_child_block(&invalid_block, BlockDifficultyTarget::initial());
+    let branch_hash = branch_block.get_hash();
+    let invalidated = Arc::new(Mutex::new(Vec::<Hash>::new()));
+
+    let mut mock_blockchain = MockBlockchain::new();
+    mock_blockchain
+        .expect_has_canon_block()
+        .returning(|_| Ok(false));
+    mock_blockchain.expect_get_tip_info().returning({
+        let tip_hash = canon_block.get_hash();
+        move || Ok(Some((tip_hash.clone(), BlockHeight::from(1))))
+    });
+    mock_blockchain.expect_get_chain_work().returning({
+        let branch_hash = branch_hash.clone();
+        move |hash| Ok(Some(if *hash == branch_hash { 3 } else { 2 }))
+    });
+    mock_blockchain.expect_get_branch().returning({
+        let fork_block = genesis_block.clone();
+        let branch = vec![invalid_block.clone(), branch_block.clone()];
+        move |_| Ok((fork_block.clone(), branch.clone()))
+    });
+    mock_blockchain
+        .expect_is_invalid_block()
+        .returning(move |hash| Ok(*hash == invalid_hash));
+    mock_blockchain.expect_rewind_tip().times(0);
+    mock_blockchain.expect_invalidate_block().returning({
+        let invalidated = invalidated.clone();
+        move |hash| {
+            invalidated.lock().unwrap().push(hash.clone());
+            Ok(())
+        }
+    });
+
+    let mut mock_utxo_set_w = MockUtxoSetWriter::new();
+    mock_utxo_set_w.expect_apply_block().times(0);
+    mock_utxo_set_w.expect_revert_block().times(0);
+
+    let handler = create_test_handler(mock_blockchain, MockBlockValidator::new(), mock_utxo_set_w);
+
+    let result = reorganize(&handler, branch_block).await;
+    assert!(result.is_ok(), "Invalid branches should be skipped");
+    assert_eq!(
+        *invalidated.lock().unwrap(),
+        vec![branch_hash],
+        "Descendants of an invalid block should be marked as invalid"
+    );
+}
+
+// ============================================================================
+// handle_receive_transaction()
+// ============================================================================
+
+fn create_test_tx(outpoint_seed: u8) -> NonValidatedTransaction {
+    let public_key = "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key");
+    let mut outpoint_hash = [0u8; 32];
+    outpoint_hash[0] = outpoint_seed;
+    let input = TransactionInput::new(
+        TransactionOutPoint::new(Hash::new(outpoint_hash), 0),
+        public_key.clone(),
+    );
+    let output = TransactionOutput::new(
+        WalletAddress::from(&public_key),
+        TransactionAmount::new(100),
+    );
+    NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1725799696000))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_receive_transaction_rejects_forged_id_of_known_transaction() {
+    let known_tx = create_test_tx(1);
+    let known_hash = known_tx.get_hash();
+
+    // Peers may declare any id, which leads the encoded transaction
+    let mut data = create_test_tx(2).try_encode().unwrap();
+    data[..32].copy_from_slice(known_hash.as_bytes());
+    let (forged_tx, _): (NonValidatedTransaction, usize) =
+        bincode::decode_from_slice(&data, bincode::config::standard()).unwrap();
+    assert_eq!(forged_tx.get_hash(), known_hash);
+
+    let mempool = Arc::new(DefaultMempool::new(
+        Block::MAX_ENCODED_SIZE,
+        TransactionAmount::new(0),
+        false,
+        0,
+        0,
+        Arc::new(MockUtxoSetReader::new()),
+    ));
+    mempool
+        .add_transaction(Transaction::_new_validated(
+            known_tx,
+            TransactionAmount::new(0),
+        ))
+        .await
+        .unwrap();
+
+    let mut mock_tx_validator = MockTransactionValidator::new();
+    mock_tx_validator.expect_validate_transaction().times(0);
+
+    let handler = MempoolCommandHandler::new(
+        Arc::new(MockBlockchain::new()),
+        mempool.clone(),
+        Arc::new(mock_tx_validator),
+        Arc::new(MockP2PNetworkHandle::new()),
+    );
+
+    let (tx, rx) = oneshot::channel();
+    handler
+        .handle_receive_transaction(
+            NetworkPeerId::_new_validated(vec![1], "peer".to_string()),
+            forged_tx,
+            Box::new(TestResponder(tx)),
+        )
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(
+            rx.await.unwrap(),
+            Err(AppError::TransactionValidation(
+                TransactionValidationError::InvalidTransactionId { .. }
+            ))
+        ),
+        "Transactions with forged ids shouldn't be mistaken for known ones"
+    );
+}
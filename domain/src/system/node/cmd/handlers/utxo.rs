@@ -1,7 +1,8 @@
 use super::super::CommandResponder;
 use super::CommandHandlerControlFlow;
-use crate::entities::transaction::{TransactionOutPoint, Utxo};
+use crate::entities::transaction::{TransactionAmount, TransactionOutPoint, Utxo};
 use crate::system::utxo::UtxoSetReader;
+use crate::types::wallet::WalletAddress;
 use common::error::AppError;
 use common::log_node_debug;
 use std::sync::Arc;
@@ -46,4 +47,35 @@ impl UtxoCommandHandler {
         responder.respond(res);
         Ok(CommandHandlerControlFlow::Continue)
     }
+
+    /// Get UTXOs paying a wallet address.
+    pub(in crate::system::node) async fn handle_get_utxos_by_address(
+        &self,
+        address: WalletAddress,
+        responder: Box<dyn CommandResponder<Result<Vec<Utxo>, AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!("UtxoCommandHandler: Getting UTXOs for address {}", address);
+
+        let res = self.utxo_set_r.get_utxos_by_address(&address);
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
+
+    /// Get the balance of a wallet address.
+    pub(in crate::system::node) async fn handle_get_balance_by_address(
+        &self,
+        address: WalletAddress,
+        responder: Box<dyn CommandResponder<Result<TransactionAmount, AppError>> + Send>,
+    ) -> Result<CommandHandlerControlFlow, AppError> {
+        log_node_debug!(
+            "UtxoCommandHandler: Getting balance for address {}",
+            address
+        );
+
+        let res = self.utxo_set_r.get_balance_by_address(&address);
+
+        responder.respond(res);
+        Ok(CommandHandlerControlFlow::Continue)
+    }
 }
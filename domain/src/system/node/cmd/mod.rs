@@ -1,13 +1,16 @@
 pub mod handlers;
 
-use crate::entities::block::{Block, BlockHeight, BlockTemplate, NonValidatedBlock};
+use crate::entities::block::{
+    Block, BlockDifficultyTarget, BlockHeight, BlockTemplate, NonValidatedBlock,
+};
 use crate::entities::transaction::{
-    NonValidatedTransaction, Transaction, TransactionOutPoint, Utxo,
+    NonValidatedTransaction, Transaction, TransactionAmount, TransactionOutPoint, Utxo,
 };
 use crate::genesis::config::GenesisConfig;
 use crate::system::network::event::{AddPeerResponse, NetworkEvent};
 use crate::types::hash::Hash;
 use crate::types::network::{NetworkAddress, NetworkIdentityKeypair, NetworkPeerId};
+use crate::types::wallet::WalletAddress;
 use async_trait::async_trait;
 use common::error::AppError;
 use common::params::PaginationParams;
@@ -34,16 +37,69 @@ pub enum BlockchainCommand {
         BlockTemplate,
         #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<Block, AppError>> + Send>,
     ),
+    /// Dev-administered command to submit an externally mined block.<br />
+    /// The block is validated and inserted into the blockchain, same as locally mined blocks.
+    SubmitBlock(
+        NonValidatedBlock,
+        #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<Block, AppError>> + Send>,
+    ),
     /// Post-blockchain insertion command to handle updating subsystems and incrementing active height.
     HandleBlockAppend(
         Block,
         #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
     ),
+    /// Post-blockchain insertion command to handle switching over to a heavier side branch.
+    HandleBlockReorganize(
+        Block,
+        #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ),
     /// Dev-administered command to retrieve blockchain tip information.
+    #[allow(clippy::type_complexity)]
     GetTipInfo(
         #[derivative(Debug = "ignore")]
         Box<dyn CommandResponder<Result<Option<(Hash, BlockHeight)>, AppError>> + Send>,
     ),
+    /// Retrieves blockchain tip information alongside its cumulative chain work.<br />
+    /// Advertised to peers during the tip exchange.
+    #[allow(clippy::type_complexity)]
+    GetTipChainInfo(
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<Option<(Hash, BlockHeight, u128)>, AppError>> + Send>,
+    ),
+    /// Locates the fork point of a peer's chain, given its block locator.<br />
+    /// Responds with the fork height alongside the local tip height.
+    #[allow(clippy::type_complexity)]
+    GetForkPoint(
+        Vec<Hash>,
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<Option<(BlockHeight, BlockHeight)>, AppError>> + Send>,
+    ),
+    /// Retrieves the difficulty target expected for a block at the given height.
+    GetDifficultyTarget(
+        BlockHeight,
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<BlockDifficultyTarget, AppError>> + Send>,
+    ),
+    /// Dev-administered command to assemble a block template out of the mempool's transactions.<br />
+    /// The template's coinbase pays the given reward address.<br />
+    /// Lets external miners solve blocks on top of the blockchain tip.
+    GetBlockTemplate(
+        WalletAddress,
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<BlockTemplate, AppError>> + Send>,
+    ),
+    /// Dev-administered command to start the background mining worker.<br />
+    /// Falls back to the configured reward address if none is provided.<br />
+    /// Returns the reward address in use.
+    StartMining(
+        Option<WalletAddress>,
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<WalletAddress, AppError>> + Send>,
+    ),
+    /// Dev-administered command to stop the background mining worker.
+    StopMining(
+        #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ),
     /// Dev-administered command to retrieve blockchain block.
     GetBlock(
         Hash,
@@ -75,6 +131,7 @@ pub enum MempoolCommand {
     ),
     /// Dev-administered command to retrieve mempool transactions with pagination.<br />
     /// Returns the paginated transactions along with the total transaction count.
+    #[allow(clippy::type_complexity)]
     GetPaginatedTransactions(
         PaginationParams,
         #[derivative(Debug = "ignore")]
@@ -102,12 +159,25 @@ pub enum UtxoCommand {
         #[derivative(Debug = "ignore")]
         Box<dyn CommandResponder<Result<Vec<Utxo>, AppError>> + Send>,
     ),
+    /// Dev-administered command to retrieve the UTXOs paying a wallet address.
+    GetUtxosByAddress(
+        WalletAddress,
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<Vec<Utxo>, AppError>> + Send>,
+    ),
+    /// Dev-administered command to retrieve the balance of a wallet address.
+    GetBalanceByAddress(
+        WalletAddress,
+        #[derivative(Debug = "ignore")]
+        Box<dyn CommandResponder<Result<TransactionAmount, AppError>> + Send>,
+    ),
 }
 
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub enum NetworkCommand {
     /// Dev-administered command to retrieve this node's network information.
+    #[allow(clippy::type_complexity)]
     GetSelfInfo(
         #[derivative(Debug = "ignore")]
         Box<
@@ -131,10 +201,16 @@ pub enum NetworkCommand {
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub enum P2PCommand {
-    /// Handles receiving blockchain tip info from a peer.
+    /// Handles receiving blockchain tip info, alongside its chain work, from a peer.
     HandleReceiveBlockchainTipInfo(
         NetworkPeerId,
-        Option<(Hash, BlockHeight)>,
+        Option<(Hash, BlockHeight, u128)>,
+        #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ),
+    /// Handles receiving the fork point of a peer's chain, alongside its tip height.
+    HandleReceiveForkPoint(
+        NetworkPeerId,
+        Option<(BlockHeight, BlockHeight)>,
         #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
     ),
     /// Handles receiving blocks from a peer.
@@ -143,6 +219,12 @@ pub enum P2PCommand {
         Vec<NonValidatedBlock>,
         #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
     ),
+    /// Handles receiving a mempool transaction from a peer.
+    HandleReceiveTransaction(
+        NetworkPeerId,
+        NonValidatedTransaction,
+        #[derivative(Debug = "ignore")] Box<dyn CommandResponder<Result<(), AppError>> + Send>,
+    ),
     /// Forwards a network event to the appropriate subsystem handler.<br />
     /// Used to decouple subsystems dependent on network event publishing from `P2PNetworkHandle`.
     ProxyForwardNetworkEvent(
@@ -197,6 +279,7 @@ pub trait CommandResponder<T>: Send + Debug {
 
 /// A factory for building cmd command requests along with their associated response futures.<br />
 /// Published events are consumed in [`crate::system::node::state::run::NodeRunning`].
+#[allow(clippy::type_complexity)]
 pub trait CommandResponderFactory: Send + Sync + Debug {
     // Blockchain commands
     fn build_blk_cmd_init_genesis(
@@ -215,6 +298,14 @@ pub trait CommandResponderFactory: Send + Sync + Debug {
         Pin<Box<dyn Future<Output = Result<Block, AppError>> + Send>>,
     );
 
+    fn build_blk_cmd_submit_block(
+        &self,
+        block: NonValidatedBlock,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Block, AppError>> + Send>>,
+    );
+
     fn build_blk_cmd_handle_block_append(
         &self,
         block: Block,
@@ -223,6 +314,14 @@ pub trait CommandResponderFactory: Send + Sync + Debug {
         Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
     );
 
+    fn build_blk_cmd_handle_block_reorganize(
+        &self,
+        block: Block,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    );
+
     fn build_blk_cmd_get_tip_info(
         &self,
     ) -> (
@@ -230,6 +329,52 @@ pub trait CommandResponderFactory: Send + Sync + Debug {
         Pin<Box<dyn Future<Output = Result<Option<(Hash, BlockHeight)>, AppError>> + Send>>,
     );
 
+    fn build_blk_cmd_get_tip_chain_info(
+        &self,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Option<(Hash, BlockHeight, u128)>, AppError>> + Send>>,
+    );
+
+    fn build_blk_cmd_get_fork_point(
+        &self,
+        locator: Vec<Hash>,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Option<(BlockHeight, BlockHeight)>, AppError>> + Send>>,
+    );
+
+    fn build_blk_cmd_get_difficulty_target(
+        &self,
+        block_height: BlockHeight,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<BlockDifficultyTarget, AppError>> + Send>>,
+    );
+
+    fn build_blk_cmd_get_block_template(
+        &self,
+        reward_address: WalletAddress,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<BlockTemplate, AppError>> + Send>>,
+    );
+
+    fn build_blk_cmd_start_mining(
+        &self,
+        reward_address: Option<WalletAddress>,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<WalletAddress, AppError>> + Send>>,
+    );
+
+    fn build_blk_cmd_stop_mining(
+        &self,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    );
+
     fn build_blk_cmd_get_block(
         &self,
         block_hash: Hash,
@@ -295,6 +440,22 @@ pub trait CommandResponderFactory: Send + Sync + Debug {
         Pin<Box<dyn Future<Output = Result<Vec<Utxo>, AppError>> + Send>>,
     );
 
+    fn build_utxo_cmd_get_utxos_by_address(
+        &self,
+        address: WalletAddress,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Vec<Utxo>, AppError>> + Send>>,
+    );
+
+    fn build_utxo_cmd_get_balance_by_address(
+        &self,
+        address: WalletAddress,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<TransactionAmount, AppError>> + Send>>,
+    );
+
     // Network commands
     fn build_net_cmd_get_self_info(
         &self,
@@ -327,7 +488,16 @@ pub trait CommandResponderFactory: Send + Sync + Debug {
     fn build_p2p_cmd_receive_blockchain_tip_info(
         &self,
         origin_peer_id: NetworkPeerId,
-        block_info: Option<(Hash, BlockHeight)>,
+        block_info: Option<(Hash, BlockHeight, u128)>,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    );
+
+    fn build_p2p_cmd_receive_fork_point(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        fork_point: Option<(BlockHeight, BlockHeight)>,
     ) -> (
         NodeCommandRequest,
         Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
@@ -342,6 +512,15 @@ pub trait CommandResponderFactory: Send + Sync + Debug {
         Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
     );
 
+    fn build_p2p_cmd_receive_transaction(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        tx: NonValidatedTransaction,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    );
+
     fn build_proxy_cmd_forward_network_event(
         &self,
         event: NetworkEvent,
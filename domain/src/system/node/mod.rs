@@ -4,6 +4,7 @@ mod state;
 pub use state::*;
 
 use crate::repos::blockchain::BlockchainRepository;
+use crate::repos::mempool::MempoolRepository;
 use crate::repos::outbox::OutboxRepository;
 use crate::repos::utxo::UtxoRepository;
 use crate::system::network::P2PNetworkEngine;
@@ -15,8 +16,17 @@ pub async fn build_node(
     cfg: NodeConfig,
     blockchain_repo: Arc<dyn BlockchainRepository>,
     utxo_repo: Arc<dyn UtxoRepository>,
+    mempool_repo: Arc<dyn MempoolRepository>,
     outbox_repo: Arc<dyn OutboxRepository>,
     network: Box<dyn P2PNetworkEngine>,
 ) -> Result<NodeInitialized, AppError> {
-    NodeInitialized::init(cfg, blockchain_repo, utxo_repo, outbox_repo, network).await
+    NodeInitialized::init(
+        cfg,
+        blockchain_repo,
+        utxo_repo,
+        mempool_repo,
+        outbox_repo,
+        network,
+    )
+    .await
 }
@@ -1,10 +1,13 @@
+use crate::repos::mempool::MempoolRepository;
 use crate::system::blockchain::Blockchain;
+use crate::system::difficulty::DifficultyAdjuster;
 use crate::system::mempool::Mempool;
+use crate::system::mining::{BlockTemplateBuilder, MiningSwitch};
 use crate::system::network::P2PNetworkHandle;
 use crate::system::node::cmd::{CommandResponderFactory, CommandSender};
 use crate::system::node::state::init::NodeInitialized;
 use crate::system::node::state::start::NodeStarted;
-use crate::system::queue::{BlockProcessingQueue, BlockSyncQueue};
+use crate::system::queue::{BlockOrphanPool, BlockProcessingQueue, BlockSyncQueue};
 use crate::system::utxo::{UtxoSetReader, UtxoSetWriter};
 use crate::system::validation::block::BlockValidator;
 use crate::system::validation::transaction::TransactionValidator;
@@ -18,12 +21,19 @@ pub struct NodeBootstrapped {
     pub(super) cfg: NodeConfig,
     pub(super) blockchain: Arc<dyn Blockchain>,
     pub(super) mempool: Arc<dyn Mempool>,
+    pub(super) mempool_repo: Arc<dyn MempoolRepository>,
     pub(super) utxo_set_rw: (Arc<dyn UtxoSetReader>, Arc<dyn UtxoSetWriter>),
     pub(super) network: Arc<dyn P2PNetworkHandle>,
     pub(super) block_sync_queue: Arc<dyn BlockSyncQueue>,
     pub(super) block_proc_queue: Arc<dyn BlockProcessingQueue>,
+    pub(super) block_orphan_pool: Arc<dyn BlockOrphanPool>,
+    pub(super) difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
     pub(super) block_validator: Arc<dyn BlockValidator>,
     pub(super) tx_validator: Arc<dyn TransactionValidator>,
+    pub(super) block_template_builder: Arc<dyn BlockTemplateBuilder>,
+    pub(super) mining_switch: Arc<MiningSwitch>,
+    pub(super) cmd_tx: Arc<dyn CommandSender>,
+    pub(super) cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
 }
 
 impl NodeBootstrapped {
@@ -33,6 +43,7 @@ impl NodeBootstrapped {
         cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
         block_sync_queue: Arc<dyn BlockSyncQueue>,
         block_proc_queue: Arc<dyn BlockProcessingQueue>,
+        block_orphan_pool: Arc<dyn BlockOrphanPool>,
         shutdown_rx: tokio::sync::broadcast::Receiver<()>,
     ) -> Result<Self, AppError> {
         log_node_info!("Node is bootstrapping...");
@@ -40,19 +51,26 @@ impl NodeBootstrapped {
         // Connect to P2P network
         let network = node
             .network
-            .connect(cmd_tx, cmd_tx_res_factory, shutdown_rx)
+            .connect(cmd_tx.clone(), cmd_tx_res_factory.clone(), shutdown_rx)
             .await?;
 
         let node = Self {
             cfg: node.cfg,
             blockchain: node.blockchain,
             mempool: node.mempool,
+            mempool_repo: node.mempool_repo,
             utxo_set_rw: node.utxo_set_rw,
             network,
             block_sync_queue,
             block_proc_queue,
+            block_orphan_pool,
+            difficulty_adjuster: node.difficulty_adjuster,
             block_validator: node.block_validator,
             tx_validator: node.tx_validator,
+            block_template_builder: node.block_template_builder,
+            mining_switch: node.mining_switch,
+            cmd_tx,
+            cmd_tx_res_factory,
         };
 
         log_node_info!("Node bootstrapped successfully.");
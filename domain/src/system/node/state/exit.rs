@@ -1,3 +1,4 @@
+use crate::repos::mempool::MempoolRepository;
 use crate::system::blockchain::Blockchain;
 use crate::system::mempool::Mempool;
 use crate::system::network::P2PNetworkHandle;
@@ -14,8 +15,8 @@ pub struct NodeTerminating {
     pub(super) cfg: NodeConfig,
     #[allow(unused)]
     pub(super) blockchain: Arc<dyn Blockchain>,
-    #[allow(unused)]
     pub(super) mempool: Arc<dyn Mempool>,
+    pub(super) mempool_repo: Arc<dyn MempoolRepository>,
     #[allow(unused)]
     pub(super) utxo_set_rw: (Arc<dyn UtxoSetReader>, Arc<dyn UtxoSetWriter>),
     #[allow(unused)]
@@ -23,7 +24,7 @@ pub struct NodeTerminating {
 }
 
 impl NodeTerminating {
-    pub(super) fn terminate(
+    pub(super) async fn terminate(
         node: NodeRunning,
         shutdown_tx: tokio::sync::broadcast::Sender<()>,
     ) -> Result<Self, AppError> {
@@ -33,19 +34,32 @@ impl NodeTerminating {
             cfg: node.cfg,
             blockchain: node.blockchain,
             mempool: node.mempool,
+            mempool_repo: node.mempool_repo,
             utxo_set_rw: (node.utxo_set_r, node.utxo_set_w),
             network: node.network,
         };
 
         node.handle_termination(shutdown_tx);
+        node.persist_mempool().await;
 
         log_node_info!("Node terminated.");
         Ok(node)
     }
 
     fn handle_termination(&self, shutdown_tx: tokio::sync::broadcast::Sender<()>) {
-        if let Err(_) = shutdown_tx.send(()) {
+        if shutdown_tx.send(()).is_err() {
             log_node_error!("Failed to broadcast node termination signal!");
         };
     }
+
+    /// Persists pending transactions, for them to be reloaded on the next startup.<br />
+    /// Ancestors are ordered ahead of their descendants, allowing chains to be readmitted in order.
+    async fn persist_mempool(&self) {
+        let transactions = self.mempool.get_pooled_transactions().await;
+        let count = transactions.len();
+        match self.mempool_repo.replace_transactions(transactions) {
+            Ok(()) => log_node_info!("Persisted {} mempool transaction(s).", count),
+            Err(err) => log_node_error!("Failed to persist mempool transactions! | Error: {}", err),
+        }
+    }
 }
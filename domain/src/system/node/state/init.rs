@@ -1,20 +1,26 @@
 use crate::entities::block::BlockHeight;
+use crate::entities::transaction::TransactionAmount;
 use crate::repos::blockchain::BlockchainRepository;
+use crate::repos::mempool::MempoolRepository;
 use crate::repos::outbox::OutboxRepository;
 use crate::repos::utxo::UtxoRepository;
 use crate::system::blockchain::{Blockchain, DefaultBlockchain};
+use crate::system::difficulty::{DifficultyAdjuster, LwmaDifficultyAdjuster};
 use crate::system::mempool::{DefaultMempool, Mempool};
+use crate::system::mining::{BlockTemplateBuilder, DefaultBlockTemplateBuilder, MiningSwitch};
 use crate::system::network::P2PNetworkEngine;
 use crate::system::node::cmd::{CommandResponderFactory, CommandSender};
 use crate::system::node::state::boot::NodeBootstrapped;
-use crate::system::queue::{BlockProcessingQueue, BlockSyncQueue};
+use crate::system::queue::{BlockOrphanPool, BlockProcessingQueue, BlockSyncQueue};
 use crate::system::utxo::{UtxoReaderService, UtxoSetReader, UtxoSetWriter, UtxoSetWriterService};
 use crate::system::validation::block::{BlockValidator, DefaultBlockValidator};
 use crate::system::validation::transaction::{DefaultTransactionValidator, TransactionValidator};
 use crate::types::hash::Hash;
+use crate::types::wallet::WalletAddress;
 use common::config::node::NodeConfig;
 use common::error::AppError;
-use common::log_node_info;
+use common::{log_node_debug, log_node_info};
+use std::str::FromStr;
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -22,10 +28,14 @@ pub struct NodeInitialized {
     pub(super) cfg: NodeConfig,
     pub(super) blockchain: Arc<dyn Blockchain>,
     pub(super) mempool: Arc<dyn Mempool>,
+    pub(super) mempool_repo: Arc<dyn MempoolRepository>,
     pub(super) utxo_set_rw: (Arc<dyn UtxoSetReader>, Arc<dyn UtxoSetWriter>),
     pub(super) network: Box<dyn P2PNetworkEngine>,
+    pub(super) difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
     pub(super) block_validator: Arc<dyn BlockValidator>,
     pub(super) tx_validator: Arc<dyn TransactionValidator>,
+    pub(super) block_template_builder: Arc<dyn BlockTemplateBuilder>,
+    pub(super) mining_switch: Arc<MiningSwitch>,
 }
 
 impl NodeInitialized {
@@ -33,6 +43,7 @@ impl NodeInitialized {
         cfg: NodeConfig,
         blockchain_repo: Arc<dyn BlockchainRepository>,
         utxo_repo: Arc<dyn UtxoRepository>,
+        mempool_repo: Arc<dyn MempoolRepository>,
         outbox_repo: Arc<dyn OutboxRepository>,
         network: Box<dyn P2PNetworkEngine>,
     ) -> Result<Self, AppError> {
@@ -41,27 +52,119 @@ impl NodeInitialized {
         let utxo_set_r = Arc::new(UtxoReaderService::new(utxo_repo.clone()));
         let utxo_set_w = Arc::new(UtxoSetWriterService::new(utxo_repo));
         let blockchain = Arc::new(DefaultBlockchain::new(blockchain_repo, outbox_repo.clone()));
-        let mempool = Arc::new(DefaultMempool::new());
-        let tx_validator = Arc::new(DefaultTransactionValidator::new(utxo_set_r.clone()));
+        let mempool = Arc::new(DefaultMempool::new(
+            cfg.max_mempool_size_bytes as usize,
+            TransactionAmount::new(cfg.min_relay_fee_rate.into()),
+            cfg.replace_by_fee_enabled,
+            cfg.max_replacement_evictions as usize,
+            cfg.mempool_expiry_secs,
+            utxo_set_r.clone(),
+        ));
+        let tx_validator = Arc::new(DefaultTransactionValidator::new(
+            utxo_set_r.clone(),
+            cfg.coinbase_maturity,
+        ));
+        let difficulty_adjuster = Arc::new(LwmaDifficultyAdjuster::new(
+            blockchain.clone(),
+            cfg.target_block_interval_secs,
+        ));
         let block_validator = Arc::new(DefaultBlockValidator::new(
             blockchain.clone(),
             tx_validator.clone(),
+            difficulty_adjuster.clone(),
+            cfg.max_future_block_drift_secs,
+        ));
+        let block_template_builder = Arc::new(DefaultBlockTemplateBuilder::new(
+            blockchain.clone(),
+            mempool.clone(),
+            tx_validator.clone(),
+            difficulty_adjuster.clone(),
         ));
 
+        let mining_reward_address = cfg
+            .mining_reward_address
+            .as_deref()
+            .map(WalletAddress::from_str)
+            .transpose()?;
+        if cfg.mining_enabled && mining_reward_address.is_none() {
+            return Err(AppError::precondition_failed(
+                "Mining is enabled, but no mining reward address was configured!",
+            ));
+        }
+        let mining_switch = Arc::new(MiningSwitch::new(mining_reward_address, cfg.mining_enabled));
+
         let node = Self {
             cfg,
             blockchain,
             mempool,
+            mempool_repo,
             utxo_set_rw: (utxo_set_r, utxo_set_w),
             network,
+            difficulty_adjuster,
             block_validator,
             tx_validator,
+            block_template_builder,
+            mining_switch,
         };
+        node.reload_mempool().await?;
 
         log_node_info!("Node initialized successfully.");
         Ok(node)
     }
 
+    /// Readmits the mempool transactions persisted on the last graceful shutdown.<br />
+    /// Each transaction is revalidated against the current UTXO set, as the chain may have moved on.
+    async fn reload_mempool(&self) -> Result<(), AppError> {
+        let transactions = self.mempool_repo.get_transactions()?;
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        let block_height = match self.blockchain.get_tip_info().await? {
+            Some((_, tip_height)) => tip_height.next(),
+            None => BlockHeight::genesis(),
+        };
+        let count = transactions.len();
+        let mut readmitted = 0;
+        for (tx, added_at) in transactions {
+            let tx_hash = tx.get_hash();
+            let res = async {
+                // Persisted transactions may spend the outputs of earlier readmitted ones.
+                let outpoints = tx
+                    .get_inputs()
+                    .iter()
+                    .map(|txin| txin.get_previous_output().clone())
+                    .collect::<Vec<_>>();
+                let pending_utxos = self
+                    .mempool
+                    .get_pending_utxos(&outpoints, &block_height)
+                    .await;
+                let tx = self
+                    .tx_validator
+                    .validate_transaction(tx.invalidate(), &block_height, pending_utxos)
+                    .await?;
+                // Persisted transactions keep expiring relative to when they were first pooled.
+                self.mempool.restore_transaction(tx, added_at).await
+            }
+            .await;
+            match res {
+                Ok(()) => readmitted += 1,
+                Err(err) => log_node_debug!(
+                    "Dropping persisted mempool transaction! | Hash: {}, Error: {}",
+                    tx_hash,
+                    err
+                ),
+            }
+        }
+
+        log_node_info!(
+            "Mempool reloaded | Readmitted {} of {} persisted transaction(s)",
+            readmitted,
+            count
+        );
+        Ok(())
+    }
+
     pub async fn get_tip_info(&self) -> Result<Option<(Hash, BlockHeight)>, AppError> {
         self.blockchain.get_tip_info().await
     }
@@ -72,6 +175,7 @@ impl NodeInitialized {
         cmd_tx_res_factory: Arc<dyn CommandResponderFactory>,
         block_sync_queue: Arc<dyn BlockSyncQueue>,
         block_proc_queue: Arc<dyn BlockProcessingQueue>,
+        block_orphan_pool: Arc<dyn BlockOrphanPool>,
         shutdown_rx: tokio::sync::broadcast::Receiver<()>,
     ) -> Result<NodeBootstrapped, AppError> {
         NodeBootstrapped::bootstrap(
@@ -80,6 +184,7 @@ impl NodeInitialized {
             cmd_tx_res_factory,
             block_sync_queue,
             block_proc_queue,
+            block_orphan_pool,
             shutdown_rx,
         )
         .await
@@ -1,16 +1,23 @@
+use crate::entities::block::BlockHeight;
+use crate::repos::mempool::MempoolRepository;
 use crate::system::blockchain::Blockchain;
 use crate::system::mempool::Mempool;
+use crate::system::mining::BlockMiningWorker;
 use crate::system::network::P2PNetworkHandle;
+use crate::system::network::event::{NetworkEvent, TaliroNetworkData, TaliroNetworkEvent};
 use crate::system::node::cmd::CommandReceiver;
 use crate::system::node::cmd::handlers::CommandDispatcher;
 use crate::system::node::state::exit::NodeTerminating;
 use crate::system::node::state::start::NodeStarted;
-use crate::system::queue::BlockProcessingQueue;
+use crate::system::queue::{BlockOrphanPool, BlockProcessingQueue};
 use crate::system::utxo::{UtxoSetReader, UtxoSetWriter};
 use crate::system::validation::block::BlockValidator;
+use crate::types::hash::Hash;
+use crate::types::network::NetworkPeerId;
 use common::config::node::NodeConfig;
-use common::error::AppError;
+use common::error::{AppError, BlockValidationError};
 use common::{log_node_debug, log_node_error, log_node_info};
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time;
@@ -20,11 +27,14 @@ pub struct NodeRunning {
     pub(super) cfg: NodeConfig,
     pub(super) blockchain: Arc<dyn Blockchain>,
     pub(super) mempool: Arc<dyn Mempool>,
+    pub(super) mempool_repo: Arc<dyn MempoolRepository>,
     pub(super) utxo_set_r: Arc<dyn UtxoSetReader>,
     pub(super) utxo_set_w: Arc<dyn UtxoSetWriter>,
     pub(super) network: Arc<dyn P2PNetworkHandle>,
     pub(super) block_validator: Arc<dyn BlockValidator>,
     block_proc_queue: Arc<dyn BlockProcessingQueue>,
+    block_orphan_pool: Arc<dyn BlockOrphanPool>,
+    mining_worker: Arc<BlockMiningWorker>,
     cmd_dispatcher: CommandDispatcher,
 }
 
@@ -36,11 +46,14 @@ impl NodeRunning {
             cfg: node.cfg,
             blockchain: node.blockchain,
             mempool: node.mempool,
+            mempool_repo: node.mempool_repo,
             utxo_set_r: node.utxo_set_rw.0,
             utxo_set_w: node.utxo_set_rw.1,
             network: node.network,
             block_validator: node.block_validator,
             block_proc_queue: node.block_proc_queue,
+            block_orphan_pool: node.block_orphan_pool,
+            mining_worker: node.mining_worker,
             cmd_dispatcher: node.cmd_dispatcher,
         }
     }
@@ -54,11 +67,16 @@ impl NodeRunning {
         // Handle Network Block Processing
         tokio::spawn(Self::spawn_block_queue_events_processor_worker_task(
             self.block_proc_queue.clone(),
+            self.block_orphan_pool.clone(),
             self.blockchain.clone(),
             self.block_validator.clone(),
-            shutdown_rx,
+            self.network.clone(),
+            shutdown_rx.resubscribe(),
         ));
 
+        // Handle Block Mining
+        tokio::spawn(self.mining_worker.clone().run(shutdown_rx));
+
         // Handle Command Events
         loop {
             match cmd_rx.receive().await {
@@ -87,21 +105,23 @@ impl NodeRunning {
         }
 
         // Initiate Graceful Shutdown
-        self.terminate(shutdown_tx)?;
+        self.terminate(shutdown_tx).await?;
         Ok(())
     }
 
-    pub(crate) fn terminate(
+    pub(crate) async fn terminate(
         self,
         shutdown_tx: tokio::sync::broadcast::Sender<()>,
     ) -> Result<NodeTerminating, AppError> {
-        NodeTerminating::terminate(self, shutdown_tx)
+        NodeTerminating::terminate(self, shutdown_tx).await
     }
 
     async fn spawn_block_queue_events_processor_worker_task(
         processing_queue: Arc<dyn BlockProcessingQueue>,
+        orphan_pool: Arc<dyn BlockOrphanPool>,
         blockchain: Arc<dyn Blockchain>,
         block_validator: Arc<dyn BlockValidator>,
+        network: Arc<dyn P2PNetworkHandle>,
         mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
     ) {
         let poll_interval = Duration::from_millis(100); // TODO: pass from cfg
@@ -110,23 +130,59 @@ impl NodeRunning {
             tokio::select! {
                 // Await next block
                 result = processing_queue.next_ready_block() => {
-                    if let Some(block) = result {
+                    if let Some((block, from_peer)) = result {
                         let height = block.get_height();
                         let hash = block.get_hash();
+
+                        // Blocks extending an unknown parent are pooled until their parent arrives.
+                        if let Some(prev_hash) = block.get_prev_block_hash() {
+                            // Descendants of rejected blocks may have been buffered prior to rejection.
+                            if processing_queue.is_rejected(&prev_hash).await {
+                                Self::reject_block(&processing_queue, &orphan_pool, hash, &height).await;
+                                continue;
+                            }
+                            match blockchain.has_known_block(&prev_hash).await {
+                                Ok(true) => {}
+                                Ok(false) => {
+                                    processing_queue.discard_block(&height).await;
+                                    if orphan_pool.add_orphan(block, from_peer.clone()).await {
+                                        Self::request_orphan_parent(&network, prev_hash, from_peer);
+                                    }
+                                    continue;
+                                }
+                                Err(err) => {
+                                    log_node_error!("Failed to look up parent block! | Height {:?} | Hash {:?} | Error: {:?}", height, hash, err);
+                                    processing_queue.mark_block_failed(&height).await;
+                                    continue;
+                                }
+                            }
+                        }
+
                         let validated_block = match block_validator.validate_block(block).await {
                             Ok(block) => block,
-                            Err(err) => {
+                            // Blocks may reach us more than once, across both sync and gossip.
+                            Err(AppError::BlockValidation(BlockValidationError::BlockAlreadyKnown { .. })) => {
+                                processing_queue.mark_block_processed(&height).await;
+                                continue;
+                            }
+                            Err(err) if err.is_transient() => {
                                 log_node_error!("Failed to validate block! | Height {:?}: | Hash {:?} | Error: {:?}", height, hash, err);
-                                // TODO:
-                                // Distinguish between failure causes.
-                                // If non-transient, remove the invalid block from the queue.
                                 processing_queue.mark_block_failed(&height).await;
                                 continue;
                             }
+                            Err(err) => {
+                                log_node_error!("Rejecting invalid block! | Height {:?}: | Hash {:?} | Error: {:?}", height, hash, err);
+                                Self::reject_block(&processing_queue, &orphan_pool, hash, &height).await;
+                                Self::request_replacement_block(&network, height, from_peer).await;
+                                continue;
+                            }
                         };
                         match blockchain.add_block(validated_block).await {
                             Ok(_) => {
                                 processing_queue.mark_block_processed(&height).await;
+                                for (orphan, orphan_peer) in orphan_pool.take_orphans_by_parent(&hash).await {
+                                    processing_queue.push_block(orphan, orphan_peer).await;
+                                }
                             }
                             Err(err) => {
                                 log_node_error!("Failed to add block! | Height {:?}  | Hash{:?}: | Error: {:?}", height, hash, err);
@@ -151,4 +207,78 @@ impl NodeRunning {
 
         log_node_info!("Block queue events processor worker task exiting....");
     }
+
+    /// Permanently rejects an invalid block, alongside any of its pooled descendants.
+    async fn reject_block(
+        processing_queue: &Arc<dyn BlockProcessingQueue>,
+        orphan_pool: &Arc<dyn BlockOrphanPool>,
+        block_hash: Hash,
+        height: &BlockHeight,
+    ) {
+        processing_queue.reject_block(&block_hash, height).await;
+
+        let mut rejected_parents = vec![block_hash];
+        while let Some(parent_hash) = rejected_parents.pop() {
+            for (orphan, _) in orphan_pool.take_orphans_by_parent(&parent_hash).await {
+                let orphan_hash = orphan.get_hash();
+                processing_queue
+                    .reject_block(&orphan_hash, &orphan.get_height())
+                    .await;
+                rejected_parents.push(orphan_hash);
+            }
+        }
+    }
+
+    /// Requests a replacement for a rejected block from every other connected peer.
+    async fn request_replacement_block(
+        network: &Arc<dyn P2PNetworkHandle>,
+        height: BlockHeight,
+        rejected_peer: NetworkPeerId,
+    ) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        if let Err(err) = network.publish_network_event(NetworkEvent::GetPeers(tx)) {
+            log_node_error!("Failed to retrieve network peers! | Error: {:?}", err);
+            return;
+        }
+        let Ok(peers) = rx.await else {
+            log_node_error!("Failed to retrieve network peers!");
+            return;
+        };
+
+        let peer_ids: HashSet<NetworkPeerId> = peers
+            .into_iter()
+            .map(|address| address.get_peer_id())
+            .filter(|peer_id| *peer_id != rejected_peer)
+            .collect();
+        for peer_id in peer_ids {
+            log_node_debug!(
+                "Requesting replacement block (height: {:?}) from peer {:?}",
+                height,
+                peer_id
+            );
+            let event_data = TaliroNetworkData::GetBlockByHeight(height.clone());
+            let event = NetworkEvent::Taliro(TaliroNetworkEvent::new(peer_id, event_data));
+            if let Err(err) = network.publish_network_event(event) {
+                log_node_error!("Failed to request replacement block! | Error: {:?}", err);
+            }
+        }
+    }
+
+    /// Requests an orphan's missing parent from the peer that announced the orphan.
+    fn request_orphan_parent(
+        network: &Arc<dyn P2PNetworkHandle>,
+        parent_hash: Hash,
+        from_peer: NetworkPeerId,
+    ) {
+        log_node_debug!(
+            "Requesting missing parent block (hash: {:?}) from peer {:?}",
+            parent_hash,
+            from_peer
+        );
+        let event_data = TaliroNetworkData::GetBlockByHash(parent_hash);
+        let event = NetworkEvent::Taliro(TaliroNetworkEvent::new(from_peer, event_data));
+        if let Err(err) = network.publish_network_event(event) {
+            log_node_error!("Failed to request missing parent block! | Error: {:?}", err);
+        }
+    }
 }
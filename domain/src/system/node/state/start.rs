@@ -1,5 +1,7 @@
+use crate::repos::mempool::MempoolRepository;
 use crate::system::blockchain::Blockchain;
 use crate::system::mempool::Mempool;
+use crate::system::mining::{BlockMiningWorker, DefaultBlockMiner};
 use crate::system::network::P2PNetworkHandle;
 use crate::system::node::cmd::CommandReceiver;
 use crate::system::node::cmd::handlers::{
@@ -8,7 +10,7 @@ use crate::system::node::cmd::handlers::{
 };
 use crate::system::node::state::boot::NodeBootstrapped;
 use crate::system::node::state::run::NodeRunning;
-use crate::system::queue::BlockProcessingQueue;
+use crate::system::queue::{BlockOrphanPool, BlockProcessingQueue};
 use crate::system::utxo::{UtxoSetReader, UtxoSetWriter};
 use crate::system::validation::block::BlockValidator;
 use common::config::node::NodeConfig;
@@ -21,10 +23,13 @@ pub struct NodeStarted {
     pub(super) cfg: NodeConfig,
     pub(super) blockchain: Arc<dyn Blockchain>,
     pub(super) mempool: Arc<dyn Mempool>,
+    pub(super) mempool_repo: Arc<dyn MempoolRepository>,
     pub(super) utxo_set_rw: (Arc<dyn UtxoSetReader>, Arc<dyn UtxoSetWriter>),
     pub(super) network: Arc<dyn P2PNetworkHandle>,
     pub(super) block_proc_queue: Arc<dyn BlockProcessingQueue>,
+    pub(super) block_orphan_pool: Arc<dyn BlockOrphanPool>,
     pub(super) block_validator: Arc<dyn BlockValidator>,
+    pub(super) mining_worker: Arc<BlockMiningWorker>,
     pub(super) cmd_dispatcher: CommandDispatcher,
 }
 
@@ -36,11 +41,20 @@ impl NodeStarted {
         let blockchain_handler = BlockchainCommandHandler::new(
             node.blockchain.clone(),
             node.block_validator.clone(),
+            node.tx_validator.clone(),
+            node.difficulty_adjuster.clone(),
             node.utxo_set_rw.1.clone(),
             node.mempool.clone(),
             node.network.clone(),
+            node.block_template_builder.clone(),
+            node.mining_switch.clone(),
+        );
+        let mempool_handler = MempoolCommandHandler::new(
+            node.blockchain.clone(),
+            node.mempool.clone(),
+            node.tx_validator.clone(),
+            node.network.clone(),
         );
-        let mempool_handler = MempoolCommandHandler::new(node.mempool.clone(), node.tx_validator);
         let network_handler = NetworkCommandHandler::new(
             node.network.clone(),
             node.blockchain.clone(),
@@ -54,14 +68,27 @@ impl NodeStarted {
             utxo_handler,
         );
 
+        // Build mining worker
+        let mining_worker = Arc::new(BlockMiningWorker::new(
+            node.blockchain.clone(),
+            node.block_template_builder,
+            Arc::new(DefaultBlockMiner::new()),
+            node.mining_switch,
+            node.cmd_tx,
+            node.cmd_tx_res_factory,
+        ));
+
         let node = Self {
             cfg: node.cfg,
             blockchain: node.blockchain,
             mempool: node.mempool,
+            mempool_repo: node.mempool_repo,
             utxo_set_rw: node.utxo_set_rw,
             network: node.network,
             block_proc_queue: node.block_proc_queue,
+            block_orphan_pool: node.block_orphan_pool,
             block_validator: node.block_validator,
+            mining_worker,
             cmd_dispatcher,
         };
 
@@ -1,5 +1,7 @@
+mod orphan;
 mod proc;
 mod sync;
 
+pub use orphan::BlockOrphanPool;
 pub use proc::BlockProcessingQueue;
 pub use sync::BlockSyncQueue;
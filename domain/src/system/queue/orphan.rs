@@ -0,0 +1,19 @@
+use crate::entities::block::NonValidatedBlock;
+use crate::types::hash::Hash;
+use crate::types::network::NetworkPeerId;
+use async_trait::async_trait;
+
+/// A pool holding blocks whose parent is yet unknown, keyed by the hash of said parent.<br />
+/// Orphans are released once their parent gets added to the blockchain.
+#[async_trait]
+pub trait BlockOrphanPool: Send + Sync + std::fmt::Debug {
+    /// Add an orphan block, alongside the peer it was received from.<br />
+    /// Returns `false` if the block was already pooled.
+    async fn add_orphan(&self, block: NonValidatedBlock, from_peer: NetworkPeerId) -> bool;
+
+    /// Remove and return any orphans directly extending the given block.
+    async fn take_orphans_by_parent(
+        &self,
+        parent_hash: &Hash,
+    ) -> Vec<(NonValidatedBlock, NetworkPeerId)>;
+}
@@ -1,16 +1,20 @@
 use crate::entities::block::{BlockHeight, NonValidatedBlock};
+use crate::types::hash::Hash;
+use crate::types::network::NetworkPeerId;
 use async_trait::async_trait;
 
 /// A queue responsible for in-order block processing, buffering out-of-order blocks and preventing concurrent handling of the same block.
 #[async_trait]
 pub trait BlockProcessingQueue: Send + Sync + std::fmt::Debug {
-    /// Push a block into the processing queue.<br />
+    /// Push a block, received from the given peer, into the processing queue.<br />
+    /// Rejected blocks and their descendants are dropped.<br />
     /// Should be polled by [`next_ready_block()`].
-    async fn push_block(&self, block: NonValidatedBlock);
+    async fn push_block(&self, block: NonValidatedBlock, from_peer: NetworkPeerId);
 
     /// Poll for the next block ready for processing.<br />
+    /// Blocks below the next expected height are handed out once, without awaiting their outcome.<br />
     /// Subsequent polls will return `None` until the previously returned block is marked as processed or failed.
-    async fn next_ready_block(&self) -> Option<NonValidatedBlock>;
+    async fn next_ready_block(&self) -> Option<(NonValidatedBlock, NetworkPeerId)>;
 
     /// Notify the queue that a block was successfully processed.
     async fn mark_block_processed(&self, height: &BlockHeight);
@@ -18,4 +22,15 @@ pub trait BlockProcessingQueue: Send + Sync + std::fmt::Debug {
     /// Notify the queue that a block processing failed.<br />
     /// This will allow re-processing of the block in future polls.
     async fn mark_block_failed(&self, height: &BlockHeight);
+
+    /// Drop a buffered block without advancing the next expected height.<br />
+    /// Another block may be pushed for the same height later on.
+    async fn discard_block(&self, height: &BlockHeight);
+
+    /// Permanently reject an invalid block, dropping it if buffered.<br />
+    /// The block and any of its descendants will be ignored from now on.
+    async fn reject_block(&self, block_hash: &Hash, height: &BlockHeight);
+
+    /// Check whether a block hash was previously rejected.
+    async fn is_rejected(&self, block_hash: &Hash) -> bool;
 }
@@ -1,10 +1,14 @@
 mod read;
+mod view;
 mod write;
 
 pub(crate) use read::UtxoReaderService;
 pub use read::UtxoSetReader;
+pub(crate) use view::PendingUtxoView;
 pub use write::UtxoSetWriter;
 pub(crate) use write::UtxoSetWriterService;
 
 #[cfg(test)]
 pub(crate) use read::MockUtxoSetReader;
+#[cfg(test)]
+pub(crate) use write::MockUtxoSetWriter;
@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod tests;
 
-use crate::entities::transaction::{TransactionOutPoint, Utxo};
+use crate::entities::transaction::{TransactionAmount, TransactionOutPoint, Utxo};
 use crate::repos::utxo::UtxoRepository;
+use crate::types::wallet::WalletAddress;
 use common::error::AppError;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -13,11 +14,26 @@ pub trait UtxoSetReader: Send + Sync + Debug {
 
     fn get_multiple_utxos_by_outpoints(
         &self,
-        outpoints: &Vec<TransactionOutPoint>,
+        outpoints: &[TransactionOutPoint],
     ) -> Result<Vec<Utxo>, AppError>;
 
     fn get_multiple_utxos(&self) -> Result<Vec<Utxo>, AppError>;
 
+    fn get_utxos_by_address(&self, address: &WalletAddress) -> Result<Vec<Utxo>, AppError>;
+
+    /// Sums the amounts of all UTXOs paying the given address.<br />
+    /// Immature coinbase outputs are included.
+    fn get_balance_by_address(
+        &self,
+        address: &WalletAddress,
+    ) -> Result<TransactionAmount, AppError> {
+        let mut balance = TransactionAmount::new(0);
+        for utxo in self.get_utxos_by_address(address)? {
+            balance.checked_add_assign(utxo.get_output().get_amount())?;
+        }
+        Ok(balance)
+    }
+
     fn get_utxo_count(&self) -> usize;
 }
 
@@ -34,23 +50,17 @@ impl UtxoReaderService {
 
 impl UtxoSetReader for UtxoReaderService {
     fn get_utxo(&self, outpoint: &TransactionOutPoint) -> Result<Option<Utxo>, AppError> {
-        let output = self.utxo_repo.get_output(None, outpoint)?;
-        let utxo = output.map(|tx_out| Utxo::new(outpoint.clone(), tx_out));
-        Ok(utxo)
+        self.utxo_repo.get_utxo(None, outpoint)
     }
 
     fn get_multiple_utxos_by_outpoints(
         &self,
-        outpoints: &Vec<TransactionOutPoint>,
+        outpoints: &[TransactionOutPoint],
     ) -> Result<Vec<Utxo>, AppError> {
         let utxos = outpoints
             .iter()
-            .map(|outpoint| (outpoint, self.utxo_repo.get_output(None, outpoint)))
-            .filter_map(|(outpoint, res_output)| match res_output {
-                Ok(Some(output)) => Some(Ok(Utxo::new(outpoint.clone(), output))),
-                Ok(None) => None,
-                Err(err) => Some(Err(err)),
-            })
+            .map(|outpoint| self.utxo_repo.get_utxo(None, outpoint))
+            .filter_map(|res_utxo| res_utxo.transpose())
             .collect::<Result<Vec<Utxo>, AppError>>()?;
 
         Ok(utxos)
@@ -60,6 +70,10 @@ impl UtxoSetReader for UtxoReaderService {
         self.utxo_repo.get_multiple_utxos()
     }
 
+    fn get_utxos_by_address(&self, address: &WalletAddress) -> Result<Vec<Utxo>, AppError> {
+        self.utxo_repo.get_utxos_by_address(address)
+    }
+
     fn get_utxo_count(&self) -> usize {
         self.utxo_repo.get_utxo_count()
     }
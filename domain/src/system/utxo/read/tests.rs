@@ -1,4 +1,5 @@
 use super::*;
+use crate::entities::block::BlockHeight;
 use crate::entities::transaction::{
     TransactionAmount, TransactionOutPoint, TransactionOutput, Utxo,
 };
@@ -24,19 +25,19 @@ fn create_test_output(amount: u128) -> TransactionOutput {
 fn create_test_utxo(seed: u8, amount: u128) -> Utxo {
     let outpoint = create_test_outpoint(seed);
     let output = create_test_output(amount);
-    Utxo::new(outpoint, output)
+    Utxo::new(outpoint, output, BlockHeight::from(1), false)
 }
 
 #[test]
 fn test_get_utxo_found() {
     let outpoint = create_test_outpoint(1);
-    let output = create_test_output(100);
+    let utxo = create_test_utxo(1, 100);
     let outpoint_clone = outpoint.clone();
 
     let mut mock_repo = MockUtxoRepository::new();
     mock_repo
-        .expect_get_output()
-        .returning(move |_, _| Ok(Some(output.clone())));
+        .expect_get_utxo()
+        .returning(move |_, _| Ok(Some(utxo.clone())));
 
     let service = UtxoReaderService::new(Arc::new(mock_repo));
 
@@ -53,7 +54,7 @@ fn test_get_utxo_not_found() {
     let outpoint = create_test_outpoint(1);
 
     let mut mock_repo = MockUtxoRepository::new();
-    mock_repo.expect_get_output().returning(|_, _| Ok(None));
+    mock_repo.expect_get_utxo().returning(|_, _| Ok(None));
 
     let service = UtxoReaderService::new(Arc::new(mock_repo));
 
@@ -68,7 +69,7 @@ fn test_get_utxo_repository_error() {
 
     let mut mock_repo = MockUtxoRepository::new();
     mock_repo
-        .expect_get_output()
+        .expect_get_utxo()
         .returning(|_, _| Err(AppError::internal("Database error")));
 
     let service = UtxoReaderService::new(Arc::new(mock_repo));
@@ -84,18 +85,18 @@ fn test_get_multiple_utxos_by_outpoints_all_found() {
     let outpoint2 = create_test_outpoint(2);
     let outpoint3 = create_test_outpoint(3);
 
-    let output1 = create_test_output(100);
-    let output2 = create_test_output(200);
-    let output3 = create_test_output(300);
+    let utxo1 = create_test_utxo(1, 100);
+    let utxo2 = create_test_utxo(2, 200);
+    let utxo3 = create_test_utxo(3, 300);
 
     let mut mock_repo = MockUtxoRepository::new();
-    mock_repo.expect_get_output().returning(move |_, op| {
+    mock_repo.expect_get_utxo().returning(move |_, op| {
         if op.get_tx_output_index() == 1 {
-            Ok(Some(output1.clone()))
+            Ok(Some(utxo1.clone()))
         } else if op.get_tx_output_index() == 2 {
-            Ok(Some(output2.clone()))
+            Ok(Some(utxo2.clone()))
         } else if op.get_tx_output_index() == 3 {
-            Ok(Some(output3.clone()))
+            Ok(Some(utxo3.clone()))
         } else {
             Ok(None)
         }
@@ -118,15 +119,15 @@ fn test_get_multiple_utxos_by_outpoints_some_not_found() {
     let outpoint2 = create_test_outpoint(2);
     let outpoint3 = create_test_outpoint(3);
 
-    let output1 = create_test_output(100);
-    let output3 = create_test_output(300);
+    let utxo1 = create_test_utxo(1, 100);
+    let utxo3 = create_test_utxo(3, 300);
 
     let mut mock_repo = MockUtxoRepository::new();
-    mock_repo.expect_get_output().returning(move |_, op| {
+    mock_repo.expect_get_utxo().returning(move |_, op| {
         if op.get_tx_output_index() == 1 {
-            Ok(Some(output1.clone()))
+            Ok(Some(utxo1.clone()))
         } else if op.get_tx_output_index() == 3 {
-            Ok(Some(output3.clone()))
+            Ok(Some(utxo3.clone()))
         } else {
             Ok(None) // outpoint2 not found
         }
@@ -159,9 +160,9 @@ fn test_get_multiple_utxos_by_outpoints_error_propagation() {
     let outpoint2 = create_test_outpoint(2);
 
     let mut mock_repo = MockUtxoRepository::new();
-    mock_repo.expect_get_output().returning(|_, op| {
+    mock_repo.expect_get_utxo().returning(|_, op| {
         if op.get_tx_output_index() == 1 {
-            Ok(Some(create_test_output(100)))
+            Ok(Some(create_test_utxo(1, 100)))
         } else {
             Err(AppError::internal("Database error"))
         }
@@ -228,6 +229,86 @@ fn test_get_multiple_utxos_error() {
     assert!(result.is_err(), "Should propagate error from repository");
 }
 
+#[test]
+fn test_get_utxos_by_address() {
+    let utxo1 = create_test_utxo(1, 100);
+    let utxo2 = create_test_utxo(2, 200);
+    let address = utxo1.get_output().get_recipient().clone();
+    let expected_address = address.clone();
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxos_by_address()
+        .withf(move |address| *address == expected_address)
+        .returning(move |_| Ok(vec![utxo1.clone(), utxo2.clone()]));
+
+    let service = UtxoReaderService::new(Arc::new(mock_repo));
+
+    let result = service.get_utxos_by_address(&address).unwrap();
+
+    assert_eq!(result.len(), 2, "Should return the address' UTXOs");
+}
+
+#[test]
+fn test_get_balance_by_address() {
+    let utxo1 = create_test_utxo(1, 100);
+    let utxo2 = create_test_utxo(2, 250);
+    let address = utxo1.get_output().get_recipient().clone();
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxos_by_address()
+        .returning(move |_| Ok(vec![utxo1.clone(), utxo2.clone()]));
+
+    let service = UtxoReaderService::new(Arc::new(mock_repo));
+
+    let balance = service.get_balance_by_address(&address).unwrap();
+
+    assert_eq!(
+        balance.as_u128(),
+        350,
+        "Should sum the address' UTXO amounts"
+    );
+}
+
+#[test]
+fn test_get_balance_by_address_empty() {
+    let address = create_test_output(0).get_recipient().clone();
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxos_by_address()
+        .returning(|_| Ok(vec![]));
+
+    let service = UtxoReaderService::new(Arc::new(mock_repo));
+
+    let balance = service.get_balance_by_address(&address).unwrap();
+
+    assert_eq!(
+        balance.as_u128(),
+        0,
+        "Should return 0 for addresses without UTXOs"
+    );
+}
+
+#[test]
+fn test_get_balance_by_address_overflow() {
+    let utxo1 = create_test_utxo(1, u128::MAX);
+    let utxo2 = create_test_utxo(2, 1);
+    let address = utxo1.get_output().get_recipient().clone();
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxos_by_address()
+        .returning(move |_| Ok(vec![utxo1.clone(), utxo2.clone()]));
+
+    let service = UtxoReaderService::new(Arc::new(mock_repo));
+
+    let result = service.get_balance_by_address(&address);
+
+    assert!(result.is_err(), "Should reject overflowing balances");
+}
+
 #[test]
 fn test_get_utxo_count_zero() {
     let mut mock_repo = MockUtxoRepository::new();
@@ -259,8 +340,8 @@ fn test_get_utxo_with_different_amounts() {
     // Test with zero amount
     let mut mock_repo = MockUtxoRepository::new();
     mock_repo
-        .expect_get_output()
-        .returning(|_, _| Ok(Some(create_test_output(0))));
+        .expect_get_utxo()
+        .returning(|_, _| Ok(Some(create_test_utxo(1, 0))));
 
     let service = UtxoReaderService::new(Arc::new(mock_repo));
     let result = service.get_utxo(&outpoint).unwrap();
@@ -269,8 +350,8 @@ fn test_get_utxo_with_different_amounts() {
     // Test with large amount
     let mut mock_repo = MockUtxoRepository::new();
     mock_repo
-        .expect_get_output()
-        .returning(|_, _| Ok(Some(create_test_output(u128::MAX))));
+        .expect_get_utxo()
+        .returning(|_, _| Ok(Some(create_test_utxo(1, u128::MAX))));
 
     let service = UtxoReaderService::new(Arc::new(mock_repo));
     let result = service.get_utxo(&outpoint).unwrap();
@@ -285,15 +366,15 @@ fn test_multiple_calls_to_get_utxo() {
     let outpoint1 = create_test_outpoint(1);
     let outpoint2 = create_test_outpoint(2);
 
-    let output1 = create_test_output(100);
-    let output2 = create_test_output(200);
+    let utxo1 = create_test_utxo(1, 100);
+    let utxo2 = create_test_utxo(2, 200);
 
     let mut mock_repo = MockUtxoRepository::new();
-    mock_repo.expect_get_output().returning(move |_, op| {
+    mock_repo.expect_get_utxo().returning(move |_, op| {
         if op.get_tx_output_index() == 1 {
-            Ok(Some(output1.clone()))
+            Ok(Some(utxo1.clone()))
         } else if op.get_tx_output_index() == 2 {
-            Ok(Some(output2.clone()))
+            Ok(Some(utxo2.clone()))
         } else {
             Ok(None)
         }
@@ -0,0 +1,68 @@
+use super::UtxoSetReader;
+use crate::entities::transaction::{TransactionOutPoint, Utxo};
+use crate::types::wallet::WalletAddress;
+use common::error::AppError;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Layers pending (unconfirmed) outputs over the confirmed UTXO set.<br />
+/// Lets transactions spend the outputs of others yet to be confirmed,
+/// whether pending in the mempool or preceding them within a block.
+#[derive(Debug)]
+pub(crate) struct PendingUtxoView {
+    confirmed: Arc<dyn UtxoSetReader>,
+    pending: HashMap<TransactionOutPoint, Utxo>,
+}
+
+impl PendingUtxoView {
+    pub(crate) fn new(confirmed: Arc<dyn UtxoSetReader>, pending_utxos: Vec<Utxo>) -> Self {
+        let pending = pending_utxos
+            .into_iter()
+            .map(|utxo| (utxo.get_outpoint().clone(), utxo))
+            .collect();
+        Self { confirmed, pending }
+    }
+}
+
+impl UtxoSetReader for PendingUtxoView {
+    fn get_utxo(&self, outpoint: &TransactionOutPoint) -> Result<Option<Utxo>, AppError> {
+        match self.pending.get(outpoint) {
+            Some(utxo) => Ok(Some(utxo.clone())),
+            None => self.confirmed.get_utxo(outpoint),
+        }
+    }
+
+    fn get_multiple_utxos_by_outpoints(
+        &self,
+        outpoints: &[TransactionOutPoint],
+    ) -> Result<Vec<Utxo>, AppError> {
+        let utxos = outpoints
+            .iter()
+            .map(|outpoint| self.get_utxo(outpoint))
+            .filter_map(|res_utxo| res_utxo.transpose())
+            .collect::<Result<Vec<Utxo>, AppError>>()?;
+
+        Ok(utxos)
+    }
+
+    fn get_multiple_utxos(&self) -> Result<Vec<Utxo>, AppError> {
+        let mut utxos = self.confirmed.get_multiple_utxos()?;
+        utxos.extend(self.pending.values().cloned());
+        Ok(utxos)
+    }
+
+    fn get_utxos_by_address(&self, address: &WalletAddress) -> Result<Vec<Utxo>, AppError> {
+        let mut utxos = self.confirmed.get_utxos_by_address(address)?;
+        utxos.extend(
+            self.pending
+                .values()
+                .filter(|utxo| utxo.get_output().get_recipient() == address)
+                .cloned(),
+        );
+        Ok(utxos)
+    }
+
+    fn get_utxo_count(&self) -> usize {
+        self.confirmed.get_utxo_count() + self.pending.len()
+    }
+}
@@ -1,17 +1,26 @@
 #[cfg(test)]
 mod tests;
 
-use crate::entities::block::Block;
+use crate::entities::block::{Block, BlockUndo};
 use crate::entities::transaction::{TransactionOutPoint, Utxo};
 use crate::repos::utxo::UtxoRepository;
+use crate::types::hash::Hash;
 use common::error::AppError;
 use common::tx::AtomicTransactionOutput;
 use common::{log_utxo_debug, log_utxo_info};
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+#[cfg_attr(test, mockall::automock)]
 pub trait UtxoSetWriter: Send + Sync + Debug {
+    /// Spends the outputs consumed by the block and inserts the ones it creates.<br />
+    /// The spent outputs are retained as the block's undo data.
     fn apply_block(&self, block: &Block) -> Result<(), AppError>;
+
+    /// Reverses a previously applied block, restoring the outputs it spent.<br />
+    /// Blocks must be reverted in reverse order of application.
+    fn revert_block(&self, block: &Block) -> Result<(), AppError>;
 }
 
 #[derive(Debug)]
@@ -29,6 +38,19 @@ impl UtxoSetWriter for UtxoSetWriterService {
     fn apply_block(&self, block: &Block) -> Result<(), AppError> {
         log_utxo_info!("UtxoSetWriter.apply_block() | block: {:?}", &block);
 
+        // Outputs created and spent within the block never make it into the UTXO set.
+        let created: HashSet<Hash> = block
+            .get_transactions()
+            .iter()
+            .map(|tx| tx.get_hash())
+            .collect();
+        let spent: HashSet<TransactionOutPoint> = block
+            .get_transactions()
+            .iter()
+            .flat_map(|tx| tx.get_inputs())
+            .map(|input| input.get_previous_output().clone())
+            .collect();
+
         let to_delete: Vec<TransactionOutPoint> = block
             .get_transactions()
             .iter()
@@ -37,35 +59,52 @@ impl UtxoSetWriter for UtxoSetWriterService {
                     .iter()
                     .map(|input| input.get_previous_output().clone())
             })
+            .filter(|outpoint| !created.contains(outpoint.get_tx_id()))
             .collect();
 
+        // Genesis funds are configured allocations, exempt from coinbase maturity.
+        let block_height = block.get_height();
+        let genesis_block = block.is_genesis_block();
         let to_insert: Vec<Utxo> = block
             .get_transactions()
             .iter()
             .flat_map(|tx| {
+                let coinbase = tx.is_coinbase_tx() && !genesis_block;
+                let block_height = block_height.clone();
                 tx.get_outputs()
                     .iter()
                     .enumerate()
                     .map(move |(index, output)| {
                         let outpoint = TransactionOutPoint::new(tx.get_hash(), index);
-                        Ok(Utxo::new(outpoint, output.clone()))
+                        Utxo::new(outpoint, output.clone(), block_height.clone(), coinbase)
                     })
             })
-            .collect::<Result<_, AppError>>()?;
+            .filter(|utxo| !spent.contains(utxo.get_outpoint()))
+            .collect();
 
         log_utxo_debug!("UtxoSetWriter.apply_block() | to_delete: {:?}", to_delete);
         log_utxo_debug!("UtxoSetWriter.apply_block() | to_insert: {:?}", to_insert);
 
         let utxo_repo = self.utxo_repo.clone();
+        let block_hash = block.get_hash();
 
         let unit_of_work = self.utxo_repo.get_utxo_set_append_block_unit_of_work();
         unit_of_work.run_in_transaction(Box::new(move |ctx| {
+            let mut spent_utxos = Vec::with_capacity(to_delete.len());
             for outpoint in &to_delete {
+                let Some(utxo) = utxo_repo.get_utxo(Some(ctx), outpoint)? else {
+                    return Err(AppError::internal(format!(
+                        "Missing spent UTXO ({}) while applying block ({})!",
+                        outpoint, block_hash
+                    )));
+                };
+                spent_utxos.push(utxo);
                 utxo_repo.delete_utxo(Some(ctx), outpoint)?;
             }
             for utxo in &to_insert {
                 utxo_repo.insert_utxo(Some(ctx), utxo.clone())?;
             }
+            utxo_repo.insert_block_undo(Some(ctx), &block_hash, BlockUndo::new(spent_utxos))?;
             Ok(AtomicTransactionOutput::new(()))
         }))?;
 
@@ -75,4 +114,46 @@ impl UtxoSetWriter for UtxoSetWriterService {
         );
         Ok(())
     }
+
+    fn revert_block(&self, block: &Block) -> Result<(), AppError> {
+        log_utxo_info!("UtxoSetWriter.revert_block() | block: {:?}", &block);
+
+        let to_delete: Vec<TransactionOutPoint> = block
+            .get_transactions()
+            .iter()
+            .flat_map(|tx| {
+                (0..tx.get_outputs().len())
+                    .map(move |index| TransactionOutPoint::new(tx.get_hash(), index))
+            })
+            .collect();
+
+        log_utxo_debug!("UtxoSetWriter.revert_block() | to_delete: {:?}", to_delete);
+
+        let utxo_repo = self.utxo_repo.clone();
+        let block_hash = block.get_hash();
+
+        let unit_of_work = self.utxo_repo.get_utxo_set_append_block_unit_of_work();
+        unit_of_work.run_in_transaction(Box::new(move |ctx| {
+            let Some(block_undo) = utxo_repo.get_block_undo(Some(ctx), &block_hash)? else {
+                return Err(AppError::internal(format!(
+                    "Missing undo data for block ({})!",
+                    block_hash
+                )));
+            };
+            for outpoint in &to_delete {
+                utxo_repo.delete_utxo(Some(ctx), outpoint)?;
+            }
+            for utxo in block_undo.get_spent_utxos() {
+                utxo_repo.insert_utxo(Some(ctx), utxo.clone())?;
+            }
+            utxo_repo.delete_block_undo(Some(ctx), &block_hash)?;
+            Ok(AtomicTransactionOutput::new(()))
+        }))?;
+
+        log_utxo_info!(
+            "UtxoSetWriter.revert_block(): UtxoSet successfully reverted for block ({}) ",
+            block.get_hash()
+        );
+        Ok(())
+    }
 }
@@ -1,8 +1,10 @@
 use super::*;
-use crate::entities::block::{Block, BlockDifficultyTarget, BlockTemplate, NonValidatedBlock};
+use crate::entities::block::{
+    Block, BlockDifficultyTarget, BlockHeight, BlockTemplate, BlockUndo, NonValidatedBlock,
+};
 use crate::entities::transaction::{
     NonValidatedTransaction, Transaction, TransactionAmount, TransactionInput, TransactionOutPoint,
-    TransactionOutput,
+    TransactionOutput, Utxo,
 };
 use crate::genesis::config::{GenesisConfig, GenesisConfigUtxoFunds};
 use crate::repos::utxo::MockUtxoRepository;
@@ -56,7 +58,7 @@ fn create_mock_transaction_with_inputs_outputs(
 ) -> Transaction {
     let timestamp = DateTime::from_ms(1000000000 + (seed as u64) * 1000);
     let tx = NonValidatedTransaction::new(inputs, outputs, timestamp).unwrap();
-    Transaction::_new_validated(tx)
+    Transaction::_new_validated(tx, TransactionAmount::new(0))
 }
 
 fn create_test_output(amount: u128) -> TransactionOutput {
@@ -66,8 +68,23 @@ fn create_test_output(amount: u128) -> TransactionOutput {
     TransactionOutput::new(wallet_address, TransactionAmount::new(amount))
 }
 
+fn create_test_public_key() -> PublicKey {
+    "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key")
+}
+
 fn create_test_input(prev_outpoint: TransactionOutPoint) -> TransactionInput {
-    TransactionInput::new(prev_outpoint)
+    TransactionInput::new(prev_outpoint, create_test_public_key())
+}
+
+fn create_test_spent_utxo(outpoint: &TransactionOutPoint) -> Utxo {
+    Utxo::new(
+        outpoint.clone(),
+        create_test_output(100),
+        BlockHeight::from(1),
+        false,
+    )
 }
 
 // Mockall doesn't support mocking Fn objects. See https://github.com/asomers/mockall/issues/139.
@@ -113,6 +130,12 @@ fn test_apply_block_with_outputs_only() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo
         .expect_delete_utxo()
@@ -156,6 +179,12 @@ fn test_apply_block_with_inputs_and_outputs() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo
         .expect_delete_utxo()
@@ -204,6 +233,12 @@ fn test_apply_block_with_multiple_transactions() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo
         .expect_delete_utxo()
@@ -264,6 +299,12 @@ fn test_apply_block_delete_error() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo
         .expect_delete_utxo()
@@ -290,6 +331,12 @@ fn test_apply_block_insert_error() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo.expect_delete_utxo().returning(|_, _| Ok(()));
 
@@ -317,6 +364,12 @@ fn test_apply_block_correct_outpoint_indices() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo.expect_insert_utxo().returning(move |_, utxo| {
         inserted_clone.lock().unwrap().push(utxo.clone());
@@ -360,6 +413,12 @@ fn test_apply_block_preserves_output_amounts() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo.expect_insert_utxo().returning(move |_, utxo| {
         inserted_clone.lock().unwrap().push(utxo.clone());
@@ -393,6 +452,12 @@ fn test_apply_block_deletes_correct_outpoints() {
     mock_repo
         .expect_get_utxo_set_append_block_unit_of_work()
         .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
 
     mock_repo
         .expect_delete_utxo()
@@ -423,3 +488,220 @@ fn test_apply_block_deletes_correct_outpoints() {
     assert_eq!(deleted_outpoints[1].get_tx_id(), &prev_tx_hash2);
     assert_eq!(deleted_outpoints[1].get_tx_output_index(), 3);
 }
+
+#[test]
+fn test_apply_block_records_spent_utxos() {
+    let undo = Arc::new(Mutex::new(None));
+    let undo_clone = undo.clone();
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxo_set_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo.expect_delete_utxo().returning(|_, _| Ok(()));
+    mock_repo.expect_insert_utxo().returning(|_, _| Ok(()));
+    mock_repo
+        .expect_insert_block_undo()
+        .times(1)
+        .returning(move |_, _, block_undo| {
+            *undo_clone.lock().unwrap() = Some(block_undo);
+            Ok(())
+        });
+
+    let service = UtxoSetWriterService::new(Arc::new(mock_repo));
+
+    let prev_tx_hash = Hash::new([1u8; 32]);
+    let inputs = vec![
+        create_test_input(TransactionOutPoint::new(prev_tx_hash.clone(), 0)),
+        create_test_input(TransactionOutPoint::new(prev_tx_hash.clone(), 1)),
+    ];
+    let tx = create_mock_transaction_with_inputs_outputs(inputs, vec![create_test_output(50)], 1);
+    let block = create_test_block_with_transactions(vec![tx]);
+
+    service.apply_block(&block).unwrap();
+
+    let undo = undo.lock().unwrap();
+    let spent_utxos = undo.as_ref().unwrap().get_spent_utxos();
+    assert_eq!(spent_utxos.len(), 2, "Should record 2 spent UTXOs");
+    assert_eq!(spent_utxos[0].get_outpoint().get_tx_output_index(), 0);
+    assert_eq!(spent_utxos[1].get_outpoint().get_tx_output_index(), 1);
+}
+
+#[test]
+fn test_apply_block_missing_spent_utxo() {
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxo_set_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo.expect_get_utxo().returning(|_, _| Ok(None));
+
+    let service = UtxoSetWriterService::new(Arc::new(mock_repo));
+
+    let inputs = vec![create_test_input(TransactionOutPoint::new(
+        Hash::new([1u8; 32]),
+        0,
+    ))];
+    let tx = create_mock_transaction_with_inputs_outputs(inputs, vec![], 1);
+    let block = create_test_block_with_transactions(vec![tx]);
+
+    let result = service.apply_block(&block);
+
+    assert!(result.is_err(), "Should fail when a spent UTXO is missing");
+}
+
+#[test]
+fn test_apply_block_skips_outputs_spent_within_block() {
+    let deleted = Arc::new(Mutex::new(Vec::new()));
+    let inserted = Arc::new(Mutex::new(Vec::new()));
+
+    let deleted_clone = deleted.clone();
+    let inserted_clone = inserted.clone();
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxo_set_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_utxo()
+        .returning(|_, outpoint| Ok(Some(create_test_spent_utxo(outpoint))));
+    mock_repo
+        .expect_insert_block_undo()
+        .returning(|_, _, _| Ok(()));
+    mock_repo
+        .expect_delete_utxo()
+        .returning(move |_, outpoint| {
+            deleted_clone.lock().unwrap().push(outpoint.clone());
+            Ok(())
+        });
+    mock_repo.expect_insert_utxo().returning(move |_, utxo| {
+        inserted_clone.lock().unwrap().push(utxo.clone());
+        Ok(())
+    });
+
+    let service = UtxoSetWriterService::new(Arc::new(mock_repo));
+
+    // Parent: 1 confirmed input, 2 outputs
+    let confirmed_outpoint = TransactionOutPoint::new(Hash::new([1u8; 32]), 0);
+    let parent_tx = create_mock_transaction_with_inputs_outputs(
+        vec![create_test_input(confirmed_outpoint.clone())],
+        vec![create_test_output(40), create_test_output(50)],
+        1,
+    );
+    let parent_change_outpoint = TransactionOutPoint::new(parent_tx.get_hash(), 1);
+
+    // Child: spends the parent's change output
+    let child_tx = create_mock_transaction_with_inputs_outputs(
+        vec![create_test_input(parent_change_outpoint.clone())],
+        vec![create_test_output(50)],
+        2,
+    );
+    let child_hash = child_tx.get_hash();
+
+    let block = create_test_block_with_transactions(vec![parent_tx, child_tx]);
+
+    let result = service.apply_block(&block);
+
+    assert!(result.is_ok(), "Should apply block successfully");
+
+    let deleted_outpoints = deleted.lock().unwrap();
+    assert_eq!(
+        *deleted_outpoints,
+        vec![confirmed_outpoint],
+        "Should only delete the confirmed UTXO"
+    );
+
+    let inserted_utxos = inserted.lock().unwrap();
+    assert_eq!(inserted_utxos.len(), 2, "Should insert 2 UTXOs (1 + 1)");
+    assert!(
+        inserted_utxos
+            .iter()
+            .all(|utxo| utxo.get_outpoint() != &parent_change_outpoint),
+        "Should not insert the UTXO spent within the block"
+    );
+    assert!(
+        inserted_utxos
+            .iter()
+            .any(|utxo| utxo.get_outpoint().get_tx_id() == &child_hash)
+    );
+}
+
+#[test]
+fn test_revert_block_restores_spent_utxos() {
+    let deleted = Arc::new(Mutex::new(Vec::new()));
+    let inserted = Arc::new(Mutex::new(Vec::new()));
+
+    let deleted_clone = deleted.clone();
+    let inserted_clone = inserted.clone();
+
+    let prev_tx_hash = Hash::new([1u8; 32]);
+    let spent_outpoint = TransactionOutPoint::new(prev_tx_hash, 2);
+    let block_undo = BlockUndo::new(vec![create_test_spent_utxo(&spent_outpoint)]);
+
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxo_set_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo
+        .expect_get_block_undo()
+        .returning(move |_, _| Ok(Some(block_undo.clone())));
+    mock_repo
+        .expect_delete_utxo()
+        .returning(move |_, outpoint| {
+            deleted_clone.lock().unwrap().push(outpoint.clone());
+            Ok(())
+        });
+    mock_repo.expect_insert_utxo().returning(move |_, utxo| {
+        inserted_clone.lock().unwrap().push(utxo.clone());
+        Ok(())
+    });
+    mock_repo
+        .expect_delete_block_undo()
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let service = UtxoSetWriterService::new(Arc::new(mock_repo));
+
+    let inputs = vec![create_test_input(spent_outpoint.clone())];
+    let outputs = vec![create_test_output(40), create_test_output(60)];
+    let tx = create_mock_transaction_with_inputs_outputs(inputs, outputs, 1);
+    let tx_hash = tx.get_hash();
+    let block = create_test_block_with_transactions(vec![tx]);
+
+    let result = service.revert_block(&block);
+
+    assert!(result.is_ok(), "Should revert block successfully");
+
+    let deleted_outpoints = deleted.lock().unwrap();
+    assert_eq!(deleted_outpoints.len(), 2, "Should delete created UTXOs");
+    assert!(
+        deleted_outpoints
+            .iter()
+            .all(|outpoint| outpoint.get_tx_id() == &tx_hash)
+    );
+
+    let inserted_utxos = inserted.lock().unwrap();
+    assert_eq!(inserted_utxos.len(), 1, "Should restore spent UTXO");
+    assert_eq!(inserted_utxos[0].get_outpoint(), &spent_outpoint);
+}
+
+#[test]
+fn test_revert_block_missing_undo_data() {
+    let mut mock_repo = MockUtxoRepository::new();
+    mock_repo
+        .expect_get_utxo_set_append_block_unit_of_work()
+        .returning(|| Arc::new(MockUnitOfWork));
+    mock_repo.expect_get_block_undo().returning(|_, _| Ok(None));
+
+    let service = UtxoSetWriterService::new(Arc::new(mock_repo));
+
+    let outputs = vec![create_test_output(100)];
+    let tx = create_mock_transaction_with_inputs_outputs(vec![], outputs, 1);
+    let block = create_test_block_with_transactions(vec![tx]);
+
+    let result = service.revert_block(&block);
+
+    assert!(result.is_err(), "Should fail without undo data");
+}
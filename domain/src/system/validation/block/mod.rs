@@ -2,22 +2,31 @@
 mod tests;
 
 use super::transaction::TransactionValidator;
-use crate::entities::block::{Block, NonValidatedBlock};
+use crate::encode::TryEncode;
+use crate::entities::block::{Block, BlockHeight, NonValidatedBlock};
 use crate::entities::transaction::{
-    NonValidatedTransaction, TransactionOutPoint, TransactionsMerkleRoot,
+    NonValidatedTransaction, TransactionAmount, TransactionOutPoint, TransactionsMerkleRoot, Utxo,
 };
 use crate::system::blockchain::Blockchain;
+use crate::system::difficulty::DifficultyAdjuster;
+use crate::system::monetary::MonetaryPolicy;
 use crate::types::hash::Hash;
+use crate::types::time::DateTime;
 use async_trait::async_trait;
-use common::error::{AppError, BlockValidationError, TransactionValidationError};
+use common::error::{
+    AppError, BlockValidationError, ConsensusValidationError, TransactionValidationError,
+};
 use common::log_blk_info;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Ensures blocks are validated against their structure and the current state of the blockchain.
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub(crate) trait BlockValidator: Send + Sync + std::fmt::Debug {
-    /// Performs full structural and content level validation on a block.
+    /// Performs full structural and content level validation on a block.<br />
+    /// Blocks extending a side branch are only validated against their parent.<br />
+    /// Their contents are validated once the branch gets connected.
     async fn validate_block(&self, block: NonValidatedBlock) -> Result<Block, AppError>;
 
     /// Performs structural validation on a block.<br />
@@ -27,14 +36,20 @@ pub(crate) trait BlockValidator: Send + Sync + std::fmt::Debug {
 
     /// Performs content validation on a block.<br />
     /// These checks <strong>depend on external state</strong>.<br />
-    /// Results may vary based on the current state of the blockchain.
-    async fn validate_block_content(&self, block: &NonValidatedBlock) -> Result<(), AppError>;
+    /// Results may vary based on the current state of the blockchain and the node's clock.<br />
+    /// Returns the fees paid by the block's transactions (in order).
+    async fn validate_block_content(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<Vec<TransactionAmount>, AppError>;
 }
 
 #[derive(Debug)]
 pub(crate) struct DefaultBlockValidator {
     blockchain: Arc<dyn Blockchain>,
     tx_validator: Arc<dyn TransactionValidator>,
+    difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
+    max_future_block_drift_ms: u64,
 }
 
 #[async_trait]
@@ -56,32 +71,48 @@ impl BlockValidator for DefaultBlockValidator {
         }
 
         self.validate_block_structure(&block)?;
-        self.validate_block_content(&block).await?;
+
+        let tip_info = self.blockchain.get_tip_info().await?;
+        let local_tip_hash = tip_info.as_ref().map(|info| &info.0);
+        let block_prev_hash = block.get_prev_block_hash();
+        // Side branch transaction fees are only known once the branch gets connected.
+        let fees = if block_prev_hash.is_some() && block_prev_hash.as_ref() != local_tip_hash {
+            self.validate_block_side_branch(&block, local_tip_hash)
+                .await?;
+            Vec::new()
+        } else {
+            self.validate_block_content(&block).await?
+        };
 
         log_blk_info!(
             "BlockValidator.validate_block(): Successfully validated block ({}) ",
             block_hash
         );
-        let validated_block = Block::_new_validated(block);
+        let validated_block = Block::_new_validated_with_fees(block, fees);
         Ok(validated_block)
     }
 
     fn validate_block_structure(&self, block: &NonValidatedBlock) -> Result<(), AppError> {
-        Self::validate_block_structure_header(block)?;
+        Self::validate_block_structure_hash(block)?;
         Self::validate_block_structure_merkle_root(block)?;
         Self::validate_block_structure_duplicate_transactions(block)?;
         Self::validate_block_structure_non_empty_transactions(block)?;
+        Self::validate_block_structure_limits(block)?;
         Self::validate_block_structure_coinbase(block)?;
         Ok(())
     }
 
-    async fn validate_block_content(&self, block: &NonValidatedBlock) -> Result<(), AppError> {
+    async fn validate_block_content(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<Vec<TransactionAmount>, AppError> {
         let tip_info = self.blockchain.get_tip_info().await?;
         self.validate_block_content_parent(block, tip_info.as_ref().map(|info| &info.0))?;
-        self.validate_block_content_consensus(block)?;
+        self.validate_block_content_future_drift(block, &DateTime::now())?;
+        self.validate_block_content_timestamp(block).await?;
+        self.validate_block_content_consensus(block).await?;
         self.validate_block_content_transactions(block, tip_info.is_none())
-            .await?;
-        Ok(())
+            .await
     }
 }
 
@@ -89,21 +120,41 @@ impl DefaultBlockValidator {
     pub(crate) fn new(
         blockchain: Arc<dyn Blockchain>,
         tx_validator: Arc<dyn TransactionValidator>,
+        difficulty_adjuster: Arc<dyn DifficultyAdjuster>,
+        max_future_block_drift_secs: u64,
     ) -> Self {
         Self {
             blockchain,
             tx_validator,
+            difficulty_adjuster,
+            max_future_block_drift_ms: max_future_block_drift_secs * 1000,
         }
     }
 
-    #[allow(unused)]
-    fn validate_block_structure_header(block: &NonValidatedBlock) -> Result<(), AppError> {
-        // TODO:
-        // Is the timestamp a sane value (not too far in the future/past)?
+    /// Number of preceding blocks whose median timestamp a block must exceed.
+    const MEDIAN_TIME_PAST_WINDOW: u64 = 11;
+
+    fn validate_block_structure_hash(block: &NonValidatedBlock) -> Result<(), AppError> {
+        let declared_hash = block.get_hash();
+        let computed_hash = block.compute_hash()?;
+
+        if declared_hash != computed_hash {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::InvalidBlockHash {
+                    expected: declared_hash.to_string(),
+                    actual: computed_hash.to_string(),
+                },
+            ));
+        }
         Ok(())
     }
 
     fn validate_block_structure_merkle_root(block: &NonValidatedBlock) -> Result<(), AppError> {
+        // The merkle root commits to declared transaction ids, which must match their contents.
+        for tx in block.get_transactions() {
+            tx.validate_hash()?;
+        }
+
         let expected_root = block.get_transactions_merkle_root();
         let computed_root = TransactionsMerkleRoot::new_non_validated(block.get_transactions())?;
 
@@ -115,6 +166,19 @@ impl DefaultBlockValidator {
                 },
             ));
         }
+
+        // Transaction ids exclude signatures, which are committed to separately.
+        let expected_witness_root = block.get_witness_merkle_root();
+        let computed_witness_root =
+            TransactionsMerkleRoot::new_witness_non_validated(block.get_transactions())?;
+        if expected_witness_root != &computed_witness_root {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::InvalidWitnessMerkleRoot {
+                    expected: expected_witness_root.inner().to_string(),
+                    actual: computed_witness_root.inner().to_string(),
+                },
+            ));
+        }
         Ok(())
     }
 
@@ -146,18 +210,67 @@ impl DefaultBlockValidator {
         Ok(())
     }
 
+    fn validate_block_structure_limits(block: &NonValidatedBlock) -> Result<(), AppError> {
+        let tx_count = block.get_transactions().len();
+        if tx_count > Block::MAX_TRANSACTIONS {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::TooManyTransactions {
+                    count: tx_count,
+                    limit: Block::MAX_TRANSACTIONS,
+                },
+            ));
+        }
+
+        let size = block.try_encode()?.len();
+        if size > Block::MAX_ENCODED_SIZE {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::BlockSizeExceeded {
+                    size,
+                    limit: Block::MAX_ENCODED_SIZE,
+                },
+            ));
+        }
+        Ok(())
+    }
+
     fn validate_block_structure_coinbase(block: &NonValidatedBlock) -> Result<(), AppError> {
-        let coinbase_tx = block
+        // Genesis funds are configured, each allocation being its own coinbase-like transaction.
+        if block.is_genesis_block() {
+            return Ok(());
+        }
+
+        let coinbase_tx_indices = block
             .get_transactions()
             .iter()
-            .filter(|tx| tx.is_coinbase_tx())
+            .enumerate()
+            .filter(|(_, tx)| tx.is_coinbase_tx())
+            .map(|(index, _)| index)
             .collect::<Vec<_>>();
-        if coinbase_tx.len() > 1 {
+        if coinbase_tx_indices.len() > 1 {
             return Err(AppError::BlockValidation(
                 BlockValidationError::MultipleCoinbaseTransactions,
             ));
         }
-        // TODO: confirm coinbase value and maturity
+        if coinbase_tx_indices.first().is_some_and(|index| *index != 0) {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::MisplacedCoinbaseTransaction,
+            ));
+        }
+
+        // Coinbase transactions commit to their block's height, keeping their ids unique.
+        // Regular transactions committing to a height are rejected by transaction validation.
+        let block_height = block.get_height();
+        let coinbase_height = coinbase_tx_indices
+            .first()
+            .and_then(|index| block.get_transactions()[*index].get_coinbase_height());
+        if !coinbase_tx_indices.is_empty() && coinbase_height != Some(&block_height) {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::InvalidCoinbaseHeight {
+                    expected: block_height.as_u64(),
+                    actual: coinbase_height.map(|height| height.as_u64()),
+                },
+            ));
+        }
         Ok(())
     }
 
@@ -177,11 +290,139 @@ impl DefaultBlockValidator {
         Ok(())
     }
 
-    #[allow(unused)]
-    fn validate_block_content_consensus(&self, block: &NonValidatedBlock) -> Result<(), AppError> {
-        // TODO:
-        // Proof of Work / Proof of Stake / Quorum is valid
-        // Correct difficulty level
+    async fn validate_block_side_branch(
+        &self,
+        block: &NonValidatedBlock,
+        local_tip_hash: Option<&Hash>,
+    ) -> Result<(), AppError> {
+        let parent = match block.get_prev_block_hash() {
+            Some(prev_hash) => self.blockchain.get_known_block(&prev_hash).await?,
+            None => None,
+        };
+        let Some(parent) = parent else {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::ContinuityMismatch {
+                    block_prev_hash: block.get_prev_block_hash().map(|h| h.to_string()),
+                    blockchain_tip_hash: local_tip_hash.map(|h| h.to_string()),
+                },
+            ));
+        };
+
+        let expected_height = parent.get_height().next();
+        if block.get_height() != expected_height {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::InvalidBlockHeight {
+                    expected: expected_height.as_u64(),
+                    actual: block.get_height().as_u64(),
+                },
+            ));
+        }
+
+        // Branch blocks are only fully validated once connected, yet they're stored right away.
+        self.validate_block_content_future_drift(block, &DateTime::now())?;
+
+        // The expected difficulty depends on the branch's own history.
+        // It's checked against the canon chain once the branch gets connected.
+        if !block.get_difficulty_target().is_met_by(&block.get_hash()) {
+            return Err(AppError::ConsensusValidation(
+                ConsensusValidationError::InsufficientProofOfWork,
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_block_content_future_drift(
+        &self,
+        block: &NonValidatedBlock,
+        now: &DateTime,
+    ) -> Result<(), AppError> {
+        // Genesis is configured, its timestamp predating the node's clock.
+        if block.is_genesis_block() {
+            return Ok(());
+        }
+
+        let timestamp = block.get_timestamp().to_ms();
+        let max_timestamp = now.to_ms().saturating_add(self.max_future_block_drift_ms);
+        if timestamp > max_timestamp {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::FutureTimestamp {
+                    timestamp,
+                    max_timestamp,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    async fn validate_block_content_timestamp(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<(), AppError> {
+        if block.is_genesis_block() {
+            return Ok(());
+        }
+
+        let last_height = block.get_height().as_u64() - 1;
+        let first_height = (last_height + 1).saturating_sub(Self::MEDIAN_TIME_PAST_WINDOW);
+        let prev_blocks = self
+            .blockchain
+            .get_canon_blocks_by_height_range(
+                BlockHeight::from(first_height)..=BlockHeight::from(last_height),
+            )
+            .await?;
+        let Some(median_time_past) = Self::compute_median_time_past(&prev_blocks) else {
+            return Ok(());
+        };
+
+        let timestamp = block.get_timestamp().to_ms();
+        if timestamp <= median_time_past {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::InvalidTimestamp {
+                    reason: format!(
+                        "Block timestamp ({}) does not exceed the median time past ({})",
+                        timestamp, median_time_past
+                    ),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn compute_median_time_past(blocks: &[Block]) -> Option<u64> {
+        let mut timestamps = blocks
+            .iter()
+            .map(|block| block.get_timestamp().to_ms())
+            .collect::<Vec<_>>();
+        timestamps.sort_unstable();
+        timestamps.get(timestamps.len() / 2).copied()
+    }
+
+    async fn validate_block_content_consensus(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<(), AppError> {
+        // Genesis is configured, not mined.
+        if block.is_genesis_block() {
+            return Ok(());
+        }
+
+        // Hash integrity is covered by structural validation.
+        let difficulty_target = block.get_difficulty_target();
+        if !difficulty_target.is_met_by(&block.get_hash()) {
+            return Err(AppError::ConsensusValidation(
+                ConsensusValidationError::InsufficientProofOfWork,
+            ));
+        }
+
+        let expected_target = self
+            .difficulty_adjuster
+            .get_difficulty_target(&block.get_height())
+            .await?;
+        if difficulty_target != expected_target {
+            return Err(AppError::ConsensusValidation(
+                ConsensusValidationError::InvalidDifficultyAdjustment,
+            ));
+        }
         Ok(())
     }
 
@@ -189,10 +430,10 @@ impl DefaultBlockValidator {
         &self,
         block: &NonValidatedBlock,
         pre_genesis_chain: bool,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<TransactionAmount>, AppError> {
         if block.is_genesis_block() {
             if pre_genesis_chain {
-                return Ok(());
+                return Ok(Vec::new());
             }
             return Err(AppError::BlockValidation(
                 BlockValidationError::GenesisAlreadyExists,
@@ -201,10 +442,77 @@ impl DefaultBlockValidator {
 
         // Rolling with readability over performance optimizations...
         let mut spent_outpoints = HashSet::new();
+        // Outputs created by earlier transactions in the block may be spent by later ones.
+        let mut block_utxos = HashMap::new();
+        let mut tx_fees = Vec::with_capacity(block.get_transactions().len());
+        let mut total_fees = TransactionAmount::new(0);
         for tx in block.get_transactions() {
-            self.tx_validator.validate_transaction(tx.clone()).await?;
+            // Coinbase transactions feature no inputs.
+            // The individual transaction rules don't apply to them.
+            let fee = if tx.is_coinbase_tx() {
+                TransactionAmount::new(0)
+            } else {
+                let pending_utxos = tx
+                    .get_inputs()
+                    .iter()
+                    .filter_map(|txin| block_utxos.get(txin.get_previous_output()))
+                    .cloned()
+                    .collect();
+                self.tx_validator
+                    .validate_transaction(tx.clone(), &block.get_height(), pending_utxos)
+                    .await?
+                    .get_fee()
+            };
             self.validate_block_content_transactions_double_spends(tx, &mut spent_outpoints)?;
-            // TODO: signature verification
+            total_fees.checked_add_assign(fee)?;
+            tx_fees.push(fee);
+
+            let tx_hash = tx.get_hash();
+            for (output_index, txout) in tx.get_outputs().iter().enumerate() {
+                let outpoint = TransactionOutPoint::new(tx_hash.clone(), output_index);
+                let utxo = Utxo::new(
+                    outpoint.clone(),
+                    txout.clone(),
+                    block.get_height(),
+                    tx.is_coinbase_tx(),
+                );
+                block_utxos.insert(outpoint, utxo);
+            }
+        }
+        Self::validate_block_content_coinbase_value(block, total_fees)?;
+        Ok(tx_fees)
+    }
+
+    fn validate_block_content_coinbase_value(
+        block: &NonValidatedBlock,
+        fees: TransactionAmount,
+    ) -> Result<(), AppError> {
+        let Some(coinbase_tx) = block
+            .get_transactions()
+            .first()
+            .filter(|tx| tx.is_coinbase_tx())
+        else {
+            return Ok(());
+        };
+
+        let mut outputs = TransactionAmount::new(0);
+        for txout in coinbase_tx.get_outputs() {
+            outputs.checked_add_assign(txout.get_amount())?;
+        }
+        let subsidy = MonetaryPolicy::get_block_subsidy(&block.get_height());
+        let limit = subsidy.checked_add(fees).ok_or(AppError::internal(format!(
+            "Overflow in TransactionAmount: {} + {}",
+            subsidy.as_u128(),
+            fees.as_u128()
+        )))?;
+
+        if outputs > limit {
+            return Err(AppError::BlockValidation(
+                BlockValidationError::ExcessiveCoinbaseValue {
+                    outputs: outputs.as_u128(),
+                    limit: limit.as_u128(),
+                },
+            ));
         }
         Ok(())
     }
@@ -232,12 +540,19 @@ impl DefaultBlockValidator {
 /// Expose internal methods for unit testing.
 #[cfg(test)]
 impl DefaultBlockValidator {
-    #[allow(unused)]
-    pub(crate) fn pub_validate_block_structure_header(
+    pub(crate) fn pub_validate_block_content_future_drift(
         &self,
         block: &NonValidatedBlock,
+        now: &DateTime,
     ) -> Result<(), AppError> {
-        Self::validate_block_structure_header(block)
+        self.validate_block_content_future_drift(block, now)
+    }
+
+    pub(crate) fn pub_validate_block_structure_hash(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<(), AppError> {
+        Self::validate_block_structure_hash(block)
     }
 
     pub(crate) fn pub_validate_block_structure_merkle_root(
@@ -261,6 +576,13 @@ impl DefaultBlockValidator {
         Self::validate_block_structure_non_empty_transactions(block)
     }
 
+    pub(crate) fn pub_validate_block_structure_limits(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<(), AppError> {
+        Self::validate_block_structure_limits(block)
+    }
+
     pub(crate) fn pub_validate_block_structure_coinbase(
         &self,
         block: &NonValidatedBlock,
@@ -276,23 +598,49 @@ impl DefaultBlockValidator {
         self.validate_block_content_parent(block, local_tip_hash)
     }
 
-    #[allow(unused)]
-    pub(crate) fn pub_validate_block_content_consensus(
+    pub(crate) async fn pub_validate_block_content_timestamp(
         &self,
         block: &NonValidatedBlock,
     ) -> Result<(), AppError> {
-        self.validate_block_content_consensus(block)
+        self.validate_block_content_timestamp(block).await
+    }
+
+    pub(crate) async fn pub_validate_block_side_branch(
+        &self,
+        block: &NonValidatedBlock,
+        local_tip_hash: Option<&Hash>,
+    ) -> Result<(), AppError> {
+        self.validate_block_side_branch(block, local_tip_hash).await
+    }
+
+    pub(crate) fn pub_compute_median_time_past(blocks: &[Block]) -> Option<u64> {
+        Self::compute_median_time_past(blocks)
+    }
+
+    pub(crate) async fn pub_validate_block_content_consensus(
+        &self,
+        block: &NonValidatedBlock,
+    ) -> Result<(), AppError> {
+        self.validate_block_content_consensus(block).await
     }
 
     pub(crate) async fn pub_validate_block_content_transactions(
         &self,
         block: &NonValidatedBlock,
         pre_genesis_chain: bool,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<TransactionAmount>, AppError> {
         self.validate_block_content_transactions(block, pre_genesis_chain)
             .await
     }
 
+    pub(crate) fn pub_validate_block_content_coinbase_value(
+        &self,
+        block: &NonValidatedBlock,
+        fees: TransactionAmount,
+    ) -> Result<(), AppError> {
+        Self::validate_block_content_coinbase_value(block, fees)
+    }
+
     pub(crate) fn pub_validate_block_content_transactions_double_spends(
         &self,
         tx: &NonValidatedTransaction,
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod tests;
 
+use crate::entities::block::BlockHeight;
 use crate::entities::transaction::{NonValidatedTransaction, Transaction, TransactionAmount, Utxo};
-use crate::system::utxo::UtxoSetReader;
+use crate::system::utxo::{PendingUtxoView, UtxoSetReader};
+use crate::types::wallet::WalletAddress;
 use async_trait::async_trait;
 use common::error::{AppError, TransactionValidationError};
+use std::collections::HashSet;
 use std::sync::Arc;
 
 // Note: This could technically be split into offline/online validators.
@@ -14,15 +17,20 @@ use std::sync::Arc;
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub(crate) trait TransactionValidator: Send + Sync + std::fmt::Debug {
+    /// Validates a transaction to be included in a block at the given height.<br />
+    /// Its inputs may spend the given pending (unconfirmed) UTXOs on top of the confirmed UTXO set.
     async fn validate_transaction(
         &self,
         tx: NonValidatedTransaction,
+        block_height: &BlockHeight,
+        pending_utxos: Vec<Utxo>,
     ) -> Result<Transaction, AppError>;
 }
 
 #[derive(Debug)]
 pub(crate) struct DefaultTransactionValidator {
     utxo_set_r: Arc<dyn UtxoSetReader>,
+    coinbase_maturity: u64,
 }
 
 #[async_trait]
@@ -30,25 +38,33 @@ impl TransactionValidator for DefaultTransactionValidator {
     async fn validate_transaction(
         &self,
         tx: NonValidatedTransaction,
+        block_height: &BlockHeight,
+        pending_utxos: Vec<Utxo>,
     ) -> Result<Transaction, AppError> {
+        let utxo_view = PendingUtxoView::new(self.utxo_set_r.clone(), pending_utxos);
         self.validate_structure(&tx)?;
-        self.validate_signatures(&tx)?;
 
-        let input_utxos = self.validate_inputs_unspent(&tx).await?;
+        let input_utxos = self.validate_inputs_unspent(&tx, &utxo_view).await?;
+        self.validate_signatures(&tx, &input_utxos)?;
+        self.validate_inputs_mature(&tx, &input_utxos, block_height)?;
         // self.validate_input_values(&tx, &input_utxos)?;
         self.validate_output_values(&tx)?;
-        self.validate_balance(&tx, &input_utxos)?;
+        let fee = self.validate_balance(&tx, &input_utxos)?;
 
-        Ok(Transaction::_new_validated(tx))
+        Ok(Transaction::_new_validated(tx, fee))
     }
 }
 
 impl DefaultTransactionValidator {
-    pub fn new(utxo_set_r: Arc<dyn UtxoSetReader>) -> Self {
-        Self { utxo_set_r }
+    pub fn new(utxo_set_r: Arc<dyn UtxoSetReader>, coinbase_maturity: u64) -> Self {
+        Self {
+            utxo_set_r,
+            coinbase_maturity,
+        }
     }
 
     fn validate_structure(&self, tx: &NonValidatedTransaction) -> Result<(), AppError> {
+        tx.validate_hash()?;
         if tx.get_inputs().is_empty() {
             return Err(AppError::TransactionValidation(
                 TransactionValidationError::EmptyInputs {
@@ -63,24 +79,74 @@ impl DefaultTransactionValidator {
                 },
             ));
         }
+        if tx.get_coinbase_height().is_some() {
+            return Err(AppError::TransactionValidation(
+                TransactionValidationError::UnexpectedCoinbaseHeight {
+                    tx_id: tx.get_hash().to_string(),
+                },
+            ));
+        }
+        let mut outpoints = HashSet::with_capacity(tx.get_inputs().len());
+        for txin in tx.get_inputs() {
+            let outpoint = txin.get_previous_output();
+            if !outpoints.insert(outpoint) {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::DuplicateInput {
+                        tx_id: tx.get_hash().to_string(),
+                        outpoint: outpoint.to_string(),
+                    },
+                ));
+            }
+        }
         Ok(())
     }
 
-    #[allow(unused)]
-    fn validate_signatures(&self, tx: &NonValidatedTransaction) -> Result<(), AppError> {
-        // Verify all input signatures using public keys and prevout scripts
-        // TODO
+    /// Inputs must be signed by the owner of the output they spend.<br />
+    /// Each signature commits to the parts of the transaction selected by its sighash type.
+    fn validate_signatures(
+        &self,
+        tx: &NonValidatedTransaction,
+        fetched_input_utxos: &[Utxo],
+    ) -> Result<(), AppError> {
+        for (index, (txin, utxo)) in tx.get_inputs().iter().zip(fetched_input_utxos).enumerate() {
+            let public_key = txin.get_public_key();
+            if WalletAddress::from(public_key) != *utxo.get_output().get_recipient() {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::PublicKeyMismatch {
+                        tx_id: tx.get_hash().to_string(),
+                        index,
+                    },
+                ));
+            }
+
+            let sighash = tx.compute_sighash(index)?;
+            let is_signed = match (sighash, txin.get_signature()) {
+                (Some(sighash), Some(signature)) => {
+                    public_key.verify(sighash.as_bytes(), signature)
+                }
+                _ => false,
+            };
+            if !is_signed {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::InvalidSignature {
+                        tx_id: tx.get_hash().to_string(),
+                        index,
+                    },
+                ));
+            }
+        }
         Ok(())
     }
 
     async fn validate_inputs_unspent(
         &self,
         tx: &NonValidatedTransaction,
+        utxo_set_r: &dyn UtxoSetReader,
     ) -> Result<Vec<Utxo>, AppError> {
         let mut utxos = Vec::new();
         for txin in tx.get_inputs() {
             let outpoint = txin.get_previous_output();
-            let Some(utxo) = self.utxo_set_r.get_utxo(outpoint)? else {
+            let Some(utxo) = utxo_set_r.get_utxo(outpoint)? else {
                 return Err(AppError::TransactionValidation(
                     TransactionValidationError::InputUtxoNotFound {
                         tx_id: tx.get_hash().to_string(),
@@ -93,6 +159,34 @@ impl DefaultTransactionValidator {
         Ok(utxos)
     }
 
+    /// Coinbase outputs may only be spent once buried under enough blocks.
+    fn validate_inputs_mature(
+        &self,
+        tx: &NonValidatedTransaction,
+        fetched_input_utxos: &[Utxo],
+        block_height: &BlockHeight,
+    ) -> Result<(), AppError> {
+        for utxo in fetched_input_utxos {
+            if !utxo.is_coinbase() {
+                continue;
+            }
+            let spendable_height = utxo
+                .get_block_height()
+                .as_u64()
+                .saturating_add(self.coinbase_maturity);
+            if block_height.as_u64() < spendable_height {
+                return Err(AppError::TransactionValidation(
+                    TransactionValidationError::ImmatureCoinbaseSpend {
+                        tx_id: tx.get_hash().to_string(),
+                        outpoint: utxo.get_outpoint().to_string(),
+                        spendable_height,
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn validate_output_values(&self, tx: &NonValidatedTransaction) -> Result<(), AppError> {
         for (index, txout) in tx.get_outputs().iter().enumerate() {
             if txout.get_amount() <= TransactionAmount::new(0) {
@@ -107,33 +201,14 @@ impl DefaultTransactionValidator {
         Ok(())
     }
 
+    /// Inputs must cover the outputs.<br />
+    /// Returns the remainder, which is paid out as the transaction fee.
     fn validate_balance(
         &self,
         tx: &NonValidatedTransaction,
         fetched_input_utxos: &[Utxo],
-    ) -> Result<(), AppError> {
-        let mut input_sum = TransactionAmount::new(0);
-        let mut output_sum = TransactionAmount::new(0);
-
-        for utxo in fetched_input_utxos {
-            input_sum.checked_add_assign(utxo.get_output().get_amount())?;
-        }
-
-        for txout in tx.get_outputs() {
-            output_sum.checked_add_assign(txout.get_amount())?;
-        }
-
-        if input_sum < output_sum {
-            return Err(AppError::TransactionValidation(
-                TransactionValidationError::OutputsExceedInputs {
-                    tx_id: tx.get_hash().to_string(),
-                    inputs: input_sum.as_u128(),
-                    outputs: output_sum.as_u128(),
-                },
-            ));
-        }
-
-        Ok(())
+    ) -> Result<TransactionAmount, AppError> {
+        tx.compute_fee(fetched_input_utxos)
     }
 }
 
@@ -147,19 +222,29 @@ impl DefaultTransactionValidator {
         self.validate_structure(tx)
     }
 
-    #[allow(unused)]
     pub(super) fn pub_validate_signatures(
         &self,
         tx: &NonValidatedTransaction,
+        fetched_input_utxos: &[Utxo],
     ) -> Result<(), AppError> {
-        self.validate_signatures(tx)
+        self.validate_signatures(tx, fetched_input_utxos)
     }
 
     pub(super) async fn pub_validate_inputs_unspent(
         &self,
         tx: &NonValidatedTransaction,
     ) -> Result<Vec<Utxo>, AppError> {
-        self.validate_inputs_unspent(tx).await
+        self.validate_inputs_unspent(tx, self.utxo_set_r.as_ref())
+            .await
+    }
+
+    pub(super) fn pub_validate_inputs_mature(
+        &self,
+        tx: &NonValidatedTransaction,
+        fetched_input_utxos: &[Utxo],
+        block_height: &BlockHeight,
+    ) -> Result<(), AppError> {
+        self.validate_inputs_mature(tx, fetched_input_utxos, block_height)
     }
 
     pub(super) fn pub_validate_output_values(
@@ -173,7 +258,7 @@ impl DefaultTransactionValidator {
         &self,
         tx: &NonValidatedTransaction,
         fetched_input_utxos: &[Utxo],
-    ) -> Result<(), AppError> {
+    ) -> Result<TransactionAmount, AppError> {
         self.validate_balance(tx, fetched_input_utxos)
     }
 }
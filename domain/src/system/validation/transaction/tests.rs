@@ -1,20 +1,30 @@
 use super::*;
+use crate::encode::TryEncode;
 use crate::entities::transaction::{
-    NonValidatedTransaction, TransactionAmount, TransactionInput, TransactionOutPoint,
+    NonValidatedTransaction, SighashType, TransactionAmount, TransactionInput, TransactionOutPoint,
     TransactionOutput, Utxo,
 };
 use crate::system::utxo::MockUtxoSetReader;
 use crate::types::hash::Hash;
+use crate::types::sign::{PrivateKey, PublicKey};
 use crate::types::time::DateTime;
 use crate::types::wallet::WalletAddress;
 use common::error::TransactionValidationError;
 use std::str::FromStr;
 
+const TEST_COINBASE_MATURITY: u64 = 100;
+
 fn create_test_wallet_address(seed: u8) -> WalletAddress {
     let hex_str = format!("{:0>64}", seed);
     WalletAddress::from_str(&hex_str).expect("Valid wallet address")
 }
 
+fn create_test_public_key() -> PublicKey {
+    "59f783b83cf3b6552f53044743ac3454a84ed9b47897ef1576e64662363dbd6b"
+        .parse::<PublicKey>()
+        .expect("Valid public key")
+}
+
 fn create_test_hash(seed: u8) -> Hash {
     let mut bytes = [0u8; 32];
     bytes[0] = seed;
@@ -31,12 +41,45 @@ fn create_test_utxo(seed: u8, amount: u128) -> Utxo {
         create_test_wallet_address(seed),
         TransactionAmount::new(amount),
     );
-    Utxo::new(outpoint, output)
+    Utxo::new(outpoint, output, BlockHeight::from(1), false)
+}
+
+fn create_test_coinbase_utxo(seed: u8, amount: u128, block_height: u64) -> Utxo {
+    let outpoint = create_test_outpoint(seed, 0);
+    let output = TransactionOutput::new(
+        create_test_wallet_address(seed),
+        TransactionAmount::new(amount),
+    );
+    Utxo::new(outpoint, output, BlockHeight::from(block_height), true)
+}
+
+fn create_test_private_key(seed: u8) -> PrivateKey {
+    let hex_str = format!("{:0>64}", seed);
+    PrivateKey::from_str(&hex_str).expect("Valid private key")
+}
+
+fn create_test_owned_utxo(private_key: &PrivateKey, seed: u8, amount: u128) -> Utxo {
+    let outpoint = create_test_outpoint(seed, 0);
+    let recipient = WalletAddress::from(&private_key.get_public_key());
+    let output = TransactionOutput::new(recipient, TransactionAmount::new(amount));
+    Utxo::new(outpoint, output, BlockHeight::from(1), false)
+}
+
+fn create_test_signed_tx(private_key: &PrivateKey, outpoint_seed: u8) -> NonValidatedTransaction {
+    let input = TransactionInput::new(
+        create_test_outpoint(outpoint_seed, 0),
+        private_key.get_public_key(),
+    );
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
+        .unwrap()
+        .sign(private_key)
+        .unwrap()
 }
 
 fn create_mock_validator() -> DefaultTransactionValidator {
     let mock_utxo_reader = MockUtxoSetReader::new();
-    DefaultTransactionValidator::new(Arc::new(mock_utxo_reader))
+    DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY)
 }
 
 // ============================================================================
@@ -64,7 +107,7 @@ fn test_validate_structure_empty_inputs() {
 fn test_validate_structure_empty_outputs() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let tx =
         NonValidatedTransaction::new(vec![input], vec![], DateTime::from_ms(1000000000)).unwrap();
 
@@ -81,7 +124,7 @@ fn test_validate_structure_empty_outputs() {
 fn test_validate_structure_valid() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
         .unwrap();
@@ -94,8 +137,8 @@ fn test_validate_structure_valid() {
 fn test_validate_structure_multiple_inputs_and_outputs() {
     let validator = create_mock_validator();
 
-    let input1 = TransactionInput::new(create_test_outpoint(1, 0));
-    let input2 = TransactionInput::new(create_test_outpoint(2, 0));
+    let input1 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let input2 = TransactionInput::new(create_test_outpoint(2, 0), create_test_public_key());
     let output1 =
         TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(100));
     let output2 =
@@ -114,6 +157,57 @@ fn test_validate_structure_multiple_inputs_and_outputs() {
     );
 }
 
+#[test]
+fn test_validate_structure_duplicate_inputs() {
+    let validator = create_mock_validator();
+
+    let input1 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let input2 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    let tx = NonValidatedTransaction::new(
+        vec![input1, input2],
+        vec![output],
+        DateTime::from_ms(1000000000),
+    )
+    .unwrap();
+
+    let result = validator.pub_validate_structure(&tx);
+    assert!(
+        result.is_err(),
+        "Should fail when an outpoint is spent twice"
+    );
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::DuplicateInput { .. }) => {}
+        _ => panic!("Expected DuplicateInput error"),
+    }
+}
+
+#[test]
+fn test_validate_structure_invalid_transaction_id() {
+    let validator = create_mock_validator();
+
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
+        .unwrap();
+
+    // Peers may declare any id, which leads the encoded transaction
+    let mut data = tx.try_encode().unwrap();
+    data[..32].copy_from_slice(create_test_hash(9).as_bytes());
+    let (tx, _): (NonValidatedTransaction, usize) =
+        bincode::decode_from_slice(&data, bincode::config::standard()).unwrap();
+    assert_eq!(tx.get_hash(), create_test_hash(9));
+
+    let result = validator.pub_validate_structure(&tx);
+    match result {
+        Err(AppError::TransactionValidation(
+            TransactionValidationError::InvalidTransactionId { .. },
+        )) => {}
+        _ => panic!("Expected InvalidTransactionId error"),
+    }
+}
+
 // ============================================================================
 // validate_output_values()
 // ============================================================================
@@ -122,7 +216,7 @@ fn test_validate_structure_multiple_inputs_and_outputs() {
 fn test_validate_output_values_zero_amount() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(0));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
         .unwrap();
@@ -145,7 +239,7 @@ fn test_validate_output_values_zero_amount() {
 fn test_validate_output_values_all_valid() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output1 =
         TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
     let output2 =
@@ -167,7 +261,7 @@ fn test_validate_output_values_all_valid() {
 fn test_validate_output_values_one_zero_among_multiple() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output1 =
         TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
     let output2 = TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(0));
@@ -198,7 +292,7 @@ fn test_validate_output_values_one_zero_among_multiple() {
 fn test_validate_output_values_single_valid_output() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(1));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
         .unwrap();
@@ -218,7 +312,7 @@ fn test_validate_output_values_single_valid_output() {
 fn test_validate_balance_outputs_exceed_inputs() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output =
         TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(1500));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
@@ -247,7 +341,7 @@ fn test_validate_balance_outputs_exceed_inputs() {
 fn test_validate_balance_exact_match() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output =
         TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(1000));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
@@ -257,13 +351,14 @@ fn test_validate_balance_exact_match() {
 
     let result = validator.pub_validate_balance(&tx, &input_utxos);
     assert!(result.is_ok(), "Exact balance (no fee) should pass");
+    assert_eq!(result.unwrap(), TransactionAmount::new(0));
 }
 
 #[test]
 fn test_validate_balance_with_fee() {
     let validator = create_mock_validator();
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(900));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
         .unwrap();
@@ -275,14 +370,15 @@ fn test_validate_balance_with_fee() {
         result.is_ok(),
         "Inputs greater than outputs (with fee) should pass"
     );
+    assert_eq!(result.unwrap(), TransactionAmount::new(100));
 }
 
 #[test]
 fn test_validate_balance_multiple_inputs_and_outputs() {
     let validator = create_mock_validator();
 
-    let input1 = TransactionInput::new(create_test_outpoint(1, 0));
-    let input2 = TransactionInput::new(create_test_outpoint(2, 0));
+    let input1 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let input2 = TransactionInput::new(create_test_outpoint(2, 0), create_test_public_key());
     let output1 =
         TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(400));
     let output2 =
@@ -308,8 +404,8 @@ fn test_validate_balance_multiple_inputs_and_outputs() {
 fn test_validate_balance_multiple_inputs_insufficient() {
     let validator = create_mock_validator();
 
-    let input1 = TransactionInput::new(create_test_outpoint(1, 0));
-    let input2 = TransactionInput::new(create_test_outpoint(2, 0));
+    let input1 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let input2 = TransactionInput::new(create_test_outpoint(2, 0), create_test_public_key());
     let output =
         TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(1000));
     let tx = NonValidatedTransaction::new(
@@ -338,6 +434,249 @@ fn test_validate_balance_multiple_inputs_insufficient() {
     }
 }
 
+// ============================================================================
+// validate_signatures()
+// ============================================================================
+
+#[test]
+fn test_validate_signatures_valid() {
+    let validator = create_mock_validator();
+    let private_key = create_test_private_key(1);
+    let tx = create_test_signed_tx(&private_key, 1);
+
+    let input_utxos = vec![create_test_owned_utxo(&private_key, 1, 1000)];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    assert!(result.is_ok(), "Owner-signed inputs should pass");
+}
+
+#[test]
+fn test_validate_signatures_missing_signature() {
+    let validator = create_mock_validator();
+    let private_key = create_test_private_key(1);
+    let input = TransactionInput::new(create_test_outpoint(1, 0), private_key.get_public_key());
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
+        .unwrap();
+
+    let input_utxos = vec![create_test_owned_utxo(&private_key, 1, 1000)];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    assert!(result.is_err(), "Unsigned inputs should fail");
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::InvalidSignature {
+            index,
+            ..
+        }) => {
+            assert_eq!(index, 0);
+        }
+        _ => panic!("Expected InvalidSignature error"),
+    }
+}
+
+#[test]
+fn test_validate_signatures_tampered_outputs() {
+    let validator = create_mock_validator();
+    let private_key = create_test_private_key(1);
+    let signed_tx = create_test_signed_tx(&private_key, 1);
+
+    // Redirect the funds, reusing the original signature.
+    let output = TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(100));
+    let tx = NonValidatedTransaction::new(
+        signed_tx.get_inputs().clone(),
+        vec![output],
+        signed_tx.get_timestamp(),
+    )
+    .unwrap();
+
+    let input_utxos = vec![create_test_owned_utxo(&private_key, 1, 1000)];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    assert!(result.is_err(), "Signatures over other outputs should fail");
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::InvalidSignature {
+            ..
+        }) => {}
+        _ => panic!("Expected InvalidSignature error"),
+    }
+}
+
+#[test]
+fn test_validate_signatures_public_key_mismatch() {
+    let validator = create_mock_validator();
+    let owner_key = create_test_private_key(1);
+    let spender_key = create_test_private_key(2);
+    let tx = create_test_signed_tx(&spender_key, 1);
+
+    // Validly signed, but by someone other than the output's owner.
+    let input_utxos = vec![create_test_owned_utxo(&owner_key, 1, 1000)];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    assert!(
+        result.is_err(),
+        "Spending someone else's output should fail"
+    );
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::PublicKeyMismatch {
+            index,
+            ..
+        }) => {
+            assert_eq!(index, 0);
+        }
+        _ => panic!("Expected PublicKeyMismatch error"),
+    }
+}
+
+#[test]
+fn test_validate_signatures_txid_excludes_signatures() {
+    let private_key = create_test_private_key(1);
+    let input = TransactionInput::new(create_test_outpoint(1, 0), private_key.get_public_key());
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    let unsigned_tx =
+        NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
+            .unwrap();
+    let unsigned_witness_hash = unsigned_tx.compute_witness_hash().unwrap();
+
+    let signed_tx = unsigned_tx.clone().sign(&private_key).unwrap();
+
+    assert_eq!(signed_tx.get_hash(), unsigned_tx.get_hash());
+    assert_ne!(
+        signed_tx.compute_witness_hash().unwrap(),
+        unsigned_witness_hash
+    );
+}
+
+#[test]
+fn test_validate_signatures_anyone_can_pay_allows_extra_inputs() {
+    let validator = create_mock_validator();
+    let first_key = create_test_private_key(1);
+    let second_key = create_test_private_key(2);
+
+    // The first party funds the output on its own, letting others chip in.
+    let first_input = TransactionInput::new_with_sighash_type(
+        create_test_outpoint(1, 0),
+        first_key.get_public_key(),
+        SighashType::ALL.with_anyone_can_pay(),
+    );
+    let output = TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(100));
+    let partial_tx = NonValidatedTransaction::new(
+        vec![first_input],
+        vec![output.clone()],
+        DateTime::from_ms(1000000000),
+    )
+    .unwrap()
+    .sign(&first_key)
+    .unwrap();
+
+    let second_input =
+        TransactionInput::new(create_test_outpoint(2, 0), second_key.get_public_key());
+    let mut inputs = partial_tx.get_inputs().clone();
+    inputs.push(second_input);
+    let tx = NonValidatedTransaction::new(inputs, vec![output], partial_tx.get_timestamp())
+        .unwrap()
+        .sign(&second_key)
+        .unwrap();
+
+    let input_utxos = vec![
+        create_test_owned_utxo(&first_key, 1, 1000),
+        create_test_owned_utxo(&second_key, 2, 1000),
+    ];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    assert!(
+        result.is_ok(),
+        "ANYONECANPAY signatures should survive added inputs"
+    );
+}
+
+#[test]
+fn test_validate_signatures_none_allows_output_changes() {
+    let validator = create_mock_validator();
+    let private_key = create_test_private_key(1);
+    let input = TransactionInput::new_with_sighash_type(
+        create_test_outpoint(1, 0),
+        private_key.get_public_key(),
+        SighashType::NONE,
+    );
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    let signed_tx =
+        NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
+            .unwrap()
+            .sign(&private_key)
+            .unwrap();
+
+    let output = TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(200));
+    let tx = NonValidatedTransaction::new(
+        signed_tx.get_inputs().clone(),
+        vec![output],
+        signed_tx.get_timestamp(),
+    )
+    .unwrap();
+
+    let input_utxos = vec![create_test_owned_utxo(&private_key, 1, 1000)];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    assert!(
+        result.is_ok(),
+        "NONE signatures should not commit to outputs"
+    );
+}
+
+#[test]
+fn test_validate_signatures_single_without_matching_output() {
+    let validator = create_mock_validator();
+    let private_key = create_test_private_key(1);
+    let first_input = TransactionInput::new_with_sighash_type(
+        create_test_outpoint(1, 0),
+        private_key.get_public_key(),
+        SighashType::ALL.with_anyone_can_pay(),
+    );
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    let signed_tx = NonValidatedTransaction::new(
+        vec![first_input],
+        vec![output],
+        DateTime::from_ms(1000000000),
+    )
+    .unwrap()
+    .sign(&private_key)
+    .unwrap();
+
+    // A SINGLE input at index 1, lacking a matching output.
+    let input = TransactionInput::new_with_sighash_type(
+        create_test_outpoint(2, 0),
+        private_key.get_public_key(),
+        SighashType::SINGLE,
+    );
+    let mut inputs = signed_tx.get_inputs().clone();
+    inputs.push(input);
+    let tx = NonValidatedTransaction::new(
+        inputs,
+        signed_tx.get_outputs().clone(),
+        signed_tx.get_timestamp(),
+    )
+    .unwrap();
+    assert!(tx.clone().sign(&private_key).is_err());
+
+    let input_utxos = vec![
+        create_test_owned_utxo(&private_key, 1, 1000),
+        create_test_owned_utxo(&private_key, 2, 1000),
+    ];
+
+    let result = validator.pub_validate_signatures(&tx, &input_utxos);
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::InvalidSignature {
+            index,
+            ..
+        }) => {
+            assert_eq!(index, 1);
+        }
+        _ => panic!("Expected InvalidSignature error"),
+    }
+}
+
 // ============================================================================
 // validate_inputs_unspent()
 // ============================================================================
@@ -355,9 +694,10 @@ async fn test_validate_inputs_unspent_single_utxo_found() {
         .times(1)
         .returning(move |_| Ok(Some(utxo.clone())));
 
-    let validator = DefaultTransactionValidator::new(Arc::new(mock_utxo_reader));
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
         .unwrap();
@@ -382,9 +722,10 @@ async fn test_validate_inputs_unspent_utxo_not_found() {
         .times(1)
         .returning(|_| Ok(None));
 
-    let validator = DefaultTransactionValidator::new(Arc::new(mock_utxo_reader));
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
 
-    let input = TransactionInput::new(create_test_outpoint(1, 0));
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
     let tx = NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000))
         .unwrap();
@@ -421,10 +762,11 @@ async fn test_validate_inputs_unspent_multiple_utxos_all_found() {
         .times(1)
         .returning(move |_| Ok(Some(utxo2.clone())));
 
-    let validator = DefaultTransactionValidator::new(Arc::new(mock_utxo_reader));
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
 
-    let input1 = TransactionInput::new(create_test_outpoint(1, 0));
-    let input2 = TransactionInput::new(create_test_outpoint(2, 0));
+    let input1 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let input2 = TransactionInput::new(create_test_outpoint(2, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(100));
     let tx = NonValidatedTransaction::new(
         vec![input1, input2],
@@ -462,10 +804,11 @@ async fn test_validate_inputs_unspent_second_utxo_not_found() {
         .times(1)
         .returning(|_| Ok(None));
 
-    let validator = DefaultTransactionValidator::new(Arc::new(mock_utxo_reader));
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
 
-    let input1 = TransactionInput::new(create_test_outpoint(1, 0));
-    let input2 = TransactionInput::new(create_test_outpoint(2, 0));
+    let input1 = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let input2 = TransactionInput::new(create_test_outpoint(2, 0), create_test_public_key());
     let output = TransactionOutput::new(create_test_wallet_address(3), TransactionAmount::new(100));
     let tx = NonValidatedTransaction::new(
         vec![input1, input2],
@@ -484,3 +827,163 @@ async fn test_validate_inputs_unspent_second_utxo_not_found() {
         _ => panic!("Expected InputUtxoNotFound error"),
     }
 }
+
+// ============================================================================
+// validate_inputs_mature()
+// ============================================================================
+
+fn create_test_spending_tx() -> NonValidatedTransaction {
+    let input = TransactionInput::new(create_test_outpoint(1, 0), create_test_public_key());
+    let output = TransactionOutput::new(create_test_wallet_address(2), TransactionAmount::new(100));
+    NonValidatedTransaction::new(vec![input], vec![output], DateTime::from_ms(1000000000)).unwrap()
+}
+
+#[test]
+fn test_validate_inputs_mature_immature_coinbase() {
+    let validator = create_mock_validator();
+    let tx = create_test_spending_tx();
+
+    let input_utxos = vec![create_test_coinbase_utxo(1, 1000, 10)];
+    let block_height = BlockHeight::from(10 + TEST_COINBASE_MATURITY - 1);
+
+    let result = validator.pub_validate_inputs_mature(&tx, &input_utxos, &block_height);
+    assert!(
+        result.is_err(),
+        "Should fail when spending an immature coinbase"
+    );
+
+    match result.unwrap_err() {
+        AppError::TransactionValidation(TransactionValidationError::ImmatureCoinbaseSpend {
+            spendable_height,
+            ..
+        }) => {
+            assert_eq!(spendable_height, 10 + TEST_COINBASE_MATURITY);
+        }
+        _ => panic!("Expected ImmatureCoinbaseSpend error"),
+    }
+}
+
+#[test]
+fn test_validate_inputs_mature_mature_coinbase() {
+    let validator = create_mock_validator();
+    let tx = create_test_spending_tx();
+
+    let input_utxos = vec![create_test_coinbase_utxo(1, 1000, 10)];
+    let block_height = BlockHeight::from(10 + TEST_COINBASE_MATURITY);
+
+    let result = validator.pub_validate_inputs_mature(&tx, &input_utxos, &block_height);
+    assert!(
+        result.is_ok(),
+        "Coinbase at maturity depth should be spendable"
+    );
+}
+
+#[test]
+fn test_validate_inputs_mature_regular_output() {
+    let validator = create_mock_validator();
+    let tx = create_test_spending_tx();
+
+    // Regular outputs are spendable right away.
+    let input_utxos = vec![create_test_utxo(1, 1000)];
+    let block_height = BlockHeight::from(2);
+
+    let result = validator.pub_validate_inputs_mature(&tx, &input_utxos, &block_height);
+    assert!(
+        result.is_ok(),
+        "Non-coinbase outputs should not require maturity"
+    );
+}
+
+#[tokio::test]
+async fn test_validate_transaction_rejects_immature_coinbase() {
+    let private_key = create_test_private_key(1);
+    let recipient = WalletAddress::from(&private_key.get_public_key());
+    let coinbase_utxo = Utxo::new(
+        create_test_outpoint(1, 0),
+        TransactionOutput::new(recipient, TransactionAmount::new(1000)),
+        BlockHeight::from(10),
+        true,
+    );
+
+    let mut mock_utxo_reader = MockUtxoSetReader::new();
+    mock_utxo_reader
+        .expect_get_utxo()
+        .returning(move |_| Ok(Some(coinbase_utxo.clone())));
+
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
+    let tx = create_test_signed_tx(&private_key, 1);
+
+    let result = validator
+        .validate_transaction(tx, &BlockHeight::from(11), Vec::new())
+        .await;
+    assert!(matches!(
+        result,
+        Err(AppError::TransactionValidation(
+            TransactionValidationError::ImmatureCoinbaseSpend { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn test_validate_transaction_computes_fee() {
+    let private_key = create_test_private_key(1);
+    let input_utxo = create_test_owned_utxo(&private_key, 1, 1000);
+
+    let mut mock_utxo_reader = MockUtxoSetReader::new();
+    mock_utxo_reader
+        .expect_get_utxo()
+        .returning(move |_| Ok(Some(input_utxo.clone())));
+
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
+    let tx = create_test_signed_tx(&private_key, 1);
+
+    let tx = validator
+        .validate_transaction(tx, &BlockHeight::from(2), Vec::new())
+        .await
+        .unwrap();
+    assert_eq!(tx.get_fee(), TransactionAmount::new(900));
+}
+
+#[tokio::test]
+async fn test_validate_transaction_spends_pending_utxo() {
+    let private_key = create_test_private_key(1);
+    let pending_utxo = create_test_owned_utxo(&private_key, 1, 1000);
+
+    let mut mock_utxo_reader = MockUtxoSetReader::new();
+    mock_utxo_reader.expect_get_utxo().returning(|_| Ok(None));
+
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
+    let tx = create_test_signed_tx(&private_key, 1);
+
+    let tx = validator
+        .validate_transaction(tx, &BlockHeight::from(2), vec![pending_utxo])
+        .await
+        .unwrap();
+    assert_eq!(tx.get_fee(), TransactionAmount::new(900));
+}
+
+#[tokio::test]
+async fn test_validate_transaction_pending_utxo_missing() {
+    let private_key = create_test_private_key(1);
+    let unrelated_utxo = create_test_owned_utxo(&private_key, 2, 1000);
+
+    let mut mock_utxo_reader = MockUtxoSetReader::new();
+    mock_utxo_reader.expect_get_utxo().returning(|_| Ok(None));
+
+    let validator =
+        DefaultTransactionValidator::new(Arc::new(mock_utxo_reader), TEST_COINBASE_MATURITY);
+    let tx = create_test_signed_tx(&private_key, 1);
+
+    let result = validator
+        .validate_transaction(tx, &BlockHeight::from(2), vec![unrelated_utxo])
+        .await;
+    assert!(matches!(
+        result,
+        Err(AppError::TransactionValidation(
+            TransactionValidationError::InputUtxoNotFound { .. }
+        ))
+    ));
+}
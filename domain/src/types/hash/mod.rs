@@ -7,7 +7,7 @@ use std::fmt;
 
 const BYTES_LENGTH: usize = 32;
 
-#[derive(Clone, Encode, Decode, PartialEq, Eq, std::hash::Hash)]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, std::hash::Hash)]
 pub struct Hash([u8; BYTES_LENGTH]);
 
 impl Hash {
@@ -11,12 +11,14 @@ use uuid::Uuid;
 #[derive(Clone, Debug, Encode, Decode)]
 pub enum OutboxEvent {
     BlockchainAppendBlock(Block),
+    BlockchainReorganize(Block),
 }
 
 impl OutboxEvent {
     pub fn get_event_type(&self) -> &'static str {
         match self {
             OutboxEvent::BlockchainAppendBlock(_) => "BlockchainAppendBlock",
+            OutboxEvent::BlockchainReorganize(_) => "BlockchainReorganize",
         }
     }
 }
@@ -1,5 +1,4 @@
 use crate::types::hash::{Hash, Hashable};
-use bincode::config::Configuration;
 use bincode::de::Decoder;
 use bincode::enc::Encoder;
 use bincode::error::{DecodeError, EncodeError};
@@ -11,17 +10,16 @@ use rand_core::OsRng;
 use std::fmt;
 use std::str::FromStr;
 
-#[allow(unused)]
 const PUBLIC_KEY_LENGTH: usize = 32;
 const SIGNATURE_LENGTH: usize = 64;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Signature(ed25519_dalek::Signature);
 
 #[derive(Clone, Debug)]
 pub struct PrivateKey(ed25519_dalek::SigningKey);
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PublicKey(ed25519_dalek::VerifyingKey);
 
 impl PublicKey {
@@ -29,6 +27,12 @@ impl PublicKey {
     pub(crate) fn as_bytes(&self) -> &[u8; PUBLIC_KEY_LENGTH] {
         self.0.as_bytes()
     }
+
+    /// Verifies a signature over the given message.<br />
+    /// Strict verification rejects malleable signatures and weak keys.
+    pub(crate) fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        self.0.verify_strict(msg, &signature.0).is_ok()
+    }
 }
 
 impl Hashable for PublicKey {
@@ -50,7 +54,6 @@ impl PrivateKey {
         PublicKey(self.0.verifying_key())
     }
 
-    #[allow(unused)]
     pub(crate) fn sign(&self, msg: &[u8]) -> Signature {
         Signature(self.0.sign(msg))
     }
@@ -63,8 +66,8 @@ impl Encode for Signature {
     }
 }
 
-impl Decode<Configuration> for Signature {
-    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+impl<Context> Decode<Context> for Signature {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
         let bytes: [u8; SIGNATURE_LENGTH] = Decode::decode(decoder)?;
         let sig = ed25519_dalek::Signature::from_bytes(&bytes);
         // TODO: no validation yet...
@@ -72,6 +75,24 @@ impl Decode<Configuration> for Signature {
     }
 }
 
+impl Encode for PublicKey {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.to_bytes().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for PublicKey {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let bytes: [u8; PUBLIC_KEY_LENGTH] = Decode::decode(decoder)?;
+        let vk = ed25519_dalek::VerifyingKey::from_bytes(&bytes)
+            .map_err(|err| DecodeError::OtherString(format!("Invalid public key: {err}")))?;
+        Ok(PublicKey(vk))
+    }
+}
+
+bincode::impl_borrow_decode!(Signature);
+bincode::impl_borrow_decode!(PublicKey);
+
 impl fmt::Display for PrivateKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", hex::encode(self.0.to_bytes()))
@@ -84,6 +105,12 @@ impl fmt::Display for PublicKey {
     }
 }
 
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0.to_bytes()))
+    }
+}
+
 impl FromStr for PrivateKey {
     type Err = AppError;
 
@@ -115,3 +142,18 @@ impl FromStr for PublicKey {
         Ok(PublicKey(vk))
     }
 }
+
+impl FromStr for Signature {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s).map_err(|err| {
+            AppError::bad_request_with_private("Couldn't parse signature.", err.to_string())
+        })?;
+        let byte_array: [u8; SIGNATURE_LENGTH] = bytes
+            .try_into()
+            .map_err(|_| AppError::bad_request("Invalid signature length (expected 64 bytes)."))?;
+        let sig = ed25519_dalek::Signature::from_bytes(&byte_array);
+        Ok(Signature(sig))
+    }
+}
@@ -9,6 +9,12 @@ use std::str::FromStr;
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
 pub struct WalletAddress(Hash);
 
+impl WalletAddress {
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
 impl From<&PublicKey> for WalletAddress {
     fn from(pub_key: &PublicKey) -> Self {
         let hash = pub_key.hash();
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use common::error::AppError;
 use common::params::PaginationParams;
-use domain::entities::block::{Block, BlockHeight, BlockTemplate, NonValidatedBlock};
+use domain::entities::block::{
+    Block, BlockDifficultyTarget, BlockHeight, BlockTemplate, NonValidatedBlock,
+};
 use domain::entities::transaction::{
-    NonValidatedTransaction, Transaction, TransactionOutPoint, Utxo,
+    NonValidatedTransaction, Transaction, TransactionAmount, TransactionOutPoint, Utxo,
 };
 use domain::genesis::config::GenesisConfig;
 use domain::system::network::event::{AddPeerResponse, NetworkEvent};
@@ -13,6 +15,7 @@ use domain::system::node::cmd::{
 };
 use domain::types::hash::Hash;
 use domain::types::network::{NetworkAddress, NetworkIdentityKeypair, NetworkPeerId};
+use domain::types::wallet::WalletAddress;
 use std::fmt::Debug;
 use std::future::Future;
 use std::ops::RangeInclusive;
@@ -124,6 +127,18 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
         })
     }
 
+    fn build_blk_cmd_submit_block(
+        &self,
+        block: NonValidatedBlock,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Block, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::SubmitBlock(block, responder))
+        })
+    }
+
     fn build_blk_cmd_handle_block_append(
         &self,
         block: Block,
@@ -136,6 +151,20 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
         })
     }
 
+    fn build_blk_cmd_handle_block_reorganize(
+        &self,
+        block: Block,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::HandleBlockReorganize(
+                block, responder,
+            ))
+        })
+    }
+
     fn build_blk_cmd_get_tip_info(
         &self,
     ) -> (
@@ -147,6 +176,85 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
         })
     }
 
+    fn build_blk_cmd_get_tip_chain_info(
+        &self,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Option<(Hash, BlockHeight, u128)>, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::GetTipChainInfo(responder))
+        })
+    }
+
+    fn build_blk_cmd_get_fork_point(
+        &self,
+        locator: Vec<Hash>,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Option<(BlockHeight, BlockHeight)>, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::GetForkPoint(locator, responder))
+        })
+    }
+
+    fn build_blk_cmd_get_difficulty_target(
+        &self,
+        block_height: BlockHeight,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<BlockDifficultyTarget, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::GetDifficultyTarget(
+                block_height,
+                responder,
+            ))
+        })
+    }
+
+    fn build_blk_cmd_get_block_template(
+        &self,
+        reward_address: WalletAddress,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<BlockTemplate, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::GetBlockTemplate(
+                reward_address,
+                responder,
+            ))
+        })
+    }
+
+    fn build_blk_cmd_start_mining(
+        &self,
+        reward_address: Option<WalletAddress>,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<WalletAddress, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::StartMining(
+                reward_address,
+                responder,
+            ))
+        })
+    }
+
+    fn build_blk_cmd_stop_mining(
+        &self,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Blockchain(BlockchainCommand::StopMining(responder))
+        })
+    }
+
     fn build_blk_cmd_get_block(
         &self,
         block_hash: Hash,
@@ -249,6 +357,30 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
         create_command(|responder| NodeCommandRequest::Utxo(UtxoCommand::GetUtxos(responder)))
     }
 
+    fn build_utxo_cmd_get_utxos_by_address(
+        &self,
+        address: WalletAddress,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<Vec<Utxo>, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Utxo(UtxoCommand::GetUtxosByAddress(address, responder))
+        })
+    }
+
+    fn build_utxo_cmd_get_balance_by_address(
+        &self,
+        address: WalletAddress,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<TransactionAmount, AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::Utxo(UtxoCommand::GetBalanceByAddress(address, responder))
+        })
+    }
+
     // Network commands
     fn build_net_cmd_get_self_info(
         &self,
@@ -291,7 +423,7 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
     fn build_p2p_cmd_receive_blockchain_tip_info(
         &self,
         origin_peer_id: NetworkPeerId,
-        block_info: Option<(Hash, BlockHeight)>,
+        block_info: Option<(Hash, BlockHeight, u128)>,
     ) -> (
         NodeCommandRequest,
         Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
@@ -305,6 +437,23 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
         })
     }
 
+    fn build_p2p_cmd_receive_fork_point(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        fork_point: Option<(BlockHeight, BlockHeight)>,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::P2P(P2PCommand::HandleReceiveForkPoint(
+                origin_peer_id,
+                fork_point,
+                responder,
+            ))
+        })
+    }
+
     fn build_p2p_cmd_receive_blocks(
         &self,
         origin_peer_id: NetworkPeerId,
@@ -322,6 +471,23 @@ impl CommandResponderFactory for NodeCommandResponderFactory {
         })
     }
 
+    fn build_p2p_cmd_receive_transaction(
+        &self,
+        origin_peer_id: NetworkPeerId,
+        tx: NonValidatedTransaction,
+    ) -> (
+        NodeCommandRequest,
+        Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>,
+    ) {
+        create_command(|responder| {
+            NodeCommandRequest::P2P(P2PCommand::HandleReceiveTransaction(
+                origin_peer_id,
+                tx,
+                responder,
+            ))
+        })
+    }
+
     fn build_proxy_cmd_forward_network_event(
         &self,
         event: NetworkEvent,
@@ -154,7 +154,7 @@ impl<T, TErr> AppErrorExtInfrastructure for Result<T, TransportError<TErr>> {
         match self {
             Ok(value) => Ok(value),
             // TODO: remap
-            Err(_) => Err(AppError::internal(format!("libp2p Transport error!"))),
+            Err(_) => Err(AppError::internal("libp2p Transport error!".to_string())),
         }
     }
 }
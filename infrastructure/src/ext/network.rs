@@ -65,11 +65,17 @@ impl TaliroNetworkRequestBuilderExtInfrastructure
             domain::system::network::event::TaliroNetworkData::GetBlockByHeight(height) => {
                 crate::network::protocol::TaliroProtocolRequest::GetBlockByHeight(height.clone())
             }
+            domain::system::network::event::TaliroNetworkData::GetBlockByHash(hash) => {
+                crate::network::protocol::TaliroProtocolRequest::GetBlockByHash(hash.clone())
+            }
             domain::system::network::event::TaliroNetworkData::GetBlocksByHeightRange(range) => {
                 crate::network::protocol::TaliroProtocolRequest::GetBlocksByHeightRange(
                     range.clone(),
                 )
             }
+            domain::system::network::event::TaliroNetworkData::GetForkPoint(locator) => {
+                crate::network::protocol::TaliroProtocolRequest::GetForkPoint(locator.clone())
+            }
         }
     }
 }
@@ -3,6 +3,7 @@ use crate::network::protocol::{BlockchainProtocolExchangeCodec, TaliroProtocol};
 use common::error::AppError;
 use libp2p::request_response::ProtocolSupport;
 use libp2p::{gossipsub, identity, kad, request_response, swarm};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::iter;
 
 #[derive(swarm::NetworkBehaviour)]
@@ -14,10 +15,18 @@ pub(crate) struct AppNetworkBehavior {
 }
 
 impl AppNetworkBehavior {
+    /// Matches the protocol codec's message cap, fitting blocks of the maximum consensus size.
+    const GOSSIPSUB_MAX_TRANSMIT_SIZE: usize = 1_000_000;
+
     pub(super) fn new(keys: &identity::Keypair) -> Result<Self, AppError> {
+        let gossipsub_cfg = gossipsub::ConfigBuilder::default()
+            .max_transmit_size(Self::GOSSIPSUB_MAX_TRANSMIT_SIZE)
+            .message_id_fn(Self::gossipsub_message_id)
+            .build()
+            .map_err(|err| AppError::internal(format!("Gossipsub Error: {}", err)))?;
         let gossipsub = gossipsub::Behaviour::new(
             gossipsub::MessageAuthenticity::Signed(keys.clone()),
-            gossipsub::Config::default(),
+            gossipsub_cfg,
         )
         .map_err(|err| AppError::internal(format!("Gossipsub Error: {}", err)))?;
         let local_id = keys.public().to_peer_id();
@@ -34,6 +43,14 @@ impl AppNetworkBehavior {
         Ok(behavior)
     }
 
+    /// Content-addressed message ids suppress duplicates of the same payload,
+    /// such as a transaction being published by several peers.
+    fn gossipsub_message_id(message: &gossipsub::Message) -> gossipsub::MessageId {
+        let mut hasher = DefaultHasher::new();
+        message.data.hash(&mut hasher);
+        gossipsub::MessageId::from(hasher.finish().to_string())
+    }
+
     pub(super) fn get_gossipsub_mut(&mut self) -> &mut gossipsub::Behaviour {
         &mut self.gossipsub
     }
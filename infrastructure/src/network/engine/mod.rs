@@ -206,6 +206,7 @@ impl Libp2pNetworkEngine {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn handle_incoming_event(
         swarm: &mut Swarm<AppNetworkBehavior>,
         network_repo: &Arc<dyn NetworkRepository>,
@@ -1,5 +1,5 @@
 use super::super::behavior::AppNetworkBehavior;
-use common::{log_net_gs_error, log_net_kad_debug, log_net_kad_trace};
+use common::{log_net_gs_debug, log_net_gs_error, log_net_kad_debug, log_net_kad_trace};
 use domain::types::network::NetworkPeerId;
 use domain::{
     encode::TryDecode,
@@ -66,7 +66,6 @@ async fn handle_gossipsub_message_event(
         propagation_source.to_string(),
     );
 
-    #[allow(clippy::single_match)]
     match event {
         GossipsubNetworkEvent::BroadcastNewBlock(block) => {
             let block = block.invalidate();
@@ -80,5 +79,20 @@ async fn handle_gossipsub_message_event(
                 log_net_gs_error!("HandleNetworkBroadcastNewBlock command failed: {}", err);
             }
         }
+        GossipsubNetworkEvent::BroadcastNewTransaction(tx) => {
+            let tx = tx.invalidate();
+            let (command, res_fut) =
+                cmd_tx_res_factory.build_p2p_cmd_receive_transaction(peer_id, tx);
+            let Ok(_) = cmd_tx.send(command).await else {
+                log_net_gs_error!("Failed to send HandleNetworkBroadcastNewTransaction command.");
+                return;
+            };
+            if let Err(err) = res_fut.await {
+                log_net_gs_debug!(
+                    "HandleNetworkBroadcastNewTransaction command failed: {}",
+                    err
+                );
+            }
+        }
     }
 }
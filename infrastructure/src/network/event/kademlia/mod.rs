@@ -30,7 +30,7 @@ pub(super) fn handle_kademlia_event(
 
             for base_addr in addresses.iter() {
                 let mut full_addr = base_addr.clone();
-                full_addr.push(Protocol::P2p(peer.into()));
+                full_addr.push(Protocol::P2p(peer));
                 log_net_kad_trace!(
                     "P2PNetwork.handle_incoming_event() | Kademlia | RoutingUpdated | Address: {:?}",
                     full_addr
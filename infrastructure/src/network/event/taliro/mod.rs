@@ -54,7 +54,7 @@ async fn handle_taliro_request_message_event(
 
     let response = match request {
         TaliroProtocolRequest::GetBlockchainTip => {
-            let (command, res_fut) = cmd_tx_res_factory.build_blk_cmd_get_tip_info();
+            let (command, res_fut) = cmd_tx_res_factory.build_blk_cmd_get_tip_chain_info();
             let Ok(_) = cmd_tx.send(command).await else {
                 log_net_gs_error!("Failed to send GetBlockchainTipInfo command.");
                 return;
@@ -77,6 +77,18 @@ async fn handle_taliro_request_message_event(
             };
             TaliroProtocolResponse::GetBlockByHeight(block)
         }
+        TaliroProtocolRequest::GetBlockByHash(hash) => {
+            let (command, res_fut) = cmd_tx_res_factory.build_blk_cmd_get_block(hash);
+            let Ok(_) = cmd_tx.send(command).await else {
+                log_net_gs_error!("Failed to send GetBlockchainBlock command.");
+                return;
+            };
+            let Ok(block) = res_fut.await else {
+                log_net_gs_error!("GetBlockchainBlock command failed.");
+                return;
+            };
+            TaliroProtocolResponse::GetBlockByHash(block)
+        }
         TaliroProtocolRequest::GetBlocksByHeightRange(range) => {
             let (command, res_fut) =
                 cmd_tx_res_factory.build_blk_cmd_get_blocks_by_height_range(range);
@@ -90,6 +102,18 @@ async fn handle_taliro_request_message_event(
             };
             TaliroProtocolResponse::GetBlocksByHeightRange(blocks)
         }
+        TaliroProtocolRequest::GetForkPoint(locator) => {
+            let (command, res_fut) = cmd_tx_res_factory.build_blk_cmd_get_fork_point(locator);
+            let Ok(_) = cmd_tx.send(command).await else {
+                log_net_gs_error!("Failed to send GetBlockchainForkPoint command.");
+                return;
+            };
+            let Ok(fork_point) = res_fut.await else {
+                log_net_gs_error!("GetBlockchainForkPoint command failed.");
+                return;
+            };
+            TaliroProtocolResponse::ForkPoint(fork_point)
+        }
     };
 
     if let Err(response) = swarm
@@ -130,7 +154,8 @@ async fn handle_taliro_response_message_event(
                 log_net_taliro_error!("ReceiveBlockchainTipInfo command failed: {err}");
             }
         }
-        TaliroProtocolResponse::GetBlockByHeight(block) => {
+        TaliroProtocolResponse::GetBlockByHeight(block)
+        | TaliroProtocolResponse::GetBlockByHash(block) => {
             let Some(block) = block else {
                 return;
             };
@@ -157,5 +182,16 @@ async fn handle_taliro_response_message_event(
                 log_net_taliro_error!("ReceiveBlocksByHeightRange command failed: {err}");
             }
         }
+        TaliroProtocolResponse::ForkPoint(fork_point) => {
+            let (command, res_fut) =
+                cmd_tx_res_factory.build_p2p_cmd_receive_fork_point(peer_id, fork_point);
+            let Ok(_) = cmd_tx.send(command).await else {
+                log_net_taliro_error!("Failed to send ReceiveForkPoint command.");
+                return;
+            };
+            if let Err(err) = res_fut.await {
+                log_net_taliro_error!("ReceiveForkPoint command failed: {err}");
+            }
+        }
     }
 }
@@ -25,17 +25,22 @@ impl AsRef<str> for TaliroProtocol {
 }
 
 #[derive(Debug, Clone, Encode, Decode)]
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum TaliroProtocolRequest {
     GetBlockchainTip,
     GetBlockByHeight(BlockHeight),
+    GetBlockByHash(Hash),
     GetBlocksByHeightRange(RangeInclusive<BlockHeight>),
+    GetForkPoint(Vec<Hash>),
 }
 
 #[derive(Debug, Clone, Encode, Decode)]
 pub(crate) enum TaliroProtocolResponse {
-    BlockchainTip(Option<(Hash, BlockHeight)>),
+    BlockchainTip(Option<(Hash, BlockHeight, u128)>),
     GetBlockByHeight(Option<Block>),
+    GetBlockByHash(Option<Block>),
     GetBlocksByHeightRange(Vec<Block>),
+    ForkPoint(Option<(BlockHeight, BlockHeight)>),
 }
 
 #[derive(Clone, Default)]
@@ -17,6 +17,8 @@ use std::sync::Arc;
 pub struct SledBlockchainRepository {
     blocks_tree: Tree,
     heights_tree: Tree,
+    chain_work_tree: Tree,
+    invalid_tree: Tree,
     meta_tree: Tree,
     outbox_unprocessed_tree: Tree,
 }
@@ -26,6 +28,8 @@ impl Debug for SledBlockchainRepository {
         f.debug_struct("SledBlockchainRepository")
             .field("blocks_tree", &SledStorage::BLOCKCHAIN_BLOCKS_TREE)
             .field("heights_tree", &SledStorage::BLOCKCHAIN_HEIGHTS_TREE)
+            .field("chain_work_tree", &SledStorage::BLOCKCHAIN_CHAIN_WORK_TREE)
+            .field("invalid_tree", &SledStorage::BLOCKCHAIN_INVALID_TREE)
             .field("meta_tree", &SledStorage::BLOCKCHAIN_META_TREE)
             .finish()
     }
@@ -35,12 +39,16 @@ impl SledBlockchainRepository {
     pub fn open(
         blocks_tree: Tree,
         heights_tree: Tree,
+        chain_work_tree: Tree,
+        invalid_tree: Tree,
         meta_tree: Tree,
         outbox_unprocessed_tree: Tree,
     ) -> Result<Self, AppError> {
         let repo = Self {
             blocks_tree,
             heights_tree,
+            chain_work_tree,
+            invalid_tree,
             meta_tree,
             outbox_unprocessed_tree,
         };
@@ -53,6 +61,7 @@ impl BlockchainRepository for SledBlockchainRepository {
         let trees = SledTxBlockchainAppendBlockTrees {
             blocks_tree: self.blocks_tree.clone(),
             heights_tree: self.heights_tree.clone(),
+            chain_work_tree: self.chain_work_tree.clone(),
             outbox_unprocessed_tree: self.outbox_unprocessed_tree.clone(),
         };
         let trees = SledTxTrees::BlockchainAppendBlock(trees);
@@ -181,12 +190,78 @@ impl BlockchainRepository for SledBlockchainRepository {
                 .to_app_error()?;
         } else {
             self.heights_tree
-                .insert(&height_key, block_hash.as_ref())
+                .insert(height_key, block_hash.as_ref())
+                .to_app_error()?;
+        };
+        Ok(())
+    }
+
+    fn delete_height(
+        &self,
+        tx_ctx: Option<&dyn AtomicTransactionContext>,
+        height: &BlockHeight,
+    ) -> Result<(), AppError> {
+        let height_key = height.to_be_bytes();
+        if let Some(tx_ctx) = tx_ctx {
+            let heights_tree = tx_ctx.get_heights_tree()?;
+            heights_tree.remove(&height_key).to_app_error()?;
+        } else {
+            self.heights_tree.remove(height_key).to_app_error()?;
+        };
+        Ok(())
+    }
+
+    fn get_chain_work(
+        &self,
+        tx_ctx: Option<&dyn AtomicTransactionContext>,
+        hash: &Hash,
+    ) -> Result<Option<u128>, AppError> {
+        let chain_work = if let Some(tx_ctx) = tx_ctx {
+            let chain_work_tree = tx_ctx.get_chain_work_tree()?;
+            chain_work_tree.get(hash.as_ref()).to_app_error()?
+        } else {
+            self.chain_work_tree.get(hash.as_ref()).to_app_error()?
+        };
+        if let Some(bytes) = chain_work {
+            let mut work_bytes = [0u8; 16];
+            work_bytes.copy_from_slice(&bytes);
+            Ok(Some(u128::from_be_bytes(work_bytes)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn insert_chain_work(
+        &self,
+        tx_ctx: Option<&dyn AtomicTransactionContext>,
+        hash: &Hash,
+        chain_work: u128,
+    ) -> Result<(), AppError> {
+        let data = chain_work.to_be_bytes();
+        if let Some(tx_ctx) = tx_ctx {
+            let chain_work_tree = tx_ctx.get_chain_work_tree()?;
+            chain_work_tree
+                .insert(hash.as_ref(), &data)
+                .to_app_error()?;
+        } else {
+            self.chain_work_tree
+                .insert(hash.as_ref(), &data)
                 .to_app_error()?;
         };
         Ok(())
     }
 
+    fn is_invalid_block(&self, hash: &Hash) -> Result<bool, AppError> {
+        self.invalid_tree.contains_key(hash.as_ref()).to_app_error()
+    }
+
+    fn insert_invalid_block(&self, hash: &Hash) -> Result<(), AppError> {
+        self.invalid_tree
+            .insert(hash.as_ref(), &[])
+            .to_app_error()?;
+        Ok(())
+    }
+
     fn get_tip(
         &self,
         tx_ctx: Option<&dyn AtomicTransactionContext>,
@@ -0,0 +1,67 @@
+use crate::ext::AppErrorExtInfrastructure;
+use crate::storage::SledStorage;
+use common::error::AppError;
+use domain::encode::{TryDecode, TryEncode};
+use domain::entities::transaction::Transaction;
+use domain::repos::mempool::MempoolRepository;
+use domain::types::time::DateTime;
+use sled::{Batch, Tree};
+use std::fmt::{Debug, Formatter};
+
+pub struct SledMempoolRepository {
+    mempool_tree: Tree,
+}
+
+impl Debug for SledMempoolRepository {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SledMempoolRepository")
+            .field("mempool_tree", &SledStorage::MEMPOOL_TREE)
+            .finish()
+    }
+}
+
+impl SledMempoolRepository {
+    pub fn open(mempool_tree: Tree) -> Result<Self, AppError> {
+        let repo = Self { mempool_tree };
+        Ok(repo)
+    }
+}
+
+impl MempoolRepository for SledMempoolRepository {
+    fn replace_transactions(
+        &self,
+        transactions: Vec<(Transaction, DateTime)>,
+    ) -> Result<(), AppError> {
+        let mut batch = Batch::default();
+        for res in self.mempool_tree.iter().keys() {
+            let key = res.to_app_error()?;
+            batch.remove(key);
+        }
+        // Keys are big-endian sequence numbers, preserving insertion order on iteration.
+        // Values are prefixed with the big-endian time (in milliseconds) transactions were pooled at.
+        for (index, (tx, added_at)) in transactions.iter().enumerate() {
+            let mut data = added_at.to_ms().to_be_bytes().to_vec();
+            data.extend(tx.try_encode()?);
+            batch.insert(&(index as u64).to_be_bytes(), data);
+        }
+        self.mempool_tree.apply_batch(batch).to_app_error()?;
+        Ok(())
+    }
+
+    fn get_transactions(&self) -> Result<Vec<(Transaction, DateTime)>, AppError> {
+        self.mempool_tree
+            .iter()
+            .map(|res| {
+                let (_key, value) = res.to_app_error()?;
+                let Some((added_at, data)) = value.split_first_chunk::<8>() else {
+                    return Err(AppError::internal(
+                        "Persisted mempool transaction is missing its pooling time!",
+                    ));
+                };
+                let added_at = DateTime::from_ms(u64::from_be_bytes(*added_at));
+                let tx = Transaction::try_decode(data)?;
+                Ok((tx, added_at))
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,5 @@
 pub mod blockchain;
+pub mod mempool;
 pub mod network;
 pub mod outbox;
 pub mod utxo;
@@ -6,45 +6,92 @@ use common::error::AppError;
 use common::tx::UnitOfWork;
 use common::tx::ctx::AtomicTransactionContext;
 use domain::encode::{TryDecode, TryEncode};
-use domain::entities::transaction::{TransactionOutPoint, TransactionOutput, Utxo};
+use domain::entities::block::BlockUndo;
+use domain::entities::transaction::{TransactionOutPoint, Utxo};
 use domain::repos::utxo::UtxoRepository;
-use sled::Tree;
+use domain::types::hash::Hash;
+use domain::types::wallet::WalletAddress;
+use sled::{Batch, Tree};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 pub struct SledUtxoRepository {
     utxo_tree: Tree,
+    utxo_undo_tree: Tree,
+    /// Secondary index keyed by wallet address, followed by the encoded outpoint.<br />
+    /// Values are empty, outpoint keys are looked up in the UTXO tree.
+    utxo_address_index_tree: Tree,
 }
 
 impl Debug for SledUtxoRepository {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SledUtxoRepository")
             .field("utxo_tree", &SledStorage::UTXO_TREE)
+            .field("utxo_undo_tree", &SledStorage::UTXO_UNDO_TREE)
+            .field(
+                "utxo_address_index_tree",
+                &SledStorage::UTXO_ADDRESS_INDEX_TREE,
+            )
             .finish()
     }
 }
 
 impl SledUtxoRepository {
-    pub fn open(utxo_tree: Tree) -> Result<Self, AppError> {
-        let repo = Self { utxo_tree };
+    pub fn open(
+        utxo_tree: Tree,
+        utxo_undo_tree: Tree,
+        utxo_address_index_tree: Tree,
+    ) -> Result<Self, AppError> {
+        let repo = Self {
+            utxo_tree,
+            utxo_undo_tree,
+            utxo_address_index_tree,
+        };
+        repo.backfill_address_index()?;
         Ok(repo)
     }
+
+    /// Populates the address index for UTXO sets predating it.
+    fn backfill_address_index(&self) -> Result<(), AppError> {
+        if !self.utxo_address_index_tree.is_empty() || self.utxo_tree.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = Batch::default();
+        for res in self.utxo_tree.iter() {
+            let (_, value_bytes) = res.to_app_error()?;
+            let utxo = Utxo::try_decode(&value_bytes)?;
+            batch.insert(Self::address_index_key(&utxo)?, &[]);
+        }
+        self.utxo_address_index_tree
+            .apply_batch(batch)
+            .to_app_error()?;
+        Ok(())
+    }
+
+    fn address_index_key(utxo: &Utxo) -> Result<Vec<u8>, AppError> {
+        let mut key = utxo.get_output().get_recipient().as_bytes().to_vec();
+        key.extend(utxo.get_outpoint().try_encode()?);
+        Ok(key)
+    }
 }
 
 impl UtxoRepository for SledUtxoRepository {
     fn get_utxo_set_append_block_unit_of_work(&self) -> Arc<dyn UnitOfWork> {
         let trees = SledTxUtxoSetAppendBlockTrees {
             utxo_tree: self.utxo_tree.clone(),
+            utxo_undo_tree: self.utxo_undo_tree.clone(),
+            utxo_address_index_tree: self.utxo_address_index_tree.clone(),
         };
         let trees = SledTxTrees::UtxoSetAppendBlock(trees);
         Arc::new(SledUnitOfWork::new(trees))
     }
 
-    fn get_output(
+    fn get_utxo(
         &self,
         tx_ctx: Option<&dyn AtomicTransactionContext>,
         outpoint: &TransactionOutPoint,
-    ) -> Result<Option<TransactionOutput>, AppError> {
+    ) -> Result<Option<Utxo>, AppError> {
         let key = outpoint.try_encode()?;
         let utxo = if let Some(tx_ctx) = tx_ctx {
             let utxo_tree = tx_ctx.get_utxo_tree()?;
@@ -53,7 +100,7 @@ impl UtxoRepository for SledUtxoRepository {
             self.utxo_tree.get(key).to_app_error()?
         };
         if let Some(bytes) = utxo {
-            let utxo = TransactionOutput::try_decode(&bytes)?;
+            let utxo = Utxo::try_decode(&bytes)?;
             Ok(Some(utxo))
         } else {
             Ok(None)
@@ -64,12 +111,29 @@ impl UtxoRepository for SledUtxoRepository {
         let mut utxos = Vec::new();
 
         for res in self.utxo_tree.iter() {
-            let (key_bytes, value_bytes) = res.map_err(|err| {
+            let (_, value_bytes) = res.map_err(|err| {
                 AppError::internal_with_private("Storage read error!", err.to_string())
             })?;
-            let outpoint = TransactionOutPoint::try_decode(&key_bytes)?;
-            let output = TransactionOutput::try_decode(&value_bytes)?;
-            utxos.push(Utxo::new(outpoint, output));
+            let utxo = Utxo::try_decode(&value_bytes)?;
+            utxos.push(utxo);
+        }
+
+        Ok(utxos)
+    }
+
+    fn get_utxos_by_address(&self, address: &WalletAddress) -> Result<Vec<Utxo>, AppError> {
+        let prefix = address.as_bytes();
+        let mut utxos = Vec::new();
+
+        for res in self.utxo_address_index_tree.scan_prefix(prefix) {
+            let (index_key, _) = res.to_app_error()?;
+            let key = &index_key[prefix.len()..];
+            let Some(value_bytes) = self.utxo_tree.get(key).to_app_error()? else {
+                // Spent in between index and UTXO tree reads.
+                continue;
+            };
+            let utxo = Utxo::try_decode(&value_bytes)?;
+            utxos.push(utxo);
         }
 
         Ok(utxos)
@@ -81,12 +145,20 @@ impl UtxoRepository for SledUtxoRepository {
         utxo: Utxo,
     ) -> Result<(), AppError> {
         let key = utxo.get_outpoint().try_encode()?;
-        let data = utxo.get_output().try_encode()?;
+        let index_key = Self::address_index_key(&utxo)?;
+        let data = utxo.try_encode()?;
         if let Some(tx_ctx) = tx_ctx {
             let utxo_tree = tx_ctx.get_utxo_tree()?;
+            let utxo_address_index_tree = tx_ctx.get_utxo_address_index_tree()?;
             utxo_tree.insert(key, data).to_app_error()?;
+            utxo_address_index_tree
+                .insert(index_key, &[])
+                .to_app_error()?;
         } else {
             self.utxo_tree.insert(key, data).to_app_error()?;
+            self.utxo_address_index_tree
+                .insert(index_key, &[])
+                .to_app_error()?;
         }
         Ok(())
     }
@@ -99,9 +171,18 @@ impl UtxoRepository for SledUtxoRepository {
         let key = outpoint.try_encode()?;
         if let Some(tx_ctx) = tx_ctx {
             let utxo_tree = tx_ctx.get_utxo_tree()?;
-            utxo_tree.remove(key).to_app_error()?;
-        } else {
-            self.utxo_tree.remove(key).to_app_error()?;
+            let utxo_address_index_tree = tx_ctx.get_utxo_address_index_tree()?;
+            if let Some(value_bytes) = utxo_tree.remove(key).to_app_error()? {
+                let utxo = Utxo::try_decode(&value_bytes)?;
+                utxo_address_index_tree
+                    .remove(Self::address_index_key(&utxo)?)
+                    .to_app_error()?;
+            }
+        } else if let Some(value_bytes) = self.utxo_tree.remove(key).to_app_error()? {
+            let utxo = Utxo::try_decode(&value_bytes)?;
+            self.utxo_address_index_tree
+                .remove(Self::address_index_key(&utxo)?)
+                .to_app_error()?;
         }
         Ok(())
     }
@@ -109,4 +190,61 @@ impl UtxoRepository for SledUtxoRepository {
     fn get_utxo_count(&self) -> usize {
         self.utxo_tree.iter().count()
     }
+
+    fn get_block_undo(
+        &self,
+        tx_ctx: Option<&dyn AtomicTransactionContext>,
+        block_hash: &Hash,
+    ) -> Result<Option<BlockUndo>, AppError> {
+        let block_undo = if let Some(tx_ctx) = tx_ctx {
+            let utxo_undo_tree = tx_ctx.get_utxo_undo_tree()?;
+            utxo_undo_tree.get(block_hash.as_ref()).to_app_error()?
+        } else {
+            self.utxo_undo_tree
+                .get(block_hash.as_ref())
+                .to_app_error()?
+        };
+        if let Some(bytes) = block_undo {
+            let block_undo = BlockUndo::try_decode(&bytes)?;
+            Ok(Some(block_undo))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn insert_block_undo(
+        &self,
+        tx_ctx: Option<&dyn AtomicTransactionContext>,
+        block_hash: &Hash,
+        block_undo: BlockUndo,
+    ) -> Result<(), AppError> {
+        let data = block_undo.try_encode()?;
+        if let Some(tx_ctx) = tx_ctx {
+            let utxo_undo_tree = tx_ctx.get_utxo_undo_tree()?;
+            utxo_undo_tree
+                .insert(block_hash.as_ref(), data)
+                .to_app_error()?;
+        } else {
+            self.utxo_undo_tree
+                .insert(block_hash.as_ref(), data)
+                .to_app_error()?;
+        }
+        Ok(())
+    }
+
+    fn delete_block_undo(
+        &self,
+        tx_ctx: Option<&dyn AtomicTransactionContext>,
+        block_hash: &Hash,
+    ) -> Result<(), AppError> {
+        if let Some(tx_ctx) = tx_ctx {
+            let utxo_undo_tree = tx_ctx.get_utxo_undo_tree()?;
+            utxo_undo_tree.remove(block_hash.as_ref()).to_app_error()?;
+        } else {
+            self.utxo_undo_tree
+                .remove(block_hash.as_ref())
+                .to_app_error()?;
+        }
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use crate::ext::AppErrorExtInfrastructure;
 use crate::repos::blockchain::SledBlockchainRepository;
+use crate::repos::mempool::SledMempoolRepository;
 use crate::repos::network::SledNetworkRepository;
 use crate::repos::outbox::SledOutboxRepository;
 use crate::repos::utxo::SledUtxoRepository;
@@ -7,6 +8,7 @@ use application::storage::Storage;
 use common::config::storage::StorageConfig;
 use common::error::AppError;
 use domain::repos::blockchain::BlockchainRepository;
+use domain::repos::mempool::MempoolRepository;
 use domain::repos::network::NetworkRepository;
 use domain::repos::outbox::OutboxRepository;
 use domain::repos::utxo::UtxoRepository;
@@ -19,6 +21,7 @@ pub struct SledStorage {
     db: Arc<sled::Db>,
     blockchain_repo: Arc<dyn BlockchainRepository>,
     utxo_repo: Arc<dyn UtxoRepository>,
+    mempool_repo: Arc<dyn MempoolRepository>,
     network_repo: Arc<dyn NetworkRepository>,
     outbox_repo: Arc<dyn OutboxRepository>,
 }
@@ -28,9 +31,14 @@ impl SledStorage {
     pub(crate) const OUTBOX_UNPROCESSED_TREE: &'static str = "outbox_unprocessed";
     pub(crate) const BLOCKCHAIN_BLOCKS_TREE: &'static str = "blockchain_blocks";
     pub(crate) const BLOCKCHAIN_HEIGHTS_TREE: &'static str = "blockchain_heights";
+    pub(crate) const BLOCKCHAIN_CHAIN_WORK_TREE: &'static str = "blockchain_chain_work";
+    pub(crate) const BLOCKCHAIN_INVALID_TREE: &'static str = "blockchain_invalid";
     pub(crate) const BLOCKCHAIN_META_TREE: &'static str = "blockchain_meta";
     pub(crate) const BLOCKCHAIN_META_TREE_TIP_KEY: &'static str = "chain_tip";
     pub(crate) const UTXO_TREE: &'static str = "utxo";
+    pub(crate) const UTXO_UNDO_TREE: &'static str = "utxo_undo";
+    pub(crate) const UTXO_ADDRESS_INDEX_TREE: &'static str = "utxo_address_index";
+    pub(crate) const MEMPOOL_TREE: &'static str = "mempool";
     pub(crate) const NETWORK_PEER_ADDRESS_TREE: &'static str = "network_peers";
     pub(crate) const NETWORK_META_TREE: &'static str = "network_meta";
     pub(crate) const NETWORK_META_TREE_IDENTITY_KEY_PAIR_KEY: &'static str = "identity_key_pair";
@@ -43,8 +51,15 @@ impl SledStorage {
         let outbox_unprocessed_tree = db.open_tree(Self::OUTBOX_UNPROCESSED_TREE).to_app_error()?;
         let blockchain_blocks_tree = db.open_tree(Self::BLOCKCHAIN_BLOCKS_TREE).to_app_error()?;
         let blockchain_heights_tree = db.open_tree(Self::BLOCKCHAIN_HEIGHTS_TREE).to_app_error()?;
+        let blockchain_chain_work_tree = db
+            .open_tree(Self::BLOCKCHAIN_CHAIN_WORK_TREE)
+            .to_app_error()?;
+        let blockchain_invalid_tree = db.open_tree(Self::BLOCKCHAIN_INVALID_TREE).to_app_error()?;
         let blockchain_meta_tree = db.open_tree(Self::BLOCKCHAIN_META_TREE).to_app_error()?;
         let utxo_tree = db.open_tree(Self::UTXO_TREE).to_app_error()?;
+        let utxo_undo_tree = db.open_tree(Self::UTXO_UNDO_TREE).to_app_error()?;
+        let utxo_address_index_tree = db.open_tree(Self::UTXO_ADDRESS_INDEX_TREE).to_app_error()?;
+        let mempool_tree = db.open_tree(Self::MEMPOOL_TREE).to_app_error()?;
         let peer_address_tree = db
             .open_tree(Self::NETWORK_PEER_ADDRESS_TREE)
             .to_app_error()?;
@@ -53,10 +68,14 @@ impl SledStorage {
         let blockchain_repo = SledBlockchainRepository::open(
             blockchain_blocks_tree,
             blockchain_heights_tree,
+            blockchain_chain_work_tree,
+            blockchain_invalid_tree,
             blockchain_meta_tree,
             outbox_unprocessed_tree.clone(),
         )?;
-        let utxo_repo = SledUtxoRepository::open(utxo_tree)?;
+        let utxo_repo =
+            SledUtxoRepository::open(utxo_tree, utxo_undo_tree, utxo_address_index_tree)?;
+        let mempool_repo = SledMempoolRepository::open(mempool_tree)?;
         let network_repo = SledNetworkRepository::open(peer_address_tree, network_meta_tree)?;
         let outbox_repo =
             SledOutboxRepository::open(outbox_unprocessed_tree, outbox_processed_tree)?;
@@ -65,6 +84,7 @@ impl SledStorage {
             db,
             blockchain_repo: Arc::new(blockchain_repo),
             utxo_repo: Arc::new(utxo_repo),
+            mempool_repo: Arc::new(mempool_repo),
             network_repo: Arc::new(network_repo),
             outbox_repo: Arc::new(outbox_repo),
         };
@@ -81,6 +101,10 @@ impl Storage for SledStorage {
         self.utxo_repo.clone()
     }
 
+    fn get_mempool_repo(&self) -> Arc<dyn MempoolRepository> {
+        self.mempool_repo.clone()
+    }
+
     fn get_network_repo(&self) -> Arc<dyn NetworkRepository> {
         self.network_repo.clone()
     }
@@ -7,6 +7,7 @@ use std::any::TypeId;
 pub(super) struct SledTxBlockchainAppendBlockContext {
     pub(super) blocks_tree: TransactionalTree,
     pub(super) heights_tree: TransactionalTree,
+    pub(super) chain_work_tree: TransactionalTree,
     pub(super) outbox_unprocessed_tree: TransactionalTree,
 }
 
@@ -19,6 +20,7 @@ impl AtomicTransactionContext for SledTxBlockchainAppendBlockContext {
         let ctx = SledTxBlockchainAppendBlockContext {
             blocks_tree: self.blocks_tree.clone(),
             heights_tree: self.heights_tree.clone(),
+            chain_work_tree: self.chain_work_tree.clone(),
             outbox_unprocessed_tree: self.outbox_unprocessed_tree.clone(),
         };
         Box::new(ctx)
@@ -28,6 +30,8 @@ impl AtomicTransactionContext for SledTxBlockchainAppendBlockContext {
 #[derive(Clone)]
 pub(super) struct SledTxUtxoSetAppendBlockContext {
     pub(super) utxo_tree: TransactionalTree,
+    pub(super) utxo_undo_tree: TransactionalTree,
+    pub(super) utxo_address_index_tree: TransactionalTree,
 }
 
 impl AtomicTransactionContext for SledTxUtxoSetAppendBlockContext {
@@ -38,6 +42,8 @@ impl AtomicTransactionContext for SledTxUtxoSetAppendBlockContext {
     fn as_any(&self) -> Box<dyn std::any::Any> {
         let ctx = SledTxUtxoSetAppendBlockContext {
             utxo_tree: self.utxo_tree.clone(),
+            utxo_undo_tree: self.utxo_undo_tree.clone(),
+            utxo_address_index_tree: self.utxo_address_index_tree.clone(),
         };
         Box::new(ctx)
     }
@@ -78,6 +84,24 @@ impl crate::ext::TransactionContextExtInfrastructure for &dyn AtomicTransactionC
         Ok(tree)
     }
 
+    fn get_chain_work_tree(&self) -> Result<TransactionalTree, AppError> {
+        let tree = match self.type_id() {
+            type_id if type_id == TypeId::of::<SledTxBlockchainAppendBlockContext>() => self
+                .as_any()
+                .downcast_ref::<SledTxBlockchainAppendBlockContext>()
+                .ok_or_else(|| {
+                    AppError::internal(
+                        "Mismatched transaction context type id. Couldn't downcast type!",
+                    )
+                })?
+                .chain_work_tree
+                .clone(),
+            _ => Err(AppError::internal("Invalid transaction context type!"))?,
+        };
+        Ok(tree)
+    }
+
+    #[allow(clippy::match_single_binding)]
     fn get_meta_tree(&self) -> Result<TransactionalTree, AppError> {
         let tree = match self.type_id() {
             _ => Err(AppError::internal("Invalid transaction context type!"))?,
@@ -102,6 +126,40 @@ impl crate::ext::TransactionContextExtInfrastructure for &dyn AtomicTransactionC
         Ok(tree)
     }
 
+    fn get_utxo_undo_tree(&self) -> Result<TransactionalTree, AppError> {
+        let tree = match self.type_id() {
+            type_id if type_id == TypeId::of::<SledTxUtxoSetAppendBlockContext>() => self
+                .as_any()
+                .downcast_ref::<SledTxUtxoSetAppendBlockContext>()
+                .ok_or_else(|| {
+                    AppError::internal(
+                        "Mismatched transaction context type id. Couldn't downcast type!",
+                    )
+                })?
+                .utxo_undo_tree
+                .clone(),
+            _ => Err(AppError::internal("Invalid transaction context type!"))?,
+        };
+        Ok(tree)
+    }
+
+    fn get_utxo_address_index_tree(&self) -> Result<TransactionalTree, AppError> {
+        let tree = match self.type_id() {
+            type_id if type_id == TypeId::of::<SledTxUtxoSetAppendBlockContext>() => self
+                .as_any()
+                .downcast_ref::<SledTxUtxoSetAppendBlockContext>()
+                .ok_or_else(|| {
+                    AppError::internal(
+                        "Mismatched transaction context type id. Couldn't downcast type!",
+                    )
+                })?
+                .utxo_address_index_tree
+                .clone(),
+            _ => Err(AppError::internal("Invalid transaction context type!"))?,
+        };
+        Ok(tree)
+    }
+
     fn get_outbox_unprocessed_tree(&self) -> Result<TransactionalTree, AppError> {
         let tree = match self.type_id() {
             type_id if type_id == TypeId::of::<SledTxBlockchainAppendBlockContext>() => self
@@ -38,23 +38,35 @@ impl UnitOfWork for SledUnitOfWork {
             SledTxTrees::BlockchainAppendBlock(trees) => (
                 &trees.blocks_tree,
                 &trees.heights_tree,
+                &trees.chain_work_tree,
                 &trees.outbox_unprocessed_tree,
             )
-                .transaction(|(blocks_tree, heights_tree, outbox_unprocessed_tree)| {
-                    let mut ctx = SledTxBlockchainAppendBlockContext {
-                        blocks_tree: blocks_tree.clone(),
-                        heights_tree: heights_tree.clone(),
-                        outbox_unprocessed_tree: outbox_unprocessed_tree.clone(),
+                .transaction(
+                    |(blocks_tree, heights_tree, chain_work_tree, outbox_unprocessed_tree)| {
+                        let mut ctx = SledTxBlockchainAppendBlockContext {
+                            blocks_tree: blocks_tree.clone(),
+                            heights_tree: heights_tree.clone(),
+                            chain_work_tree: chain_work_tree.clone(),
+                            outbox_unprocessed_tree: outbox_unprocessed_tree.clone(),
+                        };
+                        f.borrow_mut()(&mut ctx)
+                            .map_err(ConflictableTransactionError::Abort::<AppError>)
+                    },
+                ),
+            SledTxTrees::UtxoSetAppendBlock(trees) => (
+                &trees.utxo_tree,
+                &trees.utxo_undo_tree,
+                &trees.utxo_address_index_tree,
+            )
+                .transaction(|(utxo_tree, utxo_undo_tree, utxo_address_index_tree)| {
+                    let mut ctx = SledTxUtxoSetAppendBlockContext {
+                        utxo_tree: utxo_tree.clone(),
+                        utxo_undo_tree: utxo_undo_tree.clone(),
+                        utxo_address_index_tree: utxo_address_index_tree.clone(),
                     };
                     f.borrow_mut()(&mut ctx)
                         .map_err(ConflictableTransactionError::Abort::<AppError>)
                 }),
-            SledTxTrees::UtxoSetAppendBlock(trees) => trees.utxo_tree.transaction(|utxo_tree| {
-                let mut ctx = SledTxUtxoSetAppendBlockContext {
-                    utxo_tree: utxo_tree.clone(),
-                };
-                f.borrow_mut()(&mut ctx).map_err(ConflictableTransactionError::Abort::<AppError>)
-            }),
         };
         tx_res.to_app_error()
     }
@@ -10,10 +10,13 @@ pub(crate) enum SledTxTrees {
 pub(crate) struct SledTxBlockchainAppendBlockTrees {
     pub(crate) blocks_tree: Tree,
     pub(crate) heights_tree: Tree,
+    pub(crate) chain_work_tree: Tree,
     pub(crate) outbox_unprocessed_tree: Tree,
 }
 
 #[derive(Clone)]
 pub(crate) struct SledTxUtxoSetAppendBlockTrees {
     pub(crate) utxo_tree: Tree,
+    pub(crate) utxo_undo_tree: Tree,
+    pub(crate) utxo_address_index_tree: Tree,
 }
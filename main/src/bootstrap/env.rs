@@ -12,6 +12,17 @@ pub(crate) struct EnvironmentConfig {
     pub(crate) network_listen_address: Option<String>,
     pub(crate) network_init_peers: Option<Vec<String>>,
     pub(crate) network_identity_key_pair: Option<String>,
+    // Node
+    pub(crate) node_target_block_interval_secs: Option<u64>,
+    pub(crate) node_coinbase_maturity: Option<u64>,
+    pub(crate) node_max_future_block_drift_secs: Option<u64>,
+    pub(crate) node_min_relay_fee_rate: Option<u64>,
+    pub(crate) node_max_mempool_size_bytes: Option<u64>,
+    pub(crate) node_replace_by_fee_enabled: Option<bool>,
+    pub(crate) node_max_replacement_evictions: Option<u64>,
+    pub(crate) node_mempool_expiry_secs: Option<u64>,
+    pub(crate) node_mining_enabled: Option<bool>,
+    pub(crate) node_mining_reward_address: Option<String>,
     // Storage
     pub(crate) db_path: Option<String>,
 }
@@ -43,6 +54,16 @@ impl EnvironmentConfig {
     const NETWORK_LISTEN_ADDRESS_ENV: &'static str = "NETWORK_LISTEN_ADDRESS";
     const NETWORK_INIT_PEERS_ENV: &'static str = "NETWORK_INIT_PEERS";
     const NETWORK_IDENTITY_KEY_PAIR_ENV: &'static str = "NETWORK_IDENTITY_KEY_PAIR";
+    const NODE_TARGET_BLOCK_INTERVAL_SECS_ENV: &'static str = "NODE_TARGET_BLOCK_INTERVAL_SECS";
+    const NODE_COINBASE_MATURITY_ENV: &'static str = "NODE_COINBASE_MATURITY";
+    const NODE_MAX_FUTURE_BLOCK_DRIFT_SECS_ENV: &'static str = "NODE_MAX_FUTURE_BLOCK_DRIFT_SECS";
+    const NODE_MIN_RELAY_FEE_RATE_ENV: &'static str = "NODE_MIN_RELAY_FEE_RATE";
+    const NODE_MAX_MEMPOOL_SIZE_BYTES_ENV: &'static str = "NODE_MAX_MEMPOOL_SIZE_BYTES";
+    const NODE_REPLACE_BY_FEE_ENABLED_ENV: &'static str = "NODE_REPLACE_BY_FEE_ENABLED";
+    const NODE_MAX_REPLACEMENT_EVICTIONS_ENV: &'static str = "NODE_MAX_REPLACEMENT_EVICTIONS";
+    const NODE_MEMPOOL_EXPIRY_SECS_ENV: &'static str = "NODE_MEMPOOL_EXPIRY_SECS";
+    const NODE_MINING_ENABLED_ENV: &'static str = "NODE_MINING_ENABLED";
+    const NODE_MINING_REWARD_ADDRESS_ENV: &'static str = "NODE_MINING_REWARD_ADDRESS";
     const STORAGE_DB_PATH_ENV: &'static str = "STORAGE_DB_PATH";
 
     pub(crate) fn load() -> Result<Self, AppError> {
@@ -53,6 +74,16 @@ impl EnvironmentConfig {
             network_listen_address: Self::get_network_listen_address(),
             network_init_peers: Self::get_network_init_peers(),
             network_identity_key_pair: Self::get_network_identity_key_pair(),
+            node_target_block_interval_secs: Self::get_node_target_block_interval_secs(),
+            node_coinbase_maturity: Self::get_node_coinbase_maturity(),
+            node_max_future_block_drift_secs: Self::get_node_max_future_block_drift_secs(),
+            node_min_relay_fee_rate: Self::get_node_min_relay_fee_rate(),
+            node_max_mempool_size_bytes: Self::get_node_max_mempool_size_bytes(),
+            node_replace_by_fee_enabled: Self::get_node_replace_by_fee_enabled(),
+            node_max_replacement_evictions: Self::get_node_max_replacement_evictions(),
+            node_mempool_expiry_secs: Self::get_node_mempool_expiry_secs(),
+            node_mining_enabled: Self::get_node_mining_enabled(),
+            node_mining_reward_address: Self::get_node_mining_reward_address(),
             db_path: Self::get_storage_db_path(),
         })
     }
@@ -111,6 +142,76 @@ impl EnvironmentConfig {
         }
     }
 
+    fn get_node_target_block_interval_secs() -> Option<u64> {
+        match env::var(Self::NODE_TARGET_BLOCK_INTERVAL_SECS_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_coinbase_maturity() -> Option<u64> {
+        match env::var(Self::NODE_COINBASE_MATURITY_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_max_future_block_drift_secs() -> Option<u64> {
+        match env::var(Self::NODE_MAX_FUTURE_BLOCK_DRIFT_SECS_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_min_relay_fee_rate() -> Option<u64> {
+        match env::var(Self::NODE_MIN_RELAY_FEE_RATE_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_max_mempool_size_bytes() -> Option<u64> {
+        match env::var(Self::NODE_MAX_MEMPOOL_SIZE_BYTES_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_replace_by_fee_enabled() -> Option<bool> {
+        match env::var(Self::NODE_REPLACE_BY_FEE_ENABLED_ENV) {
+            Ok(env_str) => env_str.trim().parse::<bool>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_max_replacement_evictions() -> Option<u64> {
+        match env::var(Self::NODE_MAX_REPLACEMENT_EVICTIONS_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_mempool_expiry_secs() -> Option<u64> {
+        match env::var(Self::NODE_MEMPOOL_EXPIRY_SECS_ENV) {
+            Ok(env_str) => env_str.parse::<u64>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_mining_enabled() -> Option<bool> {
+        match env::var(Self::NODE_MINING_ENABLED_ENV) {
+            Ok(env_str) => env_str.trim().parse::<bool>().ok(),
+            Err(_) => None,
+        }
+    }
+
+    fn get_node_mining_reward_address() -> Option<String> {
+        match env::var(Self::NODE_MINING_REWARD_ADDRESS_ENV) {
+            Ok(env_str) if !env_str.trim().is_empty() => Some(env_str),
+            _ => None,
+        }
+    }
+
     fn get_storage_db_path() -> Option<String> {
         match env::var(Self::STORAGE_DB_PATH_ENV) {
             Ok(env_str) if !env_str.trim().is_empty() => Some(env_str),
@@ -1,5 +1,7 @@
 use application::outbox::OutboxRelay;
-use application::queue::{DefaultBlockProcessingQueue, DefaultBlockSyncQueue};
+use application::queue::{
+    DefaultBlockOrphanPool, DefaultBlockProcessingQueue, DefaultBlockSyncQueue,
+};
 use application::storage::Storage;
 use common::config::node::NodeConfig;
 use common::error::AppError;
@@ -7,7 +9,7 @@ use domain::entities::block::BlockHeight;
 use domain::system::network::P2PNetworkEngine;
 use domain::system::node;
 use domain::system::node::cmd::{CommandResponderFactory, CommandSender};
-use domain::system::queue::{BlockProcessingQueue, BlockSyncQueue};
+use domain::system::queue::{BlockOrphanPool, BlockProcessingQueue, BlockSyncQueue};
 use std::sync::Arc;
 
 pub(crate) async fn build_node(
@@ -22,12 +24,14 @@ pub(crate) async fn build_node(
         OutboxRelay,
         Arc<dyn BlockSyncQueue>,
         Arc<dyn BlockProcessingQueue>,
+        Arc<dyn BlockOrphanPool>,
     ),
     AppError,
 > {
     // Access Storage Repositories
     let blockchain_repo = storage.get_blockchain_repo();
     let utxo_repo = storage.get_utxo_repo();
+    let mempool_repo = storage.get_mempool_repo();
     let outbox_repo = storage.get_outbox_repo();
 
     // Build Outbox Relay
@@ -38,9 +42,17 @@ pub(crate) async fn build_node(
     );
 
     // Build Blockchain Node
-    let node = node::build_node(config, blockchain_repo, utxo_repo, outbox_repo, network).await?;
+    let node = node::build_node(
+        config,
+        blockchain_repo,
+        utxo_repo,
+        mempool_repo,
+        outbox_repo,
+        network,
+    )
+    .await?;
 
-    // Initialize Block Sync and Processing Queues
+    // Initialize Block Sync and Processing Queues, alongside the Orphan Pool
     let blockchain_tip = node.get_tip_info().await?;
     let next_expected_height = blockchain_tip.map_or(BlockHeight::genesis(), |info| info.1.next());
     let block_proc_queue = Arc::new(DefaultBlockProcessingQueue::new(next_expected_height));
@@ -50,5 +62,13 @@ pub(crate) async fn build_node(
         cmd_tx_res_factory.clone(),
     ));
 
-    Ok((node, outbox_relay, block_sync_queue, block_proc_queue))
+    let block_orphan_pool = Arc::new(DefaultBlockOrphanPool::new());
+
+    Ok((
+        node,
+        outbox_relay,
+        block_sync_queue,
+        block_proc_queue,
+        block_orphan_pool,
+    ))
 }
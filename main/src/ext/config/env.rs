@@ -22,7 +22,18 @@ impl PartialAppConfigFromEnvExtMain for PartialAppConfig {
                 init_peers: env.network_init_peers,
                 identity_key_pair: env.network_identity_key_pair,
             },
-            node: PartialNodeConfig {},
+            node: PartialNodeConfig {
+                target_block_interval_secs: env.node_target_block_interval_secs,
+                coinbase_maturity: env.node_coinbase_maturity,
+                max_future_block_drift_secs: env.node_max_future_block_drift_secs,
+                min_relay_fee_rate: env.node_min_relay_fee_rate,
+                max_mempool_size_bytes: env.node_max_mempool_size_bytes,
+                replace_by_fee_enabled: env.node_replace_by_fee_enabled,
+                max_replacement_evictions: env.node_max_replacement_evictions,
+                mempool_expiry_secs: env.node_mempool_expiry_secs,
+                mining_enabled: env.node_mining_enabled,
+                mining_reward_address: env.node_mining_reward_address,
+            },
             storage: PartialStorageConfig {
                 db_path: env.db_path,
             },
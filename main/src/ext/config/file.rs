@@ -38,7 +38,7 @@ impl PartialAppConfigFromFileExtMain for PartialAppConfig {
 /// Retrieves the env-specified config file path.<br />
 /// Returns an error for invalid paths.
 fn get_env_config_file_path() -> Result<Option<PathBuf>, AppError> {
-    const CFG_PATH_ENV: &'static str = "CONFIG_PATH";
+    const CFG_PATH_ENV: &str = "CONFIG_PATH";
     const ERR_MSG_PREFIX: &str = "Failed to read application config file:";
 
     let env_path = env::var(CFG_PATH_ENV)
@@ -61,7 +61,7 @@ fn get_env_config_file_path() -> Result<Option<PathBuf>, AppError> {
 
 /// Retrieves the default config file path.
 fn get_default_config_file_path() -> Option<PathBuf> {
-    const DEFAULT_CFG_PATH: &'static str = "/config.toml";
+    const DEFAULT_CFG_PATH: &str = "/config.toml";
 
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
     let mut default_path = PathBuf::from(manifest_dir)
@@ -47,20 +47,22 @@ async fn main() -> Result<(), AppError> {
     )?;
 
     // Prepare the Blockchain Node
-    let (node, outbox_relay, block_sync_queue, block_proc_queue) = bootstrap::node::build_node(
-        node_config,
-        storage,
-        network,
-        cmd_tx.clone(),
-        cmd_tx_res_factory.clone(),
-    )
-    .await?;
+    let (node, outbox_relay, block_sync_queue, block_proc_queue, block_orphan_pool) =
+        bootstrap::node::build_node(
+            node_config,
+            storage,
+            network,
+            cmd_tx.clone(),
+            cmd_tx_res_factory.clone(),
+        )
+        .await?;
     let node = node
         .bootstrap(
             cmd_tx.clone(),
             cmd_tx_res_factory.clone(),
             block_sync_queue,
             block_proc_queue,
+            block_orphan_pool,
             shutdown_rx.resubscribe(),
         )
         .await?
@@ -41,7 +41,7 @@ impl FromRequestParts<AppState> for MasterKeyAuthContextExtractor {
         // Obfuscate error specifics for security.
         get_ctx(parts, app_state)
             .await
-            .map(|ctx| MasterKeyAuthContextExtractor(ctx))
+            .map(MasterKeyAuthContextExtractor)
             .map_err(|err| {
                 let reworded = match err {
                     AppError::Unauthorized(base) => {
@@ -1,8 +1,18 @@
+#[cfg(test)]
+mod tests;
+
+use crate::conversions::time::DateTimeExtPresentation as _;
 use crate::dtos::time::DateTimeExtPresentation;
-use crate::dtos::transaction::TransactionPresentationDto;
+use crate::dtos::transaction::{InnerTransactionPresentationDto, TransactionPresentationDto};
 use chrono::{DateTime, Utc};
-use domain::entities::block::Block;
-use serde::Serialize;
+use common::error::AppError;
+use domain::encode::TryEncode;
+use domain::entities::block::{
+    Block, BlockDifficultyTarget, BlockHeight, BlockNonce, BlockTemplate, NonValidatedBlock,
+};
+use domain::entities::transaction::NonValidatedTransaction;
+use domain::types::hash::Hash;
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -20,6 +30,7 @@ struct InnerBlockPresentationDto {
     nonce: u64,
     difficulty_target: u128,
     transactions_merkle_root: String,
+    witness_merkle_root: String,
     transactions: Vec<TransactionPresentationDto>,
     timestamp: DateTime<Utc>,
 }
@@ -34,6 +45,7 @@ impl From<Block> for BlockPresentationDto {
                 nonce: block.get_nonce().as_u64(),
                 difficulty_target: block.get_difficulty_target().as_u128(),
                 transactions_merkle_root: block.get_transactions_merkle_root().to_string(),
+                witness_merkle_root: block.get_witness_merkle_root().to_string(),
                 transactions: block
                     .get_transactions()
                     .iter()
@@ -45,3 +57,79 @@ impl From<Block> for BlockPresentationDto {
         }
     }
 }
+
+/// A solved block, as submitted by external miners.<br />
+/// Merkle roots and the block hash are derived from its contents.
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(title = "NonValidatedBlock")]
+pub(crate) struct NonValidatedBlockPresentationDto {
+    height: u64,
+    prev_block_hash: Option<String>,
+    nonce: u64,
+    difficulty_target: u128,
+    transactions: Vec<InnerTransactionPresentationDto>,
+    timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "BlockTemplate")]
+pub(crate) struct BlockTemplatePresentationDto {
+    height: u64,
+    prev_block_hash: Option<String>,
+    difficulty_target: u128,
+    transaction_hashes: Vec<String>,
+    timestamp: DateTime<Utc>,
+    /// Hex-encoded unsolved block.<br />
+    /// Solved by searching for a nonce (and timestamp) whose block hash meets the difficulty target.
+    encoded_block: String,
+}
+
+impl TryFrom<BlockTemplate> for BlockTemplatePresentationDto {
+    type Error = AppError;
+
+    fn try_from(block_tpl: BlockTemplate) -> Result<Self, Self::Error> {
+        let height = block_tpl.get_height().as_u64();
+        let prev_block_hash = block_tpl.get_prev_block_hash().map(|hash| hash.to_string());
+        let difficulty_target = block_tpl.get_difficulty_target().as_u128();
+        let transaction_hashes = block_tpl
+            .get_transactions()
+            .iter()
+            .map(|tx| tx.get_hash().to_string())
+            .collect();
+        let timestamp = block_tpl.get_timestamp().to_chrono();
+        let block = NonValidatedBlock::from_template(block_tpl)?;
+        let encoded_block = hex::encode(block.try_encode()?);
+        Ok(Self {
+            height,
+            prev_block_hash,
+            difficulty_target,
+            transaction_hashes,
+            timestamp,
+            encoded_block,
+        })
+    }
+}
+
+impl TryFrom<NonValidatedBlockPresentationDto> for NonValidatedBlock {
+    type Error = AppError;
+
+    fn try_from(dto: NonValidatedBlockPresentationDto) -> Result<Self, Self::Error> {
+        let prev_block_hash = dto
+            .prev_block_hash
+            .map(|hash| Hash::try_from(hash.as_ref()))
+            .transpose()?;
+        let transactions = dto
+            .transactions
+            .into_iter()
+            .map(|tx| tx.try_into())
+            .collect::<Result<Vec<NonValidatedTransaction>, AppError>>()?;
+        Self::new(
+            BlockHeight::from(dto.height),
+            prev_block_hash,
+            BlockNonce::new(dto.nonce),
+            BlockDifficultyTarget::new(dto.difficulty_target),
+            transactions,
+            dto.timestamp.to_dmn_datetime(),
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+use domain::entities::transaction::{TransactionAmount, TransactionOutput};
+use domain::types::time::DateTime as DomainDateTime;
+use domain::types::wallet::WalletAddress;
+
+#[test]
+fn test_submitted_block_with_coinbase_from_json() {
+    let height = BlockHeight::from(1);
+    let prev_block_hash = Hash::new([7u8; 32]);
+    let recipient = format!("{:0>64}", 1).parse::<WalletAddress>().unwrap();
+    let timestamp = DomainDateTime::from_ms(1725799696000);
+    let coinbase = NonValidatedTransaction::new_coinbase(
+        height.clone(),
+        vec![TransactionOutput::new(
+            recipient.clone(),
+            TransactionAmount::new(50),
+        )],
+        timestamp.clone(),
+    )
+    .unwrap();
+    let block = NonValidatedBlock::new(
+        height,
+        Some(prev_block_hash.clone()),
+        BlockNonce::new(42),
+        BlockDifficultyTarget::new(u128::MAX >> 1),
+        vec![coinbase],
+        timestamp.clone(),
+    )
+    .unwrap();
+
+    let timestamp = timestamp.to_chrono().to_rfc3339();
+    let json = format!(
+        r#"{{
+            "height": 1,
+            "prev_block_hash": "{prev_block_hash}",
+            "nonce": 42,
+            "difficulty_target": {difficulty_target},
+            "transactions": [{{
+                "inputs": [],
+                "outputs": [{{ "recipient": "{recipient}", "amount": 50 }}],
+                "timestamp": "{timestamp}",
+                "coinbase_height": 1
+            }}],
+            "timestamp": "{timestamp}"
+        }}"#,
+        difficulty_target = u128::MAX >> 1,
+    );
+    let dto = serde_json::from_str::<NonValidatedBlockPresentationDto>(&json).unwrap();
+    let submitted_block = NonValidatedBlock::try_from(dto).unwrap();
+
+    assert_eq!(
+        submitted_block.get_hash(),
+        block.get_hash(),
+        "Submitted block should match the block committing to its coinbase height"
+    );
+    assert_eq!(
+        submitted_block.get_transactions()[0].get_coinbase_height(),
+        Some(&BlockHeight::from(1))
+    );
+}
@@ -1,8 +1,11 @@
+use crate::conversions::time::DateTimeExtPresentation as _;
 use crate::dtos::time::DateTimeExtPresentation;
 use chrono::{DateTime, Utc};
 use common::error::AppError;
+use domain::entities::block::BlockHeight;
 use domain::entities::transaction::{
-    Transaction, TransactionInput, TransactionOutPoint, TransactionOutput, Utxo,
+    NonValidatedTransaction, Transaction, TransactionAmount, TransactionInput, TransactionOutPoint,
+    TransactionOutput, Utxo,
 };
 use domain::types::hash::Hash;
 use serde::{Deserialize, Serialize};
@@ -13,23 +16,30 @@ use utoipa::ToSchema;
 pub(crate) struct TransactionPresentationDto {
     hash: String,
     data: InnerTransactionPresentationDto,
+    fee: u128,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[schema(title = "TransactionData")]
-struct InnerTransactionPresentationDto {
+pub(crate) struct InnerTransactionPresentationDto {
     inputs: Vec<TransactionInputPresentationDto>,
     outputs: Vec<TransactionOutputPresentationDto>,
     timestamp: DateTime<Utc>,
+    /// The height of the block a coinbase transaction belongs to.<br />
+    /// Omitted for regular transactions.
+    coinbase_height: Option<u64>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[schema(title = "TransactionInput")]
 struct TransactionInputPresentationDto {
     previous_output: TransactionOutPointPresentationDto,
+    public_key: String,
+    sighash_type: String,
+    signature: Option<String>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[schema(title = "TransactionOutput")]
 struct TransactionOutputPresentationDto {
     recipient: String,
@@ -48,6 +58,8 @@ pub(crate) struct TransactionOutPointPresentationDto {
 pub(crate) struct UtxoPresentationDto {
     outpoint: TransactionOutPointPresentationDto,
     output: TransactionOutputPresentationDto,
+    block_height: u64,
+    coinbase: bool,
 }
 
 impl From<Transaction> for TransactionPresentationDto {
@@ -68,7 +80,9 @@ impl From<Transaction> for TransactionPresentationDto {
                     .map(|output| output.into())
                     .collect(),
                 timestamp: tx.get_timestamp().to_chrono(),
+                coinbase_height: tx.get_coinbase_height().map(|height| height.as_u64()),
             },
+            fee: tx.get_fee().as_u128(),
         }
     }
 }
@@ -77,6 +91,9 @@ impl From<TransactionInput> for TransactionInputPresentationDto {
     fn from(input: TransactionInput) -> Self {
         Self {
             previous_output: input.get_previous_output().clone().into(),
+            public_key: input.get_public_key().to_string(),
+            sighash_type: input.get_sighash_type().to_string(),
+            signature: input.get_signature().map(|signature| signature.to_string()),
         }
     }
 }
@@ -104,6 +121,8 @@ impl From<Utxo> for UtxoPresentationDto {
         Self {
             outpoint: utxo.get_outpoint().clone().into(),
             output: utxo.get_output().clone().into(),
+            block_height: utxo.get_block_height().as_u64(),
+            coinbase: utxo.is_coinbase(),
         }
     }
 }
@@ -117,3 +136,58 @@ impl TryFrom<TransactionOutPointPresentationDto> for TransactionOutPoint {
         Ok(Self::new(tx_id, tx_output_index))
     }
 }
+
+impl TryFrom<InnerTransactionPresentationDto> for NonValidatedTransaction {
+    type Error = AppError;
+
+    fn try_from(dto: InnerTransactionPresentationDto) -> Result<Self, Self::Error> {
+        let inputs = dto
+            .inputs
+            .into_iter()
+            .map(|input| input.try_into())
+            .collect::<Result<Vec<TransactionInput>, AppError>>()?;
+        let outputs = dto
+            .outputs
+            .into_iter()
+            .map(|output| output.try_into())
+            .collect::<Result<Vec<TransactionOutput>, AppError>>()?;
+        let timestamp = dto.timestamp.to_dmn_datetime();
+        match dto.coinbase_height {
+            Some(_) if !inputs.is_empty() => Err(AppError::bad_request(
+                "Coinbase transactions can't spend any inputs!",
+            )),
+            Some(height) => Self::new_coinbase(BlockHeight::from(height), outputs, timestamp),
+            None => Self::new(inputs, outputs, timestamp),
+        }
+    }
+}
+
+impl TryFrom<TransactionInputPresentationDto> for TransactionInput {
+    type Error = AppError;
+
+    fn try_from(dto: TransactionInputPresentationDto) -> Result<Self, Self::Error> {
+        let previous_output = dto.previous_output.try_into()?;
+        let public_key = dto.public_key.parse()?;
+        let sighash_type = dto.sighash_type.parse()?;
+        let input = match dto.signature {
+            Some(signature) => Self::new_signed(
+                previous_output,
+                public_key,
+                sighash_type,
+                signature.parse()?,
+            ),
+            None => Self::new_with_sighash_type(previous_output, public_key, sighash_type),
+        };
+        Ok(input)
+    }
+}
+
+impl TryFrom<TransactionOutputPresentationDto> for TransactionOutput {
+    type Error = AppError;
+
+    fn try_from(dto: TransactionOutputPresentationDto) -> Result<Self, Self::Error> {
+        let recipient = dto.recipient.parse()?;
+        let amount = TransactionAmount::new(dto.amount);
+        Ok(Self::new(recipient, amount))
+    }
+}
@@ -4,10 +4,12 @@ use utoipa::OpenApi;
 mod get_block;
 mod get_blocks;
 mod mine;
+mod submit;
 
 use get_block::*;
 use get_blocks::*;
 use mine::*;
+use submit::*;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -15,6 +17,7 @@ use mine::*;
         get_block,
         get_blocks,
         mine_block,
+        submit_block,
     ),
     tags(
         (name = "Development / Blockchain"),
@@ -28,9 +31,13 @@ pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
             &format!("{base_path}/{{block_hash}}"),
             axum::routing::get(get_block),
         )
-        .route(&format!("{base_path}"), axum::routing::get(get_blocks))
+        .route(base_path, axum::routing::get(get_blocks))
         .route(
             &format!("{base_path}/mine"),
             axum::routing::post(mine_block),
         )
+        .route(
+            &format!("{base_path}/submit"),
+            axum::routing::post(submit_block),
+        )
 }
@@ -0,0 +1,95 @@
+use crate::auth::MasterKeyAuthContextExtractor;
+use crate::dtos::block::{BlockPresentationDto, NonValidatedBlockPresentationDto};
+use crate::types::error::PresentationError;
+use application::state::AppState;
+use application::usecases::dev::blockchain::blocks::{
+    SubmitBlockUseCaseRequest, SubmitBlockUseCaseResponse,
+};
+use axum::extract::State;
+use axum::Json;
+use common::error::AppError;
+use domain::encode::TryDecode;
+use domain::entities::block::NonValidatedBlock;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Submits an externally mined block.<br />
+/// Accepts either a hex-encoded block (see block templates) or its JSON representation.<br />
+/// Validation failures are reported in detail.
+#[utoipa::path(
+    tag = "Development / Blockchain",
+    post,
+    path = "/submit",
+    responses(
+        (status = 200, description = "Success", body = SubmitBlockHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn submit_block(
+    State(state): State<AppState>,
+    _: MasterKeyAuthContextExtractor,
+    Json(payload): Json<SubmitBlockHttpRequestBody>,
+) -> Result<Json<SubmitBlockHttpResponseBody>, PresentationError> {
+    let AppState {
+        submit_block_use_case,
+        ..
+    } = state;
+
+    let uc_req = payload.try_into()?;
+    let uc_res = submit_block_use_case.execute(uc_req).await?;
+    let http_res = uc_res.into();
+
+    Ok(Json(http_res))
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(untagged)]
+pub(crate) enum SubmitBlockHttpRequestBody {
+    Encoded {
+        /// Hex-encoded block, e.g. a solved block template.
+        encoded_block: String,
+    },
+    Json {
+        block: NonValidatedBlockPresentationDto,
+    },
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct SubmitBlockHttpResponseBody {
+    block: BlockPresentationDto,
+}
+
+impl From<SubmitBlockUseCaseResponse> for SubmitBlockHttpResponseBody {
+    fn from(res: SubmitBlockUseCaseResponse) -> Self {
+        let block = res.block.into();
+        Self { block }
+    }
+}
+
+impl TryFrom<SubmitBlockHttpRequestBody> for SubmitBlockUseCaseRequest {
+    type Error = AppError;
+
+    fn try_from(req: SubmitBlockHttpRequestBody) -> Result<Self, Self::Error> {
+        let block = match req {
+            SubmitBlockHttpRequestBody::Encoded { encoded_block } => {
+                let bytes = hex::decode(encoded_block).map_err(|err| {
+                    AppError::bad_request_with_private(
+                        "Couldn't parse encoded block.",
+                        err.to_string(),
+                    )
+                })?;
+                NonValidatedBlock::try_decode(&bytes).map_err(|err| {
+                    AppError::bad_request_with_private("Couldn't decode block.", err.to_string())
+                })?
+            }
+            SubmitBlockHttpRequestBody::Json { block } => block.try_into()?,
+        };
+        let request = Self { block };
+        Ok(request)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::auth::MasterKeyAuthContextExtractor;
+use crate::dtos::block::BlockTemplatePresentationDto;
+use crate::types::error::PresentationError;
+use application::state::AppState;
+use application::usecases::dev::blockchain::mining::{
+    GetBlockTemplateUseCaseRequest, GetBlockTemplateUseCaseResponse,
+};
+use axum::extract::{Query, State};
+use axum::Json;
+use common::error::AppError;
+use domain::types::wallet::WalletAddress;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
+
+/// Assembles a block template out of the mempool's transactions, for external miners to solve.
+#[utoipa::path(
+    tag = "Development / Blockchain",
+    get,
+    path = "/template",
+    params(GetBlockTemplateHttpRequestQuery),
+    responses(
+        (status = 200, description = "Success", body = GetBlockTemplateHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 412, description = "Precondition Failed"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_block_template(
+    State(state): State<AppState>,
+    _: MasterKeyAuthContextExtractor,
+    Query(query): Query<GetBlockTemplateHttpRequestQuery>,
+) -> Result<Json<GetBlockTemplateHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_block_template_use_case,
+        ..
+    } = state;
+
+    let uc_req = query.try_into()?;
+    let uc_res = get_block_template_use_case.execute(uc_req).await?;
+    let http_res = uc_res.try_into()?;
+
+    Ok(Json(http_res))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub(crate) struct GetBlockTemplateHttpRequestQuery {
+    /// Wallet address to be paid the coinbase reward.
+    reward_address: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetBlockTemplateHttpResponseBody {
+    block_template: BlockTemplatePresentationDto,
+}
+
+impl TryFrom<GetBlockTemplateUseCaseResponse> for GetBlockTemplateHttpResponseBody {
+    type Error = AppError;
+
+    fn try_from(res: GetBlockTemplateUseCaseResponse) -> Result<Self, Self::Error> {
+        let block_template = res.block_template.try_into()?;
+        Ok(Self { block_template })
+    }
+}
+
+impl TryFrom<GetBlockTemplateHttpRequestQuery> for GetBlockTemplateUseCaseRequest {
+    type Error = AppError;
+
+    fn try_from(query: GetBlockTemplateHttpRequestQuery) -> Result<Self, Self::Error> {
+        let reward_address = WalletAddress::from_str(&query.reward_address)?;
+        let request = Self { reward_address };
+        Ok(request)
+    }
+}
@@ -0,0 +1,39 @@
+use application::state::AppState;
+use utoipa::OpenApi;
+
+mod get_template;
+mod start;
+mod stop;
+
+use get_template::*;
+use start::*;
+use stop::*;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_block_template,
+        start_mining,
+        stop_mining,
+    ),
+    tags(
+        (name = "Development / Blockchain"),
+    )
+)]
+pub struct DevelopmentBlockchainMiningApiDoc;
+
+pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(
+            &format!("{base_path}/template"),
+            axum::routing::get(get_block_template),
+        )
+        .route(
+            &format!("{base_path}/start"),
+            axum::routing::post(start_mining),
+        )
+        .route(
+            &format!("{base_path}/stop"),
+            axum::routing::post(stop_mining),
+        )
+}
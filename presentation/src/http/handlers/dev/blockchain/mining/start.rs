@@ -0,0 +1,80 @@
+use crate::auth::MasterKeyAuthContextExtractor;
+use crate::types::error::PresentationError;
+use application::state::AppState;
+use application::usecases::dev::blockchain::mining::{
+    StartMiningUseCaseRequest, StartMiningUseCaseResponse,
+};
+use axum::extract::State;
+use axum::Json;
+use common::error::AppError;
+use domain::types::wallet::WalletAddress;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Starts the background block mining worker.
+#[utoipa::path(
+    tag = "Development / Blockchain",
+    post,
+    path = "/start",
+    responses(
+        (status = 200, description = "Success", body = StartMiningHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 412, description = "Precondition Failed"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn start_mining(
+    State(state): State<AppState>,
+    _: MasterKeyAuthContextExtractor,
+    Json(payload): Json<StartMiningHttpRequestBody>,
+) -> Result<Json<StartMiningHttpResponseBody>, PresentationError> {
+    let AppState {
+        start_mining_use_case,
+        ..
+    } = state;
+
+    let uc_req = payload.try_into()?;
+    let uc_res = start_mining_use_case.execute(uc_req).await?;
+    let http_res = uc_res.into();
+
+    Ok(Json(http_res))
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct StartMiningHttpRequestBody {
+    /// Wallet address to be paid coinbase rewards.<br />
+    /// Defaults to the configured mining reward address.
+    #[schema(example = "54b73c091395a30874a397cbfcd54c7348175a01ee6ccf0a1133f8f8b3a19e7d")]
+    reward_address: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct StartMiningHttpResponseBody {
+    reward_address: String,
+}
+
+impl From<StartMiningUseCaseResponse> for StartMiningHttpResponseBody {
+    fn from(res: StartMiningUseCaseResponse) -> Self {
+        Self {
+            reward_address: res.reward_address.to_string(),
+        }
+    }
+}
+
+impl TryFrom<StartMiningHttpRequestBody> for StartMiningUseCaseRequest {
+    type Error = AppError;
+
+    fn try_from(req: StartMiningHttpRequestBody) -> Result<Self, Self::Error> {
+        let reward_address = req
+            .reward_address
+            .as_deref()
+            .map(WalletAddress::from_str)
+            .transpose()?;
+        let request = Self { reward_address };
+        Ok(request)
+    }
+}
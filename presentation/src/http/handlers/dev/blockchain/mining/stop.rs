@@ -0,0 +1,32 @@
+use crate::auth::MasterKeyAuthContextExtractor;
+use crate::types::error::PresentationError;
+use application::state::AppState;
+use axum::extract::State;
+
+/// Stops the background block mining worker.
+#[utoipa::path(
+    tag = "Development / Blockchain",
+    post,
+    path = "/stop",
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn stop_mining(
+    State(state): State<AppState>,
+    _: MasterKeyAuthContextExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        stop_mining_use_case,
+        ..
+    } = state;
+
+    stop_mining_use_case.execute().await?;
+
+    Ok("Mining stopped successfully!".to_string())
+}
@@ -3,9 +3,11 @@ use utoipa::OpenApi;
 
 mod blocks;
 mod get_tip_info;
+mod mining;
 
 use blocks::*;
 use get_tip_info::*;
+use mining::*;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -15,6 +17,7 @@ use get_tip_info::*;
     ),
     nest(
         (path = "/blocks", api = DevelopmentBlockchainBlocksApiDoc),
+        (path = "/mining", api = DevelopmentBlockchainMiningApiDoc),
     ),
     tags(
         (name = "Development / Blockchain"),
@@ -29,4 +32,5 @@ pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
             axum::routing::get(get_tip_info),
         )
         .merge(blocks::declare_routes(&format!("{base_path}/blocks")))
+        .merge(mining::declare_routes(&format!("{base_path}/mining")))
 }
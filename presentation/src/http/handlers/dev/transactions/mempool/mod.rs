@@ -21,12 +21,6 @@ pub struct DevelopmentTransactionsMempoolApiDoc;
 
 pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
     axum::Router::new()
-        .route(
-            &format!("{base_path}"),
-            axum::routing::get(get_transactions),
-        )
-        .route(
-            &format!("{base_path}"),
-            axum::routing::post(place_transaction),
-        )
+        .route(base_path, axum::routing::get(get_transactions))
+        .route(base_path, axum::routing::post(place_transaction))
 }
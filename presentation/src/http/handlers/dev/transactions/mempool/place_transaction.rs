@@ -60,6 +60,11 @@ pub(crate) struct PlaceMempoolTransactionHttpRequestBody {
     #[schema(example = 500u128)]
     amount: u128,
 
+    /// The fee to be paid to the miner.<br />
+    /// Must meet the node's minimum relay fee rate for the transaction's encoded size.
+    #[schema(example = 250u128)]
+    fee: u128,
+
     /// The outpoints to be consumed as inputs for this transaction.
     #[schema(example = json!([
         {
@@ -90,6 +95,7 @@ impl TryFrom<PlaceMempoolTransactionHttpRequestBody> for PlaceMempoolTransaction
             sender_private_key,
             recipient_wallet_address,
             amount: TransactionAmount::new(req.amount),
+            fee: TransactionAmount::new(req.fee),
             consumed_outpoints,
         };
         Ok(dmn_req)
@@ -0,0 +1,54 @@
+use crate::types::error::PresentationError;
+use application::state::AppState;
+use application::usecases::dev::transactions::utxo::{
+    GetBalanceByAddressUseCaseRequest, GetBalanceByAddressUseCaseResponse,
+};
+use axum::extract::{Path, State};
+use axum::Json;
+use domain::types::wallet::WalletAddress;
+use serde::Serialize;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Retrieves the balance of a wallet address, summed over its UTXOs.<br />
+/// Immature coinbase outputs are included.
+#[utoipa::path(
+    tag = "Development / Transactions",
+    get,
+    path = "/address/{address}/balance",
+    params(
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetBalanceByAddressHttpResponseBody),
+        (status = 500, description = "Failure"),
+    ),
+)]
+pub(crate) async fn get_balance_by_address(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> Result<Json<GetBalanceByAddressHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_balance_by_address_use_case,
+        ..
+    } = state;
+
+    let address = WalletAddress::from_str(&address)?;
+    let request = GetBalanceByAddressUseCaseRequest { address };
+    let uc_res = get_balance_by_address_use_case.execute(request).await?;
+    let http_res = uc_res.into();
+
+    Ok(Json(http_res))
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetBalanceByAddressHttpResponseBody {
+    balance: u128,
+}
+
+impl From<GetBalanceByAddressUseCaseResponse> for GetBalanceByAddressHttpResponseBody {
+    fn from(res: GetBalanceByAddressUseCaseResponse) -> Self {
+        let balance = res.balance.as_u128();
+        Self { balance }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::dtos::transaction::UtxoPresentationDto;
+use crate::types::error::PresentationError;
+use application::state::AppState;
+use application::usecases::dev::transactions::utxo::{
+    GetUtxosByAddressUseCaseRequest, GetUtxosByAddressUseCaseResponse,
+};
+use axum::extract::{Path, State};
+use axum::Json;
+use domain::types::wallet::WalletAddress;
+use serde::Serialize;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Retrieves the UTXOs paying a wallet address.
+#[utoipa::path(
+    tag = "Development / Transactions",
+    get,
+    path = "/address/{address}",
+    params(
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetUtxosByAddressHttpResponseBody),
+        (status = 500, description = "Failure"),
+    ),
+)]
+pub(crate) async fn get_utxos_by_address(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> Result<Json<GetUtxosByAddressHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_utxos_by_address_use_case,
+        ..
+    } = state;
+
+    let address = WalletAddress::from_str(&address)?;
+    let request = GetUtxosByAddressUseCaseRequest { address };
+    let uc_res = get_utxos_by_address_use_case.execute(request).await?;
+    let http_res = uc_res.into();
+
+    Ok(Json(http_res))
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetUtxosByAddressHttpResponseBody {
+    utxos: Vec<UtxoPresentationDto>,
+}
+
+impl From<GetUtxosByAddressUseCaseResponse> for GetUtxosByAddressHttpResponseBody {
+    fn from(res: GetUtxosByAddressUseCaseResponse) -> Self {
+        let utxos = res.utxos.into_iter().map(|utxo| utxo.into()).collect();
+        Self { utxos }
+    }
+}
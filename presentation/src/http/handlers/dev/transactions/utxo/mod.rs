@@ -1,14 +1,20 @@
 use application::state::AppState;
 use utoipa::OpenApi;
 
+mod get_balance_by_address;
 mod get_utxos;
+mod get_utxos_by_address;
 
+use get_balance_by_address::*;
 use get_utxos::*;
+use get_utxos_by_address::*;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         get_utxos,
+        get_utxos_by_address,
+        get_balance_by_address,
     ),
     tags(
         (name = "Development / Transactions"),
@@ -17,5 +23,14 @@ use get_utxos::*;
 pub struct DevelopmentTransactionsUtxoApiDoc;
 
 pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
-    axum::Router::new().route(&format!("{base_path}"), axum::routing::get(get_utxos))
+    axum::Router::new()
+        .route(base_path, axum::routing::get(get_utxos))
+        .route(
+            &format!("{base_path}/address/{{address}}"),
+            axum::routing::get(get_utxos_by_address),
+        )
+        .route(
+            &format!("{base_path}/address/{{address}}/balance"),
+            axum::routing::get(get_balance_by_address),
+        )
 }
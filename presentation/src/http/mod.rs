@@ -27,6 +27,7 @@ use utoipa_swagger_ui::SwaggerUi;
 struct ApiDoc;
 
 impl ApiDoc {
+    #[allow(clippy::new_ret_no_self)]
     fn new(api_base_url: &str, use_master_key: bool) -> utoipa::openapi::OpenApi {
         let mut doc = Self::openapi();
         doc.servers = Some(vec![utoipa::openapi::Server::new(api_base_url)]);